@@ -24,6 +24,13 @@ pub struct Error {
     backtrace: Backtrace,
 }
 
+impl Error {
+    /// The kind of error that occured.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
 impl From<ErrorKind> for Error {
     #[cfg(feature = "backtrace")]
     fn from(kind: ErrorKind) -> Self {
@@ -63,6 +70,36 @@ impl From<uuid::Error> for Error {
     }
 }
 
+/// An error that was skipped when parsing in lenient mode.
+///
+/// See [`ParseOptions::with_strict()`](crate::ParseOptions::with_strict) and
+/// [`Parser::warnings()`](crate::Parser::warnings).
+#[derive(Error, Debug)]
+#[error("{location}: {error}")]
+pub struct Warning {
+    location: String,
+    error: Error,
+}
+
+impl Warning {
+    pub(crate) fn new(location: &str, error: Error) -> Warning {
+        Warning {
+            location: location.to_string(),
+            error,
+        }
+    }
+
+    /// The path of the notebook entry or the name of the section file that failed to parse.
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// The error that was skipped.
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
 /// Details about a parsing error
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
@@ -83,6 +120,31 @@ pub enum ErrorKind {
     #[error("Table of contents file is missing in dir {dir}")]
     TocFileMissing { dir: String },
 
+    /// A file is larger than the maximum file size set in the parse options.
+    ///
+    /// Files read from a [`NotebookSource`](crate::notebook::NotebookSource) are only read
+    /// until the limit is exceeded, so `size` is the number of bytes read until then
+    /// rather than the file's total size.
+    #[error("File {file} has {size} bytes which exceeds the limit of {max} bytes")]
+    FileTooLarge { file: String, size: u64, max: usize },
+
+    /// A file contains more objects than the maximum object count set in the parse options.
+    ///
+    /// Parsing stops as soon as the limit is exceeded, so `count` is the number of objects
+    /// parsed until then rather than the file's total object count.
+    #[error(
+        "File {file} contains at least {count} objects which exceeds the limit of {max} objects"
+    )]
+    TooManyObjects {
+        file: String,
+        count: usize,
+        max: usize,
+    },
+
+    /// The requested operation is not supported for the file.
+    #[error("Unsupported operation: {0}")]
+    Unsupported(Cow<'static, str>),
+
     /// Malformed data was encountered when parsing the OneNote file.
     #[error("Malformed data: {0}")]
     MalformedData(Cow<'static, str>),
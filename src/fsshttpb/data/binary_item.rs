@@ -1,6 +1,6 @@
 use crate::errors::Result;
 use crate::fsshttpb::data::compact_u64::CompactU64;
-use crate::Reader;
+use crate::{Reader, Writer};
 
 /// A byte array with the length determined by a `CompactU64`.
 ///
@@ -17,7 +17,21 @@ impl BinaryItem {
         Ok(BinaryItem(data))
     }
 
+    /// Parse a binary item without copying its data out of the reader's buffer.
+    pub(crate) fn parse_borrowed<'a>(reader: Reader<'a, '_>) -> Result<&'a [u8]> {
+        let size = CompactU64::parse(reader)?.value();
+
+        reader.read(size as usize)
+    }
+
     pub(crate) fn value(self) -> Vec<u8> {
         self.0
     }
+
+    pub(crate) fn write(data: &[u8], writer: Writer) -> Result<()> {
+        CompactU64::new(data.len() as u64).write(writer)?;
+        writer.extend_from_slice(data);
+
+        Ok(())
+    }
 }
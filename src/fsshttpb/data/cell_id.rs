@@ -1,7 +1,7 @@
 use crate::errors::Result;
 use crate::fsshttpb::data::compact_u64::CompactU64;
 use crate::fsshttpb::data::exguid::ExGuid;
-use crate::Reader;
+use crate::{Reader, Writer};
 
 /// A FSSHTTP cell identifier.
 ///
@@ -30,4 +30,19 @@ impl CellId {
 
         Ok(values)
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        self.0.write(writer)?;
+        self.1.write(writer)
+    }
+
+    pub(crate) fn write_array(values: &[CellId], writer: Writer) -> Result<()> {
+        CompactU64::new(values.len() as u64).write(writer)?;
+
+        for value in values {
+            value.write(writer)?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::errors::{ErrorKind, Result};
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 
 /// A compact unsigned 64-bit integer.
 ///
@@ -16,10 +17,42 @@ use crate::Reader;
 pub(crate) struct CompactU64(u64);
 
 impl CompactU64 {
+    pub(crate) fn new(value: u64) -> CompactU64 {
+        CompactU64(value)
+    }
+
     pub(crate) fn value(&self) -> u64 {
         self.0
     }
 
+    /// Write the integer using the smallest width that fits its value.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let value = self.0;
+
+        if value == 0 {
+            writer.put_u8(0);
+
+            return Ok(());
+        }
+
+        // Find the number of bytes needed to store the value and the width marker bit
+        let width = (1..8).find(|width| value < 1 << (7 * width));
+
+        match width {
+            Some(width) => {
+                let data = (value << width) | 1 << (width - 1);
+
+                writer.extend_from_slice(&data.to_le_bytes()[..width as usize]);
+            }
+            None => {
+                writer.put_u8(128);
+                writer.put_u64_le(value);
+            }
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn parse(reader: Reader) -> Result<CompactU64> {
         let bytes = reader.bytes();
 
@@ -119,6 +152,33 @@ mod test {
     use crate::fsshttpb::data::compact_u64::CompactU64;
     use crate::reader::Reader;
 
+    fn round_trip(value: u64) -> u64 {
+        let mut data = vec![];
+        CompactU64::new(value).write(&mut data).unwrap();
+
+        let mut reader = Reader::new(&data);
+        let parsed = CompactU64::parse(&mut reader).unwrap().value();
+        assert_eq!(reader.remaining(), 0);
+
+        parsed
+    }
+
+    #[test]
+    fn test_write() {
+        let mut data = vec![];
+        CompactU64::new(135546).write(&mut data).unwrap();
+        assert_eq!(data, [0xd4, 0x8b, 0x10]);
+
+        for shift in 0..64 {
+            let value = 1u64 << shift;
+
+            assert_eq!(round_trip(value), value);
+            assert_eq!(round_trip(value - 1), value - 1);
+        }
+
+        assert_eq!(round_trip(u64::MAX), u64::MAX);
+    }
+
     #[test]
     fn test_zero() {
         assert_eq!(
@@ -1,7 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::compact_u64::CompactU64;
 use crate::shared::guid::Guid;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 use std::fmt;
 
 /// A variable-width encoding of an extended GUID (GUID + 32 bit value)
@@ -9,13 +10,22 @@ use std::fmt;
 /// See [\[MS-FSSHTTPB\] 2.2.1.7].
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.7]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/bff58e9f-8222-4fbb-b112-5826d5febedd
-#[derive(Clone, Copy, PartialEq, Hash, Eq)]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, PartialOrd, Ord)]
 pub struct ExGuid {
+    /// The GUID part of the extended GUID.
     pub guid: Guid,
+    /// The 32 bit value distinguishing IDs sharing the same GUID.
     pub value: u32,
 }
 
 impl ExGuid {
+    pub(crate) fn nil() -> ExGuid {
+        ExGuid {
+            guid: Guid::nil(),
+            value: 0,
+        }
+    }
+
     pub(crate) fn is_nil(&self) -> bool {
         self.guid.is_nil() && self.value == 0
     }
@@ -87,6 +97,32 @@ impl ExGuid {
         )
     }
 
+    /// Write the extended GUID using the smallest encoding that fits its value.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        if self.is_nil() {
+            writer.put_u8(0);
+
+            return Ok(());
+        }
+
+        let value = self.value;
+
+        if value < 1 << 5 {
+            writer.put_u8((value << 3) as u8 | 4);
+        } else if value < 1 << 10 {
+            writer.put_u8((value << 6) as u8 | 32);
+            writer.put_u8((value >> 2) as u8);
+        } else if value < 1 << 17 {
+            writer.put_u8((value << 7) as u8 | 64);
+            writer.put_u16_le((value >> 1) as u16);
+        } else {
+            writer.put_u8(128);
+            writer.put_u32_le(value);
+        }
+
+        self.guid.write(writer)
+    }
+
     /// Parse an array of `ExGuid` values.
     ///
     /// See [\[MS-FSSHTTPB\] 2.2.1.8]
@@ -102,6 +138,17 @@ impl ExGuid {
 
         Ok(values)
     }
+
+    /// Write an array of `ExGuid` values.
+    pub(crate) fn write_array(values: &[ExGuid], writer: Writer) -> Result<()> {
+        CompactU64::new(values.len() as u64).write(writer)?;
+
+        for value in values {
+            value.write(writer)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl fmt::Debug for ExGuid {
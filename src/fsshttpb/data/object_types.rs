@@ -11,7 +11,7 @@ use std::fmt;
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.5.1]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/a1017f48-a888-49ff-b71d-cc3c707f753a
 /// [\[MS-FSSHTTPB\] 2.2.1.5.2]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/ac629d63-60a1-49b2-9db2-fa3c19971cc9
-#[derive(Debug, Primitive, PartialEq)]
+#[derive(Debug, Primitive, Clone, Copy, PartialEq)]
 pub enum ObjectType {
     CellManifest = 0x0B,
     DataElement = 0x01,
@@ -1,6 +1,7 @@
 use crate::errors::Result;
 use crate::shared::guid::Guid;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 
 /// A variable-width serial number.
 ///
@@ -8,6 +9,7 @@ use crate::Reader;
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.9]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/9db15fa4-0dc2-4b17-b091-d33886d8a0f6
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct SerialNumber {
     pub guid: Guid,
     pub serial: u64,
@@ -31,4 +33,18 @@ impl SerialNumber {
 
         Ok(SerialNumber { guid, serial })
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        if self.guid.is_nil() && self.serial == 0 {
+            writer.put_u8(0);
+
+            return Ok(());
+        }
+
+        writer.put_u8(128);
+        self.guid.write(writer)?;
+        writer.put_u64_le(self.serial);
+
+        Ok(())
+    }
 }
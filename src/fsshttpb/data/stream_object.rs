@@ -1,7 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::compact_u64::CompactU64;
 use crate::fsshttpb::data::object_types::ObjectType;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 use num_traits::{FromPrimitive, ToPrimitive};
 
 /// A FSSHTTPB stream object header.
@@ -10,6 +11,7 @@ use num_traits::{FromPrimitive, ToPrimitive};
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.5]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/5faee10f-8e55-43f8-935a-d6e4294856fc
 #[derive(Debug)]
+#[allow(dead_code)]
 pub struct ObjectHeader {
     pub compound: bool,
     pub object_type: ObjectType,
@@ -17,6 +19,14 @@ pub struct ObjectHeader {
 }
 
 impl ObjectHeader {
+    pub(crate) fn new(object_type: ObjectType, compound: bool, length: usize) -> ObjectHeader {
+        ObjectHeader {
+            compound,
+            object_type,
+            length: length as u64,
+        }
+    }
+
     pub(crate) fn try_parse(reader: Reader, object_type: ObjectType) -> Result<()> {
         Self::try_parse_start(reader, object_type, Self::parse)
     }
@@ -230,4 +240,81 @@ impl ObjectHeader {
             Err(e) => Err(e),
         }
     }
+
+    /// Write a 16-bit stream object header if the object fits into it or a 32-bit header
+    /// otherwise.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        if self.length <= 0x7f && self.object_type.to_u16().unwrap() <= 0x3f {
+            self.write_16(writer)
+        } else {
+            self.write_32(writer)
+        }
+    }
+
+    /// Write a 16-bit stream object header.
+    ///
+    /// See [\[MS-FSSHTTPB\] 2.2.1.5.1]
+    ///
+    /// [\[MS-FSSHTTPB\] 2.2.1.5.1]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/a1017f48-a888-49ff-b71d-cc3c707f753a
+    pub(crate) fn write_16(&self, writer: Writer) -> Result<()> {
+        let object_type = self.object_type.to_u16().unwrap();
+
+        if self.length > 0x7f || object_type > 0x3f {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                format!(
+                    "object of type 0x{:x} does not fit into a 16 bit header",
+                    object_type
+                )
+                .into(),
+            )
+            .into());
+        }
+
+        let compound = if self.compound { 0x4 } else { 0 };
+
+        writer.put_u16_le(compound | object_type << 3 | (self.length as u16) << 9);
+
+        Ok(())
+    }
+
+    /// Write a 32-bit stream object header.
+    ///
+    /// See [\[MS-FSSHTTPB\] 2.2.1.5.2]
+    ///
+    /// [\[MS-FSSHTTPB\] 2.2.1.5.2]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/ac629d63-60a1-49b2-9db2-fa3c19971cc9
+    pub(crate) fn write_32(&self, writer: Writer) -> Result<()> {
+        let object_type = self.object_type.to_u32().unwrap();
+        let compound = if self.compound { 0x4 } else { 0 };
+        let length = self.length.min(0x7fff) as u32;
+
+        writer.put_u32_le(0x2 | compound | object_type << 3 | length << 17);
+
+        if length == 0x7fff {
+            CompactU64::new(self.length).write(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a 16-bit stream object header end.
+    ///
+    /// See [\[MS-FSSHTTPB\] 2.2.1.5.4]
+    ///
+    /// [\[MS-FSSHTTPB\] 2.2.1.5.4]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/d8cedbb8-073b-4711-8867-f88b887ab0a9
+    pub(crate) fn write_end_16(writer: Writer, object_type: ObjectType) -> Result<()> {
+        writer.put_u16_le(0x3 | object_type.to_u16().unwrap() << 2);
+
+        Ok(())
+    }
+
+    /// Write a 8-bit stream object header end.
+    ///
+    /// See [\[MS-FSSHTTPB\] 2.2.1.5.3]
+    ///
+    /// [\[MS-FSSHTTPB\] 2.2.1.5.3]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/544ce81a-44e3-48ff-b094-0e51c7207aa1
+    pub(crate) fn write_end_8(writer: Writer, object_type: ObjectType) -> Result<()> {
+        writer.put_u8(0x1 | object_type.to_u8().unwrap() << 2);
+
+        Ok(())
+    }
 }
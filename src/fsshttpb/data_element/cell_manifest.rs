@@ -3,7 +3,7 @@ use crate::fsshttpb::data::exguid::ExGuid;
 use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::{Reader, Writer};
 
 impl DataElement {
     /// Parse a cell manifest.
@@ -20,4 +20,14 @@ impl DataElement {
 
         Ok(id)
     }
+
+    pub(crate) fn write_cell_manifest(id: ExGuid, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        id.write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::CellManifest, false, data.len()).write_16(writer)?;
+        writer.extend_from_slice(&data);
+
+        Ok(())
+    }
 }
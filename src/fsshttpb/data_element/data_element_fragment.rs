@@ -1,20 +1,27 @@
-use crate::errors::Result;
+use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::compact_u64::CompactU64;
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
-use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::fsshttpb::data_element::{DataElement, DataElementPackage};
+use crate::{Reader, Writer};
+use std::collections::HashMap;
 
 /// A data element fragment.
 ///
+/// Large data elements can be split into fragments that each hold a part of the
+/// serialized data element.
+///
 /// See [\[MS-FSSHTTPB\] 2.2.1.12.7].
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12.7]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/9a860e3b-cf61-484b-8ee3-d875afaf7a05
 #[derive(Debug)]
 pub(crate) struct DataElementFragment {
+    /// The ID of the fragmented data element.
     pub(crate) id: ExGuid,
+    /// The size of the fragmented data element.
     pub(crate) size: u64,
+    /// The location of the fragment's data within the fragmented data element.
     pub(crate) chunk_reference: DataElementFragmentChunkReference,
     pub(crate) data: Vec<u8>,
 }
@@ -25,6 +32,22 @@ pub(crate) struct DataElementFragmentChunkReference {
     pub(crate) length: u64,
 }
 
+impl DataElementFragment {
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        self.id.write(&mut data)?;
+        CompactU64::new(self.size).write(&mut data)?;
+        CompactU64::new(self.chunk_reference.offset).write(&mut data)?;
+        CompactU64::new(self.chunk_reference.length).write(&mut data)?;
+        data.extend_from_slice(&self.data);
+
+        ObjectHeader::new(ObjectType::DataElementFragment, false, data.len()).write(writer)?;
+        writer.extend_from_slice(&data);
+
+        Ok(())
+    }
+}
+
 impl DataElement {
     /// Parse a data element fragment.
     ///
@@ -39,7 +62,16 @@ impl DataElement {
         let offset = CompactU64::parse(reader)?.value();
         let length = CompactU64::parse(reader)?.value();
 
-        let data = reader.read(size as usize)?.to_vec();
+        if offset.checked_add(length).is_none_or(|end| end > size) {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "data element fragment exceeds the data element size".into(),
+            )
+            .into());
+        }
+
+        let data = reader.read(length as usize)?.to_vec();
+
+        ObjectHeader::try_parse_end_8(reader, ObjectType::DataElement)?;
 
         let chunk_reference = DataElementFragmentChunkReference { offset, length };
         let fragment = DataElementFragment {
@@ -52,3 +84,215 @@ impl DataElement {
         Ok(fragment)
     }
 }
+
+impl<'a> DataElementPackage<'a> {
+    /// Reassemble the fragmented data elements of the package.
+    ///
+    /// The fragments of each data element have to cover the whole data element without
+    /// gaps or overlaps. The reassembled data elements are added to the package so they
+    /// can be looked up like any other data element. The fragments are kept so the package
+    /// is written back unchanged.
+    pub(crate) fn reassemble_fragments(&mut self) -> Result<()> {
+        let mut fragments_by_element: HashMap<ExGuid, Vec<&DataElementFragment>> = HashMap::new();
+
+        for fragment in self.data_element_fragments.values() {
+            fragments_by_element
+                .entry(fragment.id)
+                .or_default()
+                .push(fragment);
+        }
+
+        let elements = fragments_by_element
+            .into_iter()
+            .map(|(id, mut fragments)| {
+                fragments.sort_by_key(|fragment| fragment.chunk_reference.offset);
+
+                Ok((id, join_fragments(&fragments)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (id, data) in elements {
+            if self.elements.iter().any(|header| header.id == id) {
+                return Err(ErrorKind::MalformedFssHttpBData(
+                    "fragmented data element is stored in full as well".into(),
+                )
+                .into());
+            }
+
+            self.insert_reassembled(id, &data)?;
+        }
+
+        Ok(())
+    }
+
+    fn insert_reassembled(&mut self, id: ExGuid, data: &[u8]) -> Result<()> {
+        let mut reader = crate::reader::Reader::new(data);
+        let mut element = DataElementPackage::new();
+        DataElement::parse(&mut reader, &mut element)?;
+
+        if reader.remaining() > 0 {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "reassembled data element has trailing data".into(),
+            )
+            .into());
+        }
+
+        if element.elements.iter().any(|header| header.id != id) {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "reassembled data element has a different ID".into(),
+            )
+            .into());
+        }
+
+        if !element.data_element_fragments.is_empty() {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "data element fragments are nested".into(),
+            )
+            .into());
+        }
+
+        self.storage_indexes.extend(element.storage_indexes);
+        self.storage_manifests.extend(element.storage_manifests);
+        self.cell_manifests.extend(element.cell_manifests);
+        self.revision_manifests.extend(element.revision_manifests);
+        self.object_groups.extend(element.object_groups);
+
+        // Blobs borrow from the reassembled data so they have to be copied
+        self.object_data_blobs.extend(
+            element
+                .object_data_blobs
+                .into_iter()
+                .map(|(id, blob)| (id, blob.into_owned())),
+        );
+
+        Ok(())
+    }
+}
+
+/// Join the data of a data element's fragments ordered by their offset.
+fn join_fragments(fragments: &[&DataElementFragment]) -> Result<Vec<u8>> {
+    let size = fragments.first().map_or(0, |fragment| fragment.size);
+    let mut data = Vec::new();
+
+    for fragment in fragments {
+        if fragment.size != size {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "data element fragments have different sizes".into(),
+            )
+            .into());
+        }
+
+        if fragment.chunk_reference.offset != data.len() as u64 {
+            return Err(ErrorKind::MalformedFssHttpBData(
+                "data element fragments overlap or leave a gap".into(),
+            )
+            .into());
+        }
+
+        data.extend_from_slice(&fragment.data);
+    }
+
+    if data.len() as u64 != size {
+        return Err(
+            ErrorKind::MalformedFssHttpBData("data element fragment is missing".into()).into(),
+        );
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::errors::ErrorKind;
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::fsshttpb::data::serial_number::SerialNumber;
+    use crate::fsshttpb::data_element::data_element_fragment::{
+        DataElementFragment, DataElementFragmentChunkReference,
+    };
+    use crate::fsshttpb::data_element::object_data_blob::ObjectDataBlob;
+    use crate::fsshttpb::data_element::{DataElement, DataElementHeader, DataElementPackage};
+    use crate::reader::Reader;
+    use crate::shared::guid::Guid;
+
+    fn header(id: ExGuid, element_type: u64) -> DataElementHeader {
+        DataElementHeader {
+            id,
+            serial: SerialNumber {
+                guid: Guid::nil(),
+                serial: 0,
+            },
+            element_type,
+        }
+    }
+
+    /// Write a package that stores a blob data element as fragments of the given size.
+    fn write_fragmented_blob(blob_id: ExGuid, blob: &[u8], fragment_size: usize) -> Vec<u8> {
+        let mut element = DataElementPackage::new();
+        element
+            .object_data_blobs
+            .insert(blob_id, ObjectDataBlob::new(blob));
+
+        let mut data = vec![];
+        DataElement::write(&header(blob_id, 0x0A), &element, &mut data).unwrap();
+
+        let mut package = DataElementPackage::new();
+
+        for (index, chunk) in data.chunks(fragment_size).enumerate() {
+            let fragment_id = ExGuid::from_guid(blob_id.guid, 100 + index as u32);
+            let fragment = DataElementFragment {
+                id: blob_id,
+                size: data.len() as u64,
+                chunk_reference: DataElementFragmentChunkReference {
+                    offset: (index * fragment_size) as u64,
+                    length: chunk.len() as u64,
+                },
+                data: chunk.to_vec(),
+            };
+
+            package.elements.push(header(fragment_id, 0x06));
+            package.data_element_fragments.insert(fragment_id, fragment);
+        }
+
+        let mut output = vec![];
+        package.write(&mut output).unwrap();
+
+        output
+    }
+
+    #[test]
+    fn test_reassemble_fragments() {
+        let blob_id = ExGuid::from_guid(Guid::new_v4(), 1);
+        let blob: Vec<u8> = (0..200).map(|v| v as u8).collect();
+
+        let data = write_fragmented_blob(blob_id, &blob, 100);
+        let package = DataElementPackage::parse(&mut Reader::new(&data)).unwrap();
+
+        assert_eq!(package.find_blob(blob_id).as_deref(), Some(&blob[..]));
+        assert_eq!(package.data_element_fragments.len(), 3);
+
+        // The fragments are written back as they were stored
+        let mut output = vec![];
+        package.write(&mut output).unwrap();
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn test_reassemble_incomplete_fragments() {
+        let blob_id = ExGuid::from_guid(Guid::new_v4(), 1);
+        let blob = vec![0xAB; 200];
+
+        let data = write_fragmented_blob(blob_id, &blob, 100);
+        let mut package = DataElementPackage::parse(&mut Reader::new(&data)).unwrap();
+
+        // Drop the middle fragment
+        let fragment_id = ExGuid::from_guid(blob_id.guid, 101);
+        package.elements.retain(|header| header.id != fragment_id);
+        package.data_element_fragments.remove(&fragment_id);
+
+        let mut incomplete = vec![];
+        package.write(&mut incomplete).unwrap();
+
+        let err = DataElementPackage::parse(&mut Reader::new(&incomplete)).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::MalformedFssHttpBData(_)));
+    }
+}
@@ -10,7 +10,9 @@ use crate::fsshttpb::data_element::object_group::ObjectGroup;
 use crate::fsshttpb::data_element::revision_manifest::RevisionManifest;
 use crate::fsshttpb::data_element::storage_index::StorageIndex;
 use crate::fsshttpb::data_element::storage_manifest::StorageManifest;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Debug;
 
@@ -28,25 +30,22 @@ pub(crate) mod storage_manifest;
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/99a25464-99b5-4262-a964-baabed2170eb
 #[derive(Debug)]
-pub(crate) struct DataElementPackage {
+pub(crate) struct DataElementPackage<'a> {
+    pub(crate) elements: Vec<DataElementHeader>,
     pub(crate) storage_indexes: HashMap<ExGuid, StorageIndex>,
     pub(crate) storage_manifests: HashMap<ExGuid, StorageManifest>,
     pub(crate) cell_manifests: HashMap<ExGuid, ExGuid>,
     pub(crate) revision_manifests: HashMap<ExGuid, RevisionManifest>,
     pub(crate) object_groups: HashMap<ExGuid, ObjectGroup>,
     pub(crate) data_element_fragments: HashMap<ExGuid, DataElementFragment>,
-    pub(crate) object_data_blobs: HashMap<ExGuid, ObjectDataBlob>,
+    pub(crate) object_data_blobs: HashMap<ExGuid, ObjectDataBlob<'a>>,
 }
 
-impl DataElementPackage {
-    pub(crate) fn parse(reader: Reader) -> Result<DataElementPackage> {
-        ObjectHeader::try_parse_16(reader, ObjectType::DataElementPackage)?;
-
-        if reader.get_u8()? != 0 {
-            return Err(ErrorKind::MalformedFssHttpBData("invalid padding byte".into()).into());
-        }
-
-        let mut package = DataElementPackage {
+impl<'a> DataElementPackage<'a> {
+    /// Create an empty data element package.
+    pub(crate) fn new() -> DataElementPackage<'a> {
+        DataElementPackage {
+            elements: vec![],
             storage_indexes: Default::default(),
             storage_manifests: Default::default(),
             cell_manifests: Default::default(),
@@ -54,7 +53,17 @@ impl DataElementPackage {
             object_groups: Default::default(),
             data_element_fragments: Default::default(),
             object_data_blobs: Default::default(),
-        };
+        }
+    }
+
+    pub(crate) fn parse(reader: Reader<'a, '_>) -> Result<DataElementPackage<'a>> {
+        ObjectHeader::try_parse_16(reader, ObjectType::DataElementPackage)?;
+
+        if reader.get_u8()? != 0 {
+            return Err(ErrorKind::MalformedFssHttpBData("invalid padding byte".into()).into());
+        }
+
+        let mut package = DataElementPackage::new();
 
         loop {
             if ObjectHeader::has_end_8(reader, ObjectType::DataElementPackage)? {
@@ -66,9 +75,22 @@ impl DataElementPackage {
 
         ObjectHeader::try_parse_end_8(reader, ObjectType::DataElementPackage)?;
 
+        package.reassemble_fragments()?;
+
         Ok(package)
     }
 
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        ObjectHeader::new(ObjectType::DataElementPackage, true, 1).write_16(writer)?;
+        writer.put_u8(0);
+
+        for header in &self.elements {
+            DataElement::write(header, self, writer)?;
+        }
+
+        ObjectHeader::write_end_8(writer, ObjectType::DataElementPackage)
+    }
+
     /// Look up the object groups referenced by a cell.
     pub(crate) fn find_objects(
         &self,
@@ -101,7 +123,7 @@ impl DataElementPackage {
     }
 
     /// Look up a blob by its ID.
-    pub(crate) fn find_blob(&self, id: ExGuid) -> Option<&[u8]> {
+    pub(crate) fn find_blob(&self, id: ExGuid) -> Option<Cow<'a, [u8]>> {
         self.object_data_blobs.get(&id).map(|blob| blob.value())
     }
 
@@ -131,6 +153,16 @@ impl DataElementPackage {
     }
 }
 
+/// The header of a data element.
+///
+/// The package keeps the headers in the order the data elements are stored in the file.
+#[derive(Debug)]
+pub(crate) struct DataElementHeader {
+    pub(crate) id: ExGuid,
+    pub(crate) serial: SerialNumber,
+    pub(crate) element_type: u64,
+}
+
 /// A parser for a single data element.
 ///
 /// See [\[MS-FSSHTTPB\] 2.2.1.12.1]
@@ -140,11 +172,14 @@ impl DataElementPackage {
 pub(crate) struct DataElement;
 
 impl DataElement {
-    pub(crate) fn parse(reader: Reader, package: &mut DataElementPackage) -> Result<()> {
+    pub(crate) fn parse<'a>(
+        reader: Reader<'a, '_>,
+        package: &mut DataElementPackage<'a>,
+    ) -> Result<()> {
         ObjectHeader::try_parse_16(reader, ObjectType::DataElement)?;
 
         let id = ExGuid::parse(reader)?;
-        let _serial = SerialNumber::parse(reader)?;
+        let serial = SerialNumber::parse(reader)?;
         let element_type = CompactU64::parse(reader)?;
 
         match element_type.value() {
@@ -191,6 +226,74 @@ impl DataElement {
             }
         }
 
+        package.elements.push(DataElementHeader {
+            id,
+            serial,
+            element_type: element_type.value(),
+        });
+
         Ok(())
     }
+
+    pub(crate) fn write(
+        header: &DataElementHeader,
+        package: &DataElementPackage,
+        writer: Writer,
+    ) -> Result<()> {
+        let mut data = vec![];
+        header.id.write(&mut data)?;
+        header.serial.write(&mut data)?;
+        CompactU64::new(header.element_type).write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::DataElement, true, data.len()).write_16(writer)?;
+        writer.extend_from_slice(&data);
+
+        let id = header.id;
+        let missing = || ErrorKind::MalformedFssHttpBData("data element is missing".into());
+
+        match header.element_type {
+            0x01 => package
+                .storage_indexes
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            0x02 => package
+                .storage_manifests
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            0x03 => Self::write_cell_manifest(
+                *package.cell_manifests.get(&id).ok_or_else(missing)?,
+                writer,
+            )?,
+            0x04 => package
+                .revision_manifests
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            0x05 => package
+                .object_groups
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            0x06 => package
+                .data_element_fragments
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            0x0A => package
+                .object_data_blobs
+                .get(&id)
+                .ok_or_else(missing)?
+                .write(writer)?,
+            x => {
+                return Err(ErrorKind::MalformedFssHttpBData(
+                    format!("invalid element type: 0x{:X}", x).into(),
+                )
+                .into())
+            }
+        }
+
+        ObjectHeader::write_end_8(writer, ObjectType::DataElement)
+    }
 }
@@ -3,36 +3,59 @@ use crate::fsshttpb::data::binary_item::BinaryItem;
 use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::{Reader, Writer};
+use std::borrow::Cow;
 use std::fmt;
 
 /// An object data blob.
 ///
+/// Blobs are borrowed from the file buffer unless they were reassembled from data element
+/// fragments.
+///
 /// See [\[MS-FSSHTTPB\] 2.2.1.12.8]
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12.8]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/d36dd2b4-bad1-441b-93c7-adbe3069152c
-pub(crate) struct ObjectDataBlob(Vec<u8>);
+pub(crate) struct ObjectDataBlob<'a>(Cow<'a, [u8]>);
+
+impl<'a> ObjectDataBlob<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> ObjectDataBlob<'a> {
+        ObjectDataBlob(Cow::Borrowed(data))
+    }
+
+    pub(crate) fn value(&self) -> Cow<'a, [u8]> {
+        self.0.clone()
+    }
+
+    /// Copy the blob's data so it no longer borrows from the buffer it was parsed from.
+    pub(crate) fn into_owned(self) -> ObjectDataBlob<'static> {
+        ObjectDataBlob(Cow::Owned(self.0.into_owned()))
+    }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        BinaryItem::write(&self.0, &mut data)?;
+
+        ObjectHeader::new(ObjectType::ObjectDataBlob, false, data.len()).write(writer)?;
+        writer.extend_from_slice(&data);
 
-impl ObjectDataBlob {
-    pub(crate) fn value(&self) -> &[u8] {
-        &self.0
+        Ok(())
     }
 }
 
-impl fmt::Debug for ObjectDataBlob {
+impl fmt::Debug for ObjectDataBlob<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "ObjectDataBlob({} bytes)", self.0.len())
     }
 }
 
 impl DataElement {
-    pub(crate) fn parse_object_data_blob(reader: Reader) -> Result<ObjectDataBlob> {
+    pub(crate) fn parse_object_data_blob<'a>(reader: Reader<'a, '_>) -> Result<ObjectDataBlob<'a>> {
         ObjectHeader::try_parse(reader, ObjectType::ObjectDataBlob)?;
 
-        let data = BinaryItem::parse(reader)?;
+        let data = BinaryItem::parse_borrowed(reader)?;
 
         ObjectHeader::try_parse_end_8(reader, ObjectType::DataElement)?;
 
-        Ok(ObjectDataBlob(data.value()))
+        Ok(ObjectDataBlob::new(data))
     }
 }
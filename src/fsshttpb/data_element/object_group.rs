@@ -6,7 +6,7 @@ use crate::fsshttpb::data::exguid::ExGuid;
 use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::{Reader, Writer};
 use std::fmt;
 
 /// An object group.
@@ -15,6 +15,7 @@ use std::fmt;
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12.6]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/21404be6-0334-490e-80b5-82fccb9c04af
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct ObjectGroup {
     pub(crate) declarations: Vec<ObjectGroupDeclaration>,
     pub(crate) metadata: Vec<ObjectGroupMetadata>,
@@ -27,6 +28,7 @@ pub(crate) struct ObjectGroup {
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12.6.1]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/ef660e4b-a099-4e76-81f7-ed5c04a70caa
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) enum ObjectGroupDeclaration {
     Object {
         object_id: ExGuid,
@@ -67,11 +69,12 @@ impl ObjectGroupDeclaration {
 /// [\[MS-FSSHTTPB\] 2.2.1.12.6.3]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/d35a8e21-e139-455c-a20b-3f47a5d9fb89
 /// [\[MS-FSSHTTPB\] 2.2.1.12.6.3.1]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/507c6b42-2772-4319-b530-8fbbf4d34afd
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct ObjectGroupMetadata {
     pub(crate) change_frequency: ObjectChangeFrequency,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum ObjectChangeFrequency {
     Unknown = 0,
     Frequent = 1,
@@ -129,6 +132,128 @@ pub(crate) enum ObjectGroupData {
     },
 }
 
+impl ObjectGroup {
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        ObjectHeader::new(ObjectType::ObjectGroupDeclaration, true, 0).write(writer)?;
+
+        for declaration in &self.declarations {
+            declaration.write(writer)?;
+        }
+
+        ObjectHeader::write_end_8(writer, ObjectType::ObjectGroupDeclaration)?;
+
+        if !self.metadata.is_empty() {
+            ObjectHeader::new(ObjectType::ObjectGroupMetadataBlock, true, 0).write(writer)?;
+
+            for metadata in &self.metadata {
+                let mut data = vec![];
+                CompactU64::new(metadata.change_frequency as u64).write(&mut data)?;
+
+                ObjectHeader::new(ObjectType::ObjectGroupMetadata, false, data.len())
+                    .write_32(writer)?;
+                writer.extend_from_slice(&data);
+            }
+
+            ObjectHeader::write_end_8(writer, ObjectType::ObjectGroupMetadataBlock)?;
+        }
+
+        ObjectHeader::new(ObjectType::ObjectGroupData, true, 0).write(writer)?;
+
+        for object in &self.objects {
+            object.write(writer)?;
+        }
+
+        ObjectHeader::write_end_8(writer, ObjectType::ObjectGroupData)
+    }
+}
+
+impl ObjectGroupDeclaration {
+    fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+
+        let object_type = match self {
+            ObjectGroupDeclaration::Object {
+                object_id,
+                partition_id,
+                data_size,
+                object_reference_count,
+                cell_reference_count,
+            } => {
+                object_id.write(&mut data)?;
+                CompactU64::new(*partition_id).write(&mut data)?;
+                CompactU64::new(*data_size).write(&mut data)?;
+                CompactU64::new(*object_reference_count).write(&mut data)?;
+                CompactU64::new(*cell_reference_count).write(&mut data)?;
+
+                ObjectType::ObjectGroupObject
+            }
+            ObjectGroupDeclaration::Blob {
+                object_id,
+                blob_id,
+                partition_id,
+                object_reference_count,
+                cell_reference_count,
+            } => {
+                object_id.write(&mut data)?;
+                blob_id.write(&mut data)?;
+                CompactU64::new(*partition_id).write(&mut data)?;
+                CompactU64::new(*object_reference_count).write(&mut data)?;
+                CompactU64::new(*cell_reference_count).write(&mut data)?;
+
+                ObjectType::ObjectGroupDataBlob
+            }
+        };
+
+        ObjectHeader::new(object_type, false, data.len()).write(writer)?;
+        writer.extend_from_slice(&data);
+
+        Ok(())
+    }
+}
+
+impl ObjectGroupData {
+    fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+
+        let object_type = match self {
+            ObjectGroupData::Object {
+                group,
+                cells,
+                data: object_data,
+            } => {
+                ExGuid::write_array(group, &mut data)?;
+                CellId::write_array(cells, &mut data)?;
+                BinaryItem::write(object_data, &mut data)?;
+
+                ObjectType::ObjectGroupDataObject
+            }
+            ObjectGroupData::ObjectExcluded { group, cells, size } => {
+                ExGuid::write_array(group, &mut data)?;
+                CellId::write_array(cells, &mut data)?;
+                CompactU64::new(*size).write(&mut data)?;
+
+                ObjectType::ObjectGroupDataExcluded
+            }
+            ObjectGroupData::BlobReference {
+                objects,
+                cells,
+                blob,
+            } => {
+                ExGuid::write_array(objects, &mut data)?;
+                CellId::write_array(cells, &mut data)?;
+                blob.write(&mut data)?;
+
+                ObjectType::ObjectGroupBlobReference
+            }
+        };
+
+        ObjectHeader::new(object_type, false, data.len()).write(writer)?;
+        writer.extend_from_slice(&data);
+
+        Ok(())
+    }
+}
+
 struct DebugSize(usize);
 
 impl fmt::Debug for ObjectGroupData {
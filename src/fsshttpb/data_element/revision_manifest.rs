@@ -3,7 +3,7 @@ use crate::fsshttpb::data::exguid::ExGuid;
 use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::{Reader, Writer};
 
 /// A revision manifest.
 ///
@@ -25,6 +25,39 @@ pub(crate) struct RevisionManifestRootDeclare {
     pub(crate) object_id: ExGuid,
 }
 
+impl RevisionManifest {
+    /// Write the revision manifest.
+    ///
+    /// The root declarations are written before the object group references.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        self.rev_id.write(&mut data)?;
+        self.base_rev_id.write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::RevisionManifest, false, data.len()).write_16(writer)?;
+        writer.extend_from_slice(&data);
+
+        for root in &self.root_declare {
+            root.write(writer)?;
+        }
+
+        for reference in &self.group_references {
+            let mut data = vec![];
+            reference.write(&mut data)?;
+
+            ObjectHeader::new(
+                ObjectType::RevisionManifestGroupReference,
+                false,
+                data.len(),
+            )
+            .write_16(writer)?;
+            writer.extend_from_slice(&data);
+        }
+
+        Ok(())
+    }
+}
+
 impl RevisionManifestRootDeclare {
     fn parse(reader: Reader) -> Result<RevisionManifestRootDeclare> {
         let root_id = ExGuid::parse(reader)?;
@@ -32,6 +65,17 @@ impl RevisionManifestRootDeclare {
 
         Ok(RevisionManifestRootDeclare { root_id, object_id })
     }
+
+    fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        self.root_id.write(&mut data)?;
+        self.object_id.write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::RevisionManifestRoot, false, data.len()).write_16(writer)?;
+        writer.extend_from_slice(&data);
+
+        Ok(())
+    }
 }
 
 impl DataElement {
@@ -5,7 +5,7 @@ use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::serial_number::SerialNumber;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
-use crate::Reader;
+use crate::{Reader, Writer};
 use std::collections::HashMap;
 
 /// A storage index.
@@ -14,7 +14,9 @@ use std::collections::HashMap;
 ///
 /// [\[MS-FSSHTTPB\] 2.2.1.12.2]: https://docs.microsoft.com/en-us/openspecs/sharepoint_protocols/ms-fsshttpb/f5724986-bd0f-488d-9b85-7d5f954d8e9a
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct StorageIndex {
+    pub(crate) entries: Vec<StorageIndexEntry>,
     pub(crate) manifest_mappings: Vec<StorageIndexManifestMapping>,
     pub(crate) cell_mappings: HashMap<CellId, StorageIndexCellMapping>,
     pub(crate) revision_mappings: HashMap<ExGuid, StorageIndexRevisionMapping>,
@@ -30,10 +32,62 @@ impl StorageIndex {
             .get(&id)
             .map(|mapping| mapping.revision_mapping)
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let missing =
+            || ErrorKind::MalformedFssHttpBData("storage index mapping is missing".into());
+
+        for entry in &self.entries {
+            let mut data = vec![];
+
+            let object_type = match entry {
+                StorageIndexEntry::Manifest(index) => {
+                    let mapping = self.manifest_mappings.get(*index).ok_or_else(missing)?;
+                    mapping.mapping_id.write(&mut data)?;
+                    mapping.serial.write(&mut data)?;
+
+                    ObjectType::StorageIndexManifestMapping
+                }
+                StorageIndexEntry::Cell(cell_id) => {
+                    let mapping = self.cell_mappings.get(cell_id).ok_or_else(missing)?;
+                    mapping.cell_id.write(&mut data)?;
+                    mapping.id.write(&mut data)?;
+                    mapping.serial.write(&mut data)?;
+
+                    ObjectType::StorageIndexCellMapping
+                }
+                StorageIndexEntry::Revision(id) => {
+                    let mapping = self.revision_mappings.get(id).ok_or_else(missing)?;
+                    id.write(&mut data)?;
+                    mapping.revision_mapping.write(&mut data)?;
+                    mapping.serial.write(&mut data)?;
+
+                    ObjectType::StorageIndexRevisionMapping
+                }
+            };
+
+            ObjectHeader::new(object_type, false, data.len()).write_16(writer)?;
+            writer.extend_from_slice(&data);
+        }
+
+        Ok(())
+    }
+}
+
+/// A storage index mapping in the order the mappings are stored in the storage index.
+#[derive(Debug)]
+pub(crate) enum StorageIndexEntry {
+    /// A manifest mapping referenced by its index.
+    Manifest(usize),
+    /// A cell mapping referenced by its cell ID.
+    Cell(CellId),
+    /// A revision mapping referenced by its revision ID.
+    Revision(ExGuid),
 }
 
 /// A storage indexes manifest mapping.
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct StorageIndexManifestMapping {
     pub(crate) mapping_id: ExGuid,
     pub(crate) serial: SerialNumber,
@@ -41,6 +95,7 @@ pub(crate) struct StorageIndexManifestMapping {
 
 /// A storage indexes cell mapping.
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct StorageIndexCellMapping {
     pub(crate) cell_id: CellId,
     pub(crate) id: ExGuid,
@@ -49,6 +104,7 @@ pub(crate) struct StorageIndexCellMapping {
 
 /// A storage indexes revision mapping.
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct StorageIndexRevisionMapping {
     pub(crate) revision_mapping: ExGuid,
     pub(crate) serial: SerialNumber,
@@ -56,6 +112,7 @@ pub(crate) struct StorageIndexRevisionMapping {
 
 impl DataElement {
     pub(crate) fn parse_storage_index(reader: Reader) -> Result<StorageIndex> {
+        let mut entries = vec![];
         let mut manifest_mappings = vec![];
         let mut cell_mappings = HashMap::new();
         let mut revision_mappings = HashMap::new();
@@ -68,16 +125,19 @@ impl DataElement {
             let object_header = ObjectHeader::parse_16(reader)?;
             match object_header.object_type {
                 ObjectType::StorageIndexManifestMapping => {
+                    entries.push(StorageIndexEntry::Manifest(manifest_mappings.len()));
                     manifest_mappings.push(Self::parse_storage_index_manifest_mapping(reader)?)
                 }
                 ObjectType::StorageIndexCellMapping => {
                     let (id, mapping) = Self::parse_storage_index_cell_mapping(reader)?;
 
+                    entries.push(StorageIndexEntry::Cell(id));
                     cell_mappings.insert(id, mapping);
                 }
                 ObjectType::StorageIndexRevisionMapping => {
                     let (id, mapping) = Self::parse_storage_index_revision_mapping(reader)?;
 
+                    entries.push(StorageIndexEntry::Revision(id));
                     revision_mappings.insert(id, mapping);
                 }
                 _ => {
@@ -92,6 +152,7 @@ impl DataElement {
         ObjectHeader::try_parse_end_8(reader, ObjectType::DataElement)?;
 
         Ok(StorageIndex {
+            entries,
             manifest_mappings,
             cell_mappings,
             revision_mappings,
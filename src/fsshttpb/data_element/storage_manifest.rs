@@ -5,8 +5,7 @@ use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElement;
 use crate::shared::guid::Guid;
-use crate::Reader;
-use std::collections::HashMap;
+use crate::{Reader, Writer};
 
 /// A storage manifest.
 ///
@@ -16,7 +15,37 @@ use std::collections::HashMap;
 #[derive(Debug)]
 pub(crate) struct StorageManifest {
     pub(crate) id: Guid,
-    pub(crate) roots: HashMap<ExGuid, CellId>,
+    pub(crate) roots: Vec<(ExGuid, CellId)>,
+}
+
+impl StorageManifest {
+    /// Look up the cell of a storage manifest root by the root's ID.
+    pub(crate) fn find_root(&self, id: ExGuid) -> Option<CellId> {
+        self.roots
+            .iter()
+            .find(|(root_id, _)| *root_id == id)
+            .map(|(_, cell)| *cell)
+    }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let mut data = vec![];
+        self.id.write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::StorageManifest, false, data.len()).write_16(writer)?;
+        writer.extend_from_slice(&data);
+
+        for (root_manifest, cell) in &self.roots {
+            let mut data = vec![];
+            root_manifest.write(&mut data)?;
+            cell.write(&mut data)?;
+
+            ObjectHeader::new(ObjectType::StorageManifestRoot, false, data.len())
+                .write_16(writer)?;
+            writer.extend_from_slice(&data);
+        }
+
+        Ok(())
+    }
 }
 
 impl DataElement {
@@ -25,7 +54,7 @@ impl DataElement {
 
         let id = Guid::parse(reader)?;
 
-        let mut roots = HashMap::new();
+        let mut roots = vec![];
 
         loop {
             if ObjectHeader::has_end_8(reader, ObjectType::DataElement)? {
@@ -37,7 +66,7 @@ impl DataElement {
             let root_manifest = ExGuid::parse(reader)?;
             let cell = CellId::parse(reader)?;
 
-            roots.insert(root_manifest, cell);
+            roots.push((root_manifest, cell));
         }
 
         ObjectHeader::try_parse_end_8(reader, ObjectType::DataElement)?;
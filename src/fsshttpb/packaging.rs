@@ -4,7 +4,8 @@ use crate::fsshttpb::data::object_types::ObjectType;
 use crate::fsshttpb::data::stream_object::ObjectHeader;
 use crate::fsshttpb::data_element::DataElementPackage;
 use crate::shared::guid::Guid;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 
 /// A OneNote file packaged in FSSHTTPB format.
 ///
@@ -12,18 +13,21 @@ use crate::Reader;
 ///
 /// [\[MS-ONESTORE\] 2.8.1]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/a2f046ea-109a-49c4-912d-dc2888cf0565
 #[derive(Debug)]
-pub(crate) struct OneStorePackaging {
+#[allow(dead_code)]
+pub(crate) struct OneStorePackaging<'a> {
     pub(crate) file_type: Guid,
     pub(crate) file: Guid,
     pub(crate) legacy_file_version: Guid,
     pub(crate) file_format: Guid,
     pub(crate) storage_index: ExGuid,
     pub(crate) cell_schema: Guid,
-    pub(crate) data_element_package: DataElementPackage,
+    pub(crate) data_element_package: DataElementPackage<'a>,
+    /// The data following the packaging's end (usually zero padding).
+    pub(crate) trailing_data: &'a [u8],
 }
 
-impl OneStorePackaging {
-    pub(crate) fn parse(reader: Reader) -> Result<OneStorePackaging> {
+impl<'a> OneStorePackaging<'a> {
+    pub(crate) fn parse(reader: Reader<'a, '_>) -> Result<OneStorePackaging<'a>> {
         let file_type = Guid::parse(reader)?;
         let file = Guid::parse(reader)?;
         let legacy_file_version = Guid::parse(reader)?;
@@ -48,6 +52,8 @@ impl OneStorePackaging {
 
         ObjectHeader::try_parse_end_16(reader, ObjectType::OneNotePackaging)?;
 
+        let trailing_data = reader.read(reader.remaining())?;
+
         Ok(OneStorePackaging {
             file_type,
             file,
@@ -56,6 +62,30 @@ impl OneStorePackaging {
             storage_index,
             cell_schema,
             data_element_package,
+            trailing_data,
         })
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        self.file_type.write(writer)?;
+        self.file.write(writer)?;
+        self.legacy_file_version.write(writer)?;
+        self.file_format.write(writer)?;
+        writer.put_u32_le(0);
+
+        let mut data = vec![];
+        self.storage_index.write(&mut data)?;
+        self.cell_schema.write(&mut data)?;
+
+        ObjectHeader::new(ObjectType::OneNotePackaging, true, data.len()).write_32(writer)?;
+        writer.extend_from_slice(&data);
+
+        self.data_element_package.write(writer)?;
+
+        ObjectHeader::write_end_16(writer, ObjectType::OneNotePackaging)?;
+
+        writer.extend_from_slice(self.trailing_data);
+
+        Ok(())
+    }
 }
@@ -13,43 +13,85 @@ mod one;
 mod onenote;
 mod onestore;
 mod reader;
+pub mod render;
+#[cfg(feature = "search")]
+pub mod search;
 mod shared;
 mod utils;
 
 pub(crate) type Reader<'a, 'b> = &'b mut crate::reader::Reader<'a>;
+pub(crate) type Writer<'b> = &'b mut Vec<u8>;
 
+pub use crate::onenote::options::ParseOptions;
 pub use crate::onenote::Parser;
 
+/// Identifiers used by the OneNote file format.
+pub mod ids {
+    pub use crate::fsshttpb::data::cell_id::CellId;
+    pub use crate::fsshttpb::data::exguid::ExGuid;
+    pub use crate::shared::guid::Guid;
+}
+
 /// The data that represents a OneNote notebook.
 pub mod notebook {
+    pub use crate::onenote::link_index::LinkIndex;
     pub use crate::onenote::notebook::Notebook;
+    pub use crate::onenote::resolver::{NotebookResolver, ResolvedEntry};
+    pub use crate::onenote::source::{FsSource, NotebookSource};
+
+    #[cfg(feature = "zip")]
+    pub use crate::onenote::source::ZipSource;
 }
 
 /// The data that represents a OneNote section.
 pub mod section {
+    pub use crate::onenote::lazy_section::LazySection;
     pub use crate::onenote::section::{Section, SectionEntry, SectionGroup};
 }
 
 /// The data that represents a OneNote page.
 pub mod page {
-    pub use crate::onenote::page::{Page, Title};
+    pub use crate::onenote::lazy_section::PageHeader;
+    pub use crate::onenote::page::{Page, PageRevision, Title};
     pub use crate::onenote::page_content::PageContent;
     pub use crate::onenote::page_series::PageSeries;
+    pub use crate::onenote::printout::Printout;
+    pub use crate::onenote::recording::{Recording, TimedNote};
 }
 
 /// The data that represents the contents of a OneNote section.
 pub mod contents {
     pub use crate::onenote::content::Content;
     pub use crate::onenote::embedded_file::EmbeddedFile;
-    pub use crate::onenote::image::Image;
+    pub use crate::onenote::image::{Image, ImageUploadState};
     pub use crate::onenote::ink::{Ink, InkBoundingBox, InkPoint, InkStroke};
     pub use crate::onenote::list::List;
     pub use crate::onenote::note_tag::NoteTag;
     pub use crate::onenote::outline::{Outline, OutlineElement, OutlineGroup, OutlineItem};
     pub use crate::onenote::rich_text::{
-        EmbeddedInkContainer, EmbeddedInkSpace, EmbeddedObject, ParagraphStyling, RichText,
+        EmbeddedInkContainer, EmbeddedInkSpace, EmbeddedObject, Link, ParagraphStyling, RichText,
     };
     pub use crate::onenote::table::{Table, TableCell, TableRow};
+    pub use crate::onenote::unknown::{UnknownContent, UnknownContentType};
+}
+
+/// Builders to create new OneNote sections.
+pub mod builder {
+    pub use crate::onenote::builder::embedded_file::EmbeddedFileBuilder;
+    pub use crate::onenote::builder::image::ImageBuilder;
+    pub use crate::onenote::builder::outline::{OutlineBuilder, OutlineElementBuilder};
+    pub use crate::onenote::builder::page::PageBuilder;
+    pub use crate::onenote::builder::rich_text::RichTextBuilder;
+    pub use crate::onenote::builder::table::{TableBuilder, TableCellBuilder, TableRowBuilder};
+    pub use crate::onenote::builder::{PageSeriesBuilder, SectionBuilder};
+}
+
+/// Read-only access to the object graph of OneNote files.
+///
+/// This exposes the objects and properties OneNote files are made of, including those that
+/// aren't modeled by this crate. See [`RawStore`](crate::raw::RawStore).
+pub mod raw {
+    pub use crate::onenote::raw::{RawObject, RawObjectSpace, RawProperty, RawStore, RawValue};
 }
 
 /// Collection of properties used by the OneNote file format.
@@ -58,11 +100,13 @@ pub mod property {
     pub mod common {
         pub use crate::one::property::color::Color;
         pub use crate::one::property::color_ref::ColorRef;
+        pub use crate::one::property::time::{Time, Timestamp};
     }
 
     /// Properties related to embedded files.
     pub mod embedded_file {
         pub use crate::one::property::file_type::FileType;
+        pub use crate::onenote::recording::RecordingInfo;
     }
 
     /// Properties related to note tags.
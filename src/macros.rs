@@ -13,6 +13,10 @@ macro_rules! guid {
         ))
         .unwrap()
     };
+    // GUIDs like `{1791EDC8AED8}` can't be written as tokens as they look like float literals
+    ($guid:literal) => {
+        crate::shared::guid::Guid(uuid::uuid!($guid))
+    };
 }
 
 macro_rules! exguid {
@@ -36,6 +40,16 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_guid_literal() {
+        let guid = guid!("109ADD3F-911B-49F5-A5D0-1791EDC8AED8");
+
+        assert_eq!(
+            guid,
+            Guid::from_str("109ADD3F-911B-49F5-A5D0-1791EDC8AED8").unwrap()
+        );
+    }
+
     #[test]
     fn parse_exguid() {
         let guid = exguid!({{1A5A319C-C26B-41AA-B9C5-9BD8C44E07D4}, 1});
@@ -1,6 +1,7 @@
 use crate::errors::Result;
 use crate::one::property::{simple, PropertyType};
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// The author of an object.
 ///
@@ -11,6 +12,10 @@ use crate::onestore::object::Object;
 pub(crate) struct Author(String);
 
 impl Author {
+    pub(crate) fn new(name: String) -> Author {
+        Author(name)
+    }
+
     pub(crate) fn into_value(self) -> String {
         self.0
     }
@@ -18,4 +23,12 @@ impl Author {
     pub(crate) fn parse(object: &Object) -> Result<Option<Author>> {
         Ok(simple::parse_string(PropertyType::Author, object)?.map(Author))
     }
+
+    pub(crate) fn write(value: Option<&Author>, object: &mut ObjectBuilder) {
+        simple::write_string(
+            PropertyType::Author,
+            value.map(|author| author.0.as_str()),
+            object,
+        )
+    }
 }
@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// A charset representation.
 ///
@@ -70,4 +72,35 @@ impl Charset {
 
         Ok(Some(charset))
     }
+
+    pub(crate) fn write(
+        prop_type: PropertyType,
+        value: Option<Charset>,
+        object: &mut ObjectBuilder,
+    ) {
+        let value = match value {
+            Some(Charset::Ansi) => 0,
+            Some(Charset::Default) => 1,
+            Some(Charset::Symbol) => 2,
+            Some(Charset::Mac) => 77,
+            Some(Charset::ShiftJis) => 128,
+            Some(Charset::Hangul) => 129,
+            Some(Charset::Johab) => 130,
+            Some(Charset::Gb2312) => 134,
+            Some(Charset::ChineseBig5) => 136,
+            Some(Charset::Greek) => 161,
+            Some(Charset::Turkish) => 162,
+            Some(Charset::Vietnamese) => 163,
+            Some(Charset::Hebrew) => 177,
+            Some(Charset::Arabic) => 178,
+            Some(Charset::Baltic) => 186,
+            Some(Charset::Russian) => 204,
+            Some(Charset::Thai) => 222,
+            Some(Charset::EastEurope) => 238,
+            Some(Charset::Oem) => 255,
+            None => return,
+        };
+
+        object.set(prop_type, PropertyValue::U8(value));
+    }
 }
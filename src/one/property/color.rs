@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// A RGBA color value.
 ///
@@ -55,4 +57,12 @@ impl Color {
             b: bytes[2],
         }))
     }
+
+    pub(crate) fn write(prop_type: PropertyType, value: Option<Color>, object: &mut ObjectBuilder) {
+        if let Some(color) = value {
+            let value = u32::from_le_bytes([color.r, color.g, color.b, 255 - color.alpha]);
+
+            object.set(prop_type, PropertyValue::U32(value));
+        }
+    }
 }
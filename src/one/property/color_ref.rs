@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// An RGB color value.
 ///
@@ -51,4 +53,18 @@ impl ColorRef {
 
         Ok(Some(color))
     }
+
+    pub(crate) fn write(
+        prop_type: PropertyType,
+        value: Option<ColorRef>,
+        object: &mut ObjectBuilder,
+    ) {
+        let value = match value {
+            Some(ColorRef::Auto) => 0xFF000000,
+            Some(ColorRef::Manual { r, g, b }) => u32::from_le_bytes([r, g, b, 0]),
+            None => return,
+        };
+
+        object.set(prop_type, PropertyValue::U32(value));
+    }
 }
@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// An embedded file's file type.
 ///
@@ -41,4 +43,14 @@ impl FileType {
 
         Ok(file_type)
     }
+
+    pub(crate) fn write(&self, object: &mut ObjectBuilder) {
+        let value = match self {
+            FileType::Unknown => return,
+            FileType::Audio => 1,
+            FileType::Video => 2,
+        };
+
+        object.set(PropertyType::IRecordMedia, PropertyValue::U32(value));
+    }
 }
@@ -4,15 +4,35 @@ use crate::onestore::object::Object;
 use crate::reader::Reader;
 use crate::shared::guid::Guid;
 
+const UNITS_INCHES: u32 = 1;
+const UNITS_CENTIMETERS: u32 = 2;
+
 /// The dimensions (X or Y) for an ink stoke with lower and upper limits.
+///
+/// The entries match the metric entries used by the Ink Serialized Format: besides the
+/// limits they specify the unit of the coordinates and the resolution (the number of
+/// coordinate units per unit).
 #[allow(dead_code)]
 pub(crate) struct InkDimension {
     pub(crate) id: Guid,
     pub(crate) limit_lower: i32,
     pub(crate) limit_upper: i32,
+    pub(crate) units: u32,
+    pub(crate) resolution: f32,
 }
 
 impl InkDimension {
+    /// The number of coordinate units per half-inch, if the dimension's unit is a length.
+    pub(crate) fn units_per_half_inch(&self) -> Option<f32> {
+        let units_per_inch = match self.units {
+            UNITS_INCHES => self.resolution,
+            UNITS_CENTIMETERS => self.resolution * 2.54,
+            _ => return None,
+        };
+
+        Some(units_per_inch / 2.0).filter(|units| units.is_normal() && *units > 0.0)
+    }
+
     pub(crate) fn parse(prop_type: PropertyType, object: &Object) -> Result<Vec<InkDimension>> {
         let data = match object.props().get(prop_type) {
             Some(value) => value.to_vec().ok_or_else(|| {
@@ -31,11 +51,15 @@ impl InkDimension {
         let id = Guid::parse(&mut reader)?;
         let limit_lower = reader.get_u32()? as i32;
         let limit_upper = reader.get_u32()? as i32;
+        let units = reader.get_u32()?;
+        let resolution = reader.get_f32()?;
 
         Ok(InkDimension {
             id,
             limit_lower,
             limit_upper,
+            units,
+            resolution,
         })
     }
 }
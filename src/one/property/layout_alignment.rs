@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// A layout alignment specification.
 ///
@@ -61,6 +63,21 @@ impl LayoutAlignment {
             alignment_margin_vertical,
         }))
     }
+
+    pub(crate) fn write(
+        prop_type: PropertyType,
+        value: Option<LayoutAlignment>,
+        object: &mut ObjectBuilder,
+    ) {
+        if let Some(alignment) = value {
+            let value = alignment.alignment_horizontal as u32
+                | (alignment.alignment_margin_horizontal as u32) << 3
+                | (alignment.alignment_vertical as u32) << 16
+                | (alignment.alignment_margin_vertical as u32) << 19;
+
+            object.set(prop_type, PropertyValue::U32(value));
+        }
+    }
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -4,6 +4,9 @@
 //!
 //! [\[MS-ONE\] 2.1.12]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e9bf7da8-7aab-4668-be5e-e0c421175e3c
 
+use enum_primitive_derive::Primitive;
+use num_traits::FromPrimitive;
+
 pub(crate) mod author;
 pub(crate) mod charset;
 pub(crate) mod color;
@@ -23,7 +26,7 @@ mod references;
 pub(crate) mod simple;
 pub(crate) mod time;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Primitive)]
 #[allow(dead_code)]
 #[allow(clippy::enum_clike_unportable_variant)]
 pub(crate) enum PropertyType {
@@ -182,8 +185,8 @@ pub(crate) enum PropertyType {
     InkPath = 0x1C00340B,
     InkStrokeProperties = 0x20003409,
     InkStrokes = 0x24003416,
-    InkAntialised = 0x8000340E,
-    InkFitToCurve = 0x80003410,
+    InkAntialised = 0x0800340E,
+    InkFitToCurve = 0x08003410,
     InkIgnorePressure = 0x08003411,
     InkPenTip = 0x0C003412,
     InkRasterOperation = 0x0C003413,
@@ -206,3 +209,17 @@ pub(crate) enum PropertyType {
     ImageEmbedType = 0x140035F2,
     ImageEmbeddedUrl = 0x1C0035F3,
 }
+
+impl PropertyType {
+    /// Look up the property type with the given property ID.
+    ///
+    /// The property ID's type bits are ignored since some property types are declared with a
+    /// different type than the one OneNote stores them with.
+    pub(crate) fn from_id(id: u32) -> Option<PropertyType> {
+        let id = id & 0x3ffffff;
+
+        (0..0x20)
+            .map(|prop_type| id | prop_type << 26)
+            .find_map(PropertyType::from_u32)
+    }
+}
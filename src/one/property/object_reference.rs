@@ -3,6 +3,7 @@ use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property::references::References;
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 use crate::onestore::types::compact_id::CompactId;
 use crate::onestore::types::property::PropertyValue;
 
@@ -25,12 +26,10 @@ impl ObjectReference {
         // Find the correct object reference
         let index = Self::get_offset(prop_type, object)?;
 
-        let id = object
-            .props()
-            .object_ids()
-            .iter()
-            .nth(index)
-            .ok_or_else(|| ErrorKind::MalformedOneNoteFileData("object id index corrupt".into()))?;
+        let id =
+            object.props().object_ids().get(index).ok_or_else(|| {
+                ErrorKind::MalformedOneNoteFileData("object id index corrupt".into())
+            })?;
 
         Ok(Self::resolve_id(index, id, object))
     }
@@ -87,6 +86,18 @@ impl ObjectReference {
             .sum()
     }
 
+    pub(crate) fn write(prop_type: PropertyType, id: Option<ExGuid>, object: &mut ObjectBuilder) {
+        if let Some(id) = id {
+            object.set(prop_type, PropertyValue::ObjectId);
+            object.add_object_refs(&[id]);
+        }
+    }
+
+    pub(crate) fn write_vec(prop_type: PropertyType, ids: &[ExGuid], object: &mut ObjectBuilder) {
+        object.set(prop_type, PropertyValue::ObjectIds(ids.len() as u32));
+        object.add_object_refs(ids);
+    }
+
     fn resolve_id(index: usize, id: &CompactId, object: &Object) -> Option<ExGuid> {
         object.mapping().get_object(index, *id)
     }
@@ -3,6 +3,7 @@ use crate::fsshttpb::data::cell_id::CellId;
 use crate::one::property::references::References;
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 use crate::onestore::types::compact_id::CompactId;
 use crate::onestore::types::property::PropertyValue;
 
@@ -66,6 +67,11 @@ impl ObjectSpaceReference {
             .sum()
     }
 
+    pub(crate) fn write_vec(prop_type: PropertyType, ids: &[CellId], object: &mut ObjectBuilder) {
+        object.set(prop_type, PropertyValue::ObjectSpaceIds(ids.len() as u32));
+        object.add_object_space_refs(ids);
+    }
+
     fn resolve_id(index: usize, id: &CompactId, object: &Object) -> Result<CellId> {
         object
             .mapping()
@@ -1,12 +1,18 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 use crate::reader::Reader;
 
 #[derive(Debug, Clone)]
 pub struct OutlineIndentDistance(Vec<f32>);
 
 impl OutlineIndentDistance {
+    pub(crate) fn new(distances: Vec<f32>) -> OutlineIndentDistance {
+        OutlineIndentDistance(distances)
+    }
+
     pub fn value(&self) -> &[f32] {
         &self.0
     }
@@ -33,4 +39,14 @@ impl OutlineIndentDistance {
 
         Ok(Some(OutlineIndentDistance(distances)))
     }
+
+    pub(crate) fn write(&self, object: &mut ObjectBuilder) {
+        let mut data = vec![self.0.len() as u8, 0, 0, 0];
+        data.extend(self.0.iter().flat_map(|distance| distance.to_le_bytes()));
+
+        object.set(
+            PropertyType::RgOutlineIndentDistance,
+            PropertyValue::Vec(data),
+        );
+    }
 }
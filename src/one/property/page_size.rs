@@ -1,14 +1,17 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// A page size declaration.
 ///
 /// See [\[MS-ONE\] 2.3.36].
 ///
 /// [\[MS-ONE\] 2.3.36]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/8866c05a-602d-4868-95de-2d8b1a0b9d2e
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) enum PageSize {
+    #[default]
     Auto,
     Us,
     AnsiLetter,
@@ -63,10 +66,27 @@ impl PageSize {
 
         Ok(Some(page_size))
     }
-}
 
-impl Default for PageSize {
-    fn default() -> Self {
-        PageSize::Auto
+    pub(crate) fn write(prop_type: PropertyType, value: &PageSize, object: &mut ObjectBuilder) {
+        let value = match value {
+            PageSize::Auto => 0,
+            PageSize::Us => 1,
+            PageSize::AnsiLetter => 2,
+            PageSize::AnsiTabloid => 3,
+            PageSize::UsLegal => 4,
+            PageSize::IsoA3 => 5,
+            PageSize::IsoA4 => 6,
+            PageSize::IsoA5 => 7,
+            PageSize::IsoA6 => 8,
+            PageSize::JisB4 => 9,
+            PageSize::JisB5 => 10,
+            PageSize::JisB6 => 11,
+            PageSize::JapanesePostcard => 12,
+            PageSize::IndexCard => 13,
+            PageSize::Billfold => 14,
+            PageSize::Custom => 15,
+        };
+
+        object.set(prop_type, PropertyValue::U8(value));
     }
 }
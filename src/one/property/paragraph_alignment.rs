@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 
 /// A paragraph's alignment.
 ///
@@ -8,9 +10,10 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.3.94]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/36edb135-5e8e-400f-9394-82853d662d90
 #[allow(missing_docs)]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
 pub enum ParagraphAlignment {
     Unknown,
+    #[default]
     Left,
     Center,
     Right,
@@ -32,10 +35,15 @@ impl ParagraphAlignment {
             _ => ParagraphAlignment::Unknown,
         }))
     }
-}
 
-impl Default for ParagraphAlignment {
-    fn default() -> Self {
-        ParagraphAlignment::Left
+    pub(crate) fn write(value: Option<ParagraphAlignment>, object: &mut ObjectBuilder) {
+        let value = match value {
+            Some(ParagraphAlignment::Left) => 0,
+            Some(ParagraphAlignment::Center) => 1,
+            Some(ParagraphAlignment::Right) => 2,
+            Some(ParagraphAlignment::Unknown) | None => return,
+        };
+
+        object.set(PropertyType::ParagraphAlignment, PropertyValue::U8(value));
     }
 }
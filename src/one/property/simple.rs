@@ -1,9 +1,11 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
 use crate::reader::Reader;
 use crate::shared::guid::Guid;
-use crate::utils::Utf16ToString;
+use crate::utils::{utf16_bytes, Utf16ToString};
 use encoding_rs::mem::decode_latin1;
 
 pub(crate) fn parse_bool(prop_type: PropertyType, object: &Object) -> Result<Option<bool>> {
@@ -157,3 +159,68 @@ pub(crate) fn parse_guid(prop_type: PropertyType, object: &Object) -> Result<Opt
 
     Ok(Some(Guid::parse(&mut Reader::new(data))?))
 }
+
+pub(crate) fn write_bool(prop_type: PropertyType, value: Option<bool>, object: &mut ObjectBuilder) {
+    if let Some(value) = value {
+        object.set(prop_type, PropertyValue::Bool(value));
+    }
+}
+
+pub(crate) fn write_u8(prop_type: PropertyType, value: Option<u8>, object: &mut ObjectBuilder) {
+    if let Some(value) = value {
+        object.set(prop_type, PropertyValue::U8(value));
+    }
+}
+
+pub(crate) fn write_u16(prop_type: PropertyType, value: Option<u16>, object: &mut ObjectBuilder) {
+    if let Some(value) = value {
+        object.set(prop_type, PropertyValue::U16(value));
+    }
+}
+
+pub(crate) fn write_u32(prop_type: PropertyType, value: Option<u32>, object: &mut ObjectBuilder) {
+    if let Some(value) = value {
+        object.set(prop_type, PropertyValue::U32(value));
+    }
+}
+
+pub(crate) fn write_f32(prop_type: PropertyType, value: Option<f32>, object: &mut ObjectBuilder) {
+    write_u32(prop_type, value.map(f32::to_bits), object)
+}
+
+pub(crate) fn write_vec(
+    prop_type: PropertyType,
+    value: Option<Vec<u8>>,
+    object: &mut ObjectBuilder,
+) {
+    if let Some(value) = value {
+        object.set(prop_type, PropertyValue::Vec(value));
+    }
+}
+
+pub(crate) fn write_vec_u32(
+    prop_type: PropertyType,
+    value: Option<Vec<u32>>,
+    object: &mut ObjectBuilder,
+) {
+    let data = value.map(|value| value.iter().flat_map(|v| v.to_le_bytes()).collect());
+
+    write_vec(prop_type, data, object)
+}
+
+/// Write a null-terminated UTF-16 string.
+pub(crate) fn write_string(
+    prop_type: PropertyType,
+    value: Option<&str>,
+    object: &mut ObjectBuilder,
+) {
+    let data = value.map(|value| utf16_bytes(&format!("{}\0", value)));
+
+    write_vec(prop_type, data, object)
+}
+
+pub(crate) fn write_guid(prop_type: PropertyType, value: Option<Guid>, object: &mut ObjectBuilder) {
+    let data = value.map(|guid| guid.0.to_bytes_le().to_vec());
+
+    write_vec(prop_type, data, object)
+}
@@ -1,6 +1,15 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property::PropertyType;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::property::PropertyValue;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The number of seconds between January 1, 1601 (UTC) and the Unix epoch.
+const FILETIME_UNIX_OFFSET: u64 = 11_644_473_600;
+
+/// The number of seconds between January 1, 1980 (UTC) and the Unix epoch.
+const TIME32_UNIX_OFFSET: u64 = 315_532_800;
 
 /// A 32 bit date/time timestamp.
 ///
@@ -11,6 +20,16 @@ use crate::onestore::object::Object;
 pub struct Time(u32);
 
 impl Time {
+    /// The number of seconds since midnight January 1, 1980 (UTC).
+    pub fn as_seconds(&self) -> u32 {
+        self.0
+    }
+
+    /// Convert the time to a system time.
+    pub fn to_system_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(TIME32_UNIX_OFFSET + self.0 as u64)
+    }
+
     pub(crate) fn parse(prop_type: PropertyType, object: &Object) -> Result<Option<Time>> {
         let time = object
             .props()
@@ -25,6 +44,25 @@ impl Time {
 
         Ok(time)
     }
+
+    /// Convert a system time to a 32 bit timestamp.
+    ///
+    /// Times outside of the supported range are clamped to the nearest supported time.
+    pub(crate) fn from_system_time(time: SystemTime) -> Time {
+        let seconds = time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default()
+            .saturating_sub(TIME32_UNIX_OFFSET);
+
+        Time(seconds.min(u32::MAX as u64) as u32)
+    }
+
+    pub(crate) fn write(prop_type: PropertyType, value: Option<Time>, object: &mut ObjectBuilder) {
+        if let Some(Time(value)) = value {
+            object.set(prop_type, PropertyValue::U32(value));
+        }
+    }
 }
 
 /// A 64 bit date/time timestamp.
@@ -32,10 +70,53 @@ impl Time {
 /// See [\[MS-DTYP\] 2.3.3]
 ///
 /// [\[MS-DTYP\] 2.3.3]: https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-dtyp/2c57429b-fdd4-488f-b5fc-9e4cf020fcdf
-#[derive(Debug)]
-pub(crate) struct Timestamp(u64);
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Timestamp(u64);
 
 impl Timestamp {
+    /// The timestamp as a Windows `FILETIME` value.
+    ///
+    /// This is the number of 100-nanosecond intervals since January 1, 1601 (UTC).
+    pub fn as_filetime(&self) -> u64 {
+        self.0
+    }
+
+    /// Convert the timestamp to a system time.
+    pub fn to_system_time(&self) -> SystemTime {
+        let since_1601 = Duration::new(self.0 / 10_000_000, (self.0 % 10_000_000) as u32 * 100);
+        let offset = Duration::from_secs(FILETIME_UNIX_OFFSET);
+
+        if since_1601 >= offset {
+            UNIX_EPOCH + (since_1601 - offset)
+        } else {
+            UNIX_EPOCH - (offset - since_1601)
+        }
+    }
+
+    pub(crate) fn from_filetime(value: u64) -> Timestamp {
+        Timestamp(value)
+    }
+
+    /// Convert a system time to a 64 bit timestamp.
+    pub(crate) fn from_system_time(time: SystemTime) -> Timestamp {
+        let since_1601 = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => Duration::from_secs(FILETIME_UNIX_OFFSET) + duration,
+            Err(err) => Duration::from_secs(FILETIME_UNIX_OFFSET).saturating_sub(err.duration()),
+        };
+
+        Timestamp((since_1601.as_nanos() / 100).min(u64::MAX as u128) as u64)
+    }
+
+    pub(crate) fn write(
+        prop_type: PropertyType,
+        value: Option<Timestamp>,
+        object: &mut ObjectBuilder,
+    ) {
+        if let Some(Timestamp(value)) = value {
+            object.set(prop_type, PropertyValue::U64(value));
+        }
+    }
+
     pub(crate) fn parse(prop_type: PropertyType, object: &Object) -> Result<Option<Timestamp>> {
         let timestamp = object
             .props()
@@ -51,3 +132,61 @@ impl Timestamp {
         Ok(timestamp)
     }
 }
+
+impl From<Time> for SystemTime {
+    fn from(time: Time) -> Self {
+        time.to_system_time()
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_system_time()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Time, Timestamp};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_time_to_system_time() {
+        assert_eq!(
+            Time(0).to_system_time(),
+            UNIX_EPOCH + Duration::from_secs(315_532_800)
+        );
+        assert_eq!(
+            Time(1_000_000_000).to_system_time(),
+            UNIX_EPOCH + Duration::from_secs(1_315_532_800)
+        );
+    }
+
+    #[test]
+    fn test_timestamp_to_system_time() {
+        assert_eq!(
+            Timestamp(116_444_736_000_000_000).to_system_time(),
+            UNIX_EPOCH
+        );
+        assert_eq!(
+            Timestamp(132_000_000_001_234_567).to_system_time(),
+            UNIX_EPOCH + Duration::new(1_555_526_400, 123_456_700)
+        );
+        assert_eq!(
+            Timestamp(0).to_system_time(),
+            UNIX_EPOCH - Duration::from_secs(11_644_473_600)
+        );
+    }
+
+    #[test]
+    fn test_from_system_time() {
+        let time = UNIX_EPOCH + Duration::new(1_555_526_400, 123_456_700);
+
+        assert_eq!(
+            Timestamp::from_system_time(time),
+            Timestamp(132_000_000_001_234_567)
+        );
+        assert_eq!(Time::from_system_time(time), Time(1_239_993_600));
+        assert_eq!(Time::from_system_time(UNIX_EPOCH), Time(0));
+    }
+}
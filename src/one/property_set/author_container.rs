@@ -0,0 +1,36 @@
+use crate::errors::{ErrorKind, Result};
+use crate::one::property::author::Author;
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+
+/// An author.
+///
+/// Referenced by a revision's metadata (`jcidAuthorContainer` in \[MS-ONE\]).
+#[derive(Debug)]
+pub(crate) struct Data {
+    pub(crate) author: Option<Author>,
+}
+
+pub(crate) fn parse(object: &Object) -> Result<Data> {
+    if object.id() != PropertySetId::AuthorContainer.as_jcid() {
+        return Err(ErrorKind::MalformedOneNoteFileData(
+            format!("unexpected object type: 0x{:X}", object.id().0).into(),
+        )
+        .into());
+    }
+
+    let author = Author::parse(object)?;
+
+    let data = Data { author };
+
+    Ok(data)
+}
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::AuthorContainer.as_jcid());
+
+    Author::write(data.author.as_ref(), &mut object);
+
+    object
+}
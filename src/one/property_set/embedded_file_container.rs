@@ -1,6 +1,7 @@
 use crate::errors::{ErrorKind, Result};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// An embedded file data container.
 ///
@@ -24,12 +25,19 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         .into());
     }
 
+    // The data is missing if the file was parsed without loading payloads
     let data = object
         .file_data()
-        .ok_or_else(|| {
-            ErrorKind::MalformedOneNoteFileData("embedded file container has no data".into())
-        })?
-        .to_vec();
+        .map(|data| data.to_vec())
+        .unwrap_or_default();
 
     Ok(Data(data))
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::EmbeddedFileContainer.as_jcid());
+
+    object.set_file_data(data.0);
+
+    object
+}
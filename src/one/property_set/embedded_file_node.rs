@@ -8,6 +8,7 @@ use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::note_tag_container::Data as NoteTagData;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// An embedded file.
 ///
@@ -34,7 +35,6 @@ pub(crate) struct Data {
     pub(crate) note_tags: Vec<NoteTagData>,
     pub(crate) offset_from_parent_horiz: Option<f32>,
     pub(crate) offset_from_parent_vert: Option<f32>,
-    pub(crate) recording_duration: Option<u32>,
 }
 
 pub(crate) fn parse(object: &Object) -> Result<Data> {
@@ -73,7 +73,6 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let picture_height = simple::parse_f32(PropertyType::PictureHeight, object)?;
     let offset_from_parent_horiz = simple::parse_f32(PropertyType::OffsetFromParentHoriz, object)?;
     let offset_from_parent_vert = simple::parse_f32(PropertyType::OffsetFromParentVert, object)?;
-    // let recording_duration = simple::parse_u32(PropertyType::Duration) // FIXME: Record duration property id not known
 
     let note_tags = NoteTagData::parse(object)?.unwrap_or_default();
 
@@ -96,8 +95,94 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         note_tags,
         offset_from_parent_horiz,
         offset_from_parent_vert,
-        recording_duration: None, // FIXME: Parse this
     };
 
     Ok(data)
 }
+
+/// Build an embedded file.
+///
+/// Note tags are not written.
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::EmbeddedFileNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified),
+        &mut object,
+    );
+    ObjectReference::write(
+        PropertyType::PictureContainer,
+        data.picture_container,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxWidth,
+        data.layout_max_width,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxHeight,
+        data.layout_max_height,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsLayoutSizeSetByUser,
+        data.is_layout_size_set_by_user.then_some(true),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::RichEditTextUnicode,
+        data.text.as_deref(),
+        &mut object,
+    );
+    simple::write_u16(
+        PropertyType::RichEditTextLangId,
+        data.text_language_code.map(|code| code as u16),
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentInParent,
+        data.layout_alignment_in_parent,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentSelf,
+        data.layout_alignment_self,
+        &mut object,
+    );
+    ObjectReference::write(
+        PropertyType::EmbeddedFileContainer,
+        Some(data.embedded_file_container),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::EmbeddedFileName,
+        Some(&data.embedded_file_name),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::SourceFilepath,
+        data.source_path.as_deref(),
+        &mut object,
+    );
+    data.file_type.write(&mut object);
+    simple::write_f32(PropertyType::PictureWidth, data.picture_width, &mut object);
+    simple::write_f32(
+        PropertyType::PictureHeight,
+        data.picture_height,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::OffsetFromParentHoriz,
+        data.offset_from_parent_horiz,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::OffsetFromParentVert,
+        data.offset_from_parent_vert,
+        &mut object,
+    );
+
+    object
+}
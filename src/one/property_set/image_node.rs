@@ -7,6 +7,7 @@ use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::note_tag_container::Data as NoteTagData;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// An embedded image.
 ///
@@ -14,9 +15,11 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.24]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/b7bb4d1a-2a57-4819-9eb4-5a2ce8cf210f
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Time,
     pub(crate) picture_container: Option<ExGuid>,
+    pub(crate) upload_state: Option<u32>,
     pub(crate) layout_max_width: Option<f32>,
     pub(crate) layout_max_height: Option<f32>,
     pub(crate) is_layout_size_set_by_user: bool,
@@ -50,6 +53,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         ErrorKind::MalformedOneNoteFileData("image has no last modified time".into())
     })?;
     let picture_container = ObjectReference::parse(PropertyType::PictureContainer, object)?;
+    let upload_state = simple::parse_u32(PropertyType::ImageUploadState, object)?;
     let layout_max_width = simple::parse_f32(PropertyType::LayoutMaxWidth, object)?;
     let layout_max_height = simple::parse_f32(PropertyType::LayoutMaxHeight, object)?;
     let is_layout_size_set_by_user =
@@ -79,6 +83,7 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let data = Data {
         last_modified,
         picture_container,
+        upload_state,
         layout_max_width,
         layout_max_height,
         is_layout_size_set_by_user,
@@ -102,3 +107,109 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+/// Build an image.
+///
+/// Note tags are not written.
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::ImageNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified),
+        &mut object,
+    );
+    ObjectReference::write(
+        PropertyType::PictureContainer,
+        data.picture_container,
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::ImageUploadState,
+        data.upload_state,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxWidth,
+        data.layout_max_width,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxHeight,
+        data.layout_max_height,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsLayoutSizeSetByUser,
+        data.is_layout_size_set_by_user.then_some(true),
+        &mut object,
+    );
+    simple::write_u32(PropertyType::LanguageId, data.language_code, &mut object);
+    simple::write_string(
+        PropertyType::ImageAltText,
+        data.alt_text.as_deref(),
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentInParent,
+        data.layout_alignment_in_parent,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentSelf,
+        data.layout_alignment_self,
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::ImageFilename,
+        data.image_filename.as_deref(),
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::DisplayedPageNumber,
+        data.displayed_page_number,
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::RichEditTextUnicode,
+        data.text.as_deref(),
+        &mut object,
+    );
+    simple::write_u16(
+        PropertyType::RichEditTextLangId,
+        data.text_language_code.map(|code| code as u16),
+        &mut object,
+    );
+    simple::write_f32(PropertyType::PictureWidth, data.picture_width, &mut object);
+    simple::write_f32(
+        PropertyType::PictureHeight,
+        data.picture_height,
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::WzHyperlinkUrl,
+        data.hyperlink_url.as_deref(),
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::OffsetFromParentHoriz,
+        data.offset_from_parent_horiz,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::OffsetFromParentVert,
+        data.offset_from_parent_vert,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsBackground,
+        data.is_background.then_some(true),
+        &mut object,
+    );
+
+    if !data.iframe.is_empty() {
+        ObjectReference::write_vec(PropertyType::ContentChildNodes, &data.iframe, &mut object);
+    }
+
+    object
+}
@@ -8,6 +8,7 @@ use crate::onestore::types::jcid::JcId;
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
 
+pub(crate) mod author_container;
 pub(crate) mod embedded_file_container;
 pub(crate) mod embedded_file_node;
 pub(crate) mod embedded_ink_container;
@@ -28,6 +29,7 @@ pub(crate) mod page_node;
 pub(crate) mod page_series_node;
 pub(crate) mod paragraph_style_object;
 pub(crate) mod picture_container;
+pub(crate) mod revision_metadata;
 pub(crate) mod rich_text_node;
 pub(crate) mod section_metadata_node;
 pub(crate) mod section_node;
@@ -5,6 +5,7 @@ use crate::one::property::time::Time;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// An outline element.
 ///
@@ -12,6 +13,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.21]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/d47760a6-6f1f-4fd5-b2ad-a51fe5a72c21
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) created_at: Time,
     pub(crate) last_modified: Time,
@@ -86,3 +88,59 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::OutlineElementNode.as_jcid());
+
+    Time::write(
+        PropertyType::CreationTimeStamp,
+        Some(data.created_at),
+        &mut object,
+    );
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified),
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.children, &mut object);
+    simple::write_u8(
+        PropertyType::OutlineElementChildLevel,
+        Some(data.child_level),
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ContentChildNodes, &data.contents, &mut object);
+    ObjectReference::write_vec(PropertyType::ListNodes, &data.list_contents, &mut object);
+    simple::write_f32(PropertyType::ListSpacingMu, data.list_spacing, &mut object);
+    ObjectReference::write(
+        PropertyType::AuthorOriginal,
+        Some(data.author_original),
+        &mut object,
+    );
+    ObjectReference::write(
+        PropertyType::AuthorMostRecent,
+        Some(data.author_most_recent),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::OutlineElementRtl,
+        data.rtl.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Deletable,
+        data.is_deletable.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::CannotBeSelected,
+        Some(!data.is_selectable),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleText,
+        data.is_title_text.then_some(true),
+        &mut object,
+    );
+
+    object
+}
@@ -7,6 +7,7 @@ use crate::one::property::time::Time;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// An outline group.
 ///
@@ -14,6 +15,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.20]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/b25fa331-e07e-474e-99c9-b3603b7bf937
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Time,
     pub(crate) children: Vec<ExGuid>,
@@ -118,3 +120,108 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::OutlineNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified),
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.children, &mut object);
+    simple::write_u8(
+        PropertyType::OutlineElementChildLevel,
+        Some(data.child_level),
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxHeight,
+        data.layout_max_height,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutOutlineReservedWidth,
+        data.layout_reserved_width,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMinimumOutlineWidth,
+        data.layout_minimum_outline_width,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::LayoutMaxWidth,
+        data.layout_max_width,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::LayoutTightAlignment,
+        data.layout_tight_alignment.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsLayoutSizeSetByUser,
+        data.is_layout_size_set_by_user.then_some(true),
+        &mut object,
+    );
+    simple::write_f32(PropertyType::ListSpacingMu, data.list_spacing, &mut object);
+    data.outline_indent_distance.write(&mut object);
+    simple::write_f32(
+        PropertyType::OffsetFromParentHoriz,
+        data.offset_from_parent_horiz,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::OffsetFromParentVert,
+        data.offset_from_parent_vert,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentInParent,
+        data.layout_alignment_in_parent,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentSelf,
+        data.layout_alignment_self,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Deletable,
+        data.is_deletable.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleDate,
+        data.is_title_date.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::CannotBeSelected,
+        (!data.is_selectable).then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleText,
+        data.is_title_text.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsReadOnly,
+        data.is_read_only.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::DescendantsCannotBeMoved,
+        data.descendants_cannot_be_moved.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::LayoutTightLayout,
+        data.tight_layout.then_some(true),
+        &mut object,
+    );
+
+    object
+}
@@ -4,6 +4,7 @@ use crate::one::property::object_reference::ObjectReference;
 use crate::one::property::PropertyType;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A page manifest.
 ///
@@ -29,3 +30,11 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(Data { page })
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::PageManifestNode.as_jcid());
+
+    ObjectReference::write_vec(PropertyType::ContentChildNodes, &[data.page], &mut object);
+
+    object
+}
@@ -3,6 +3,7 @@ use crate::one::property::time::Timestamp;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 use crate::shared::guid::Guid;
 
 /// A page's metadata.
@@ -11,6 +12,7 @@ use crate::shared::guid::Guid;
 ///
 /// [\[MS-ONE\] 2.2.30]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/aaabcc70-5836-4dcb-8209-012ce5d45b3c
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) entity_guid: Guid,
     pub(crate) cached_title: String,
@@ -59,3 +61,45 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::PageMetadata.as_jcid());
+
+    simple::write_guid(
+        PropertyType::NotebookManagementEntityGuid,
+        Some(data.entity_guid),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::CachedTitleString,
+        Some(&data.cached_title),
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::SchemaRevisionInOrderToRead,
+        data.schema_revision_in_order_to_read,
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::SchemaRevisionInOrderToWrite,
+        data.schema_revision_in_order_to_write,
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::PageLevel,
+        Some(data.page_level as u32),
+        &mut object,
+    );
+    Timestamp::write(
+        PropertyType::TopologyCreationTimeStamp,
+        Some(data.created_at),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsDeletedGraphSpaceContent,
+        data.is_deleted.then_some(true),
+        &mut object,
+    );
+
+    object
+}
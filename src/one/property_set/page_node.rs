@@ -7,6 +7,7 @@ use crate::one::property::time::Time;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A page.
 ///
@@ -14,6 +15,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.19]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e381b7c7-b434-43a2-ba23-0d08bafd281a
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Option<Time>,
     pub(crate) cached_title: Option<String>,
@@ -84,3 +86,74 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::PageNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        data.last_modified,
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::CachedTitleStringFromPage,
+        data.cached_title.as_deref(),
+        &mut object,
+    );
+    Author::write(data.author.as_ref(), &mut object);
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.content, &mut object);
+    ObjectReference::write_vec(
+        PropertyType::StructureElementChildNodes,
+        data.title.as_slice(),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::PortraitPage,
+        data.orientation_portrait.then_some(true),
+        &mut object,
+    );
+    simple::write_f32(PropertyType::PageWidth, data.page_width, &mut object);
+    simple::write_f32(PropertyType::PageHeight, data.page_height, &mut object);
+    simple::write_f32(
+        PropertyType::PageMarginOriginX,
+        data.page_margin_origin_x,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::PageMarginOriginY,
+        data.page_margin_origin_y,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::PageMarginLeft,
+        data.page_margin_left,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::PageMarginRight,
+        data.page_margin_right,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::PageMarginTop,
+        data.page_margin_top,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::PageMarginBottom,
+        data.page_margin_bottom,
+        &mut object,
+    );
+
+    if !matches!(data.page_size, PageSize::Auto) {
+        PageSize::write(PropertyType::PageSize, &data.page_size, &mut object);
+    }
+
+    simple::write_bool(
+        PropertyType::EditRootRtl,
+        data.rtl.then_some(true),
+        &mut object,
+    );
+
+    object
+}
@@ -7,6 +7,7 @@ use crate::one::property::time::Timestamp;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 use crate::shared::guid::Guid;
 
 /// A page series.
@@ -15,6 +16,7 @@ use crate::shared::guid::Guid;
 ///
 /// [\[MS-ONE\] 2.2.18]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e2957d3b-a2a8-4756-8662-4e67fefa9f4e
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) entity_guid: Guid,
     pub(crate) page_spaces: Vec<CellId>,
@@ -49,3 +51,30 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::PageSeriesNode.as_jcid());
+
+    simple::write_guid(
+        PropertyType::NotebookManagementEntityGuid,
+        Some(data.entity_guid),
+        &mut object,
+    );
+    ObjectSpaceReference::write_vec(
+        PropertyType::ChildGraphSpaceElementNodes,
+        &data.page_spaces,
+        &mut object,
+    );
+    ObjectReference::write_vec(
+        PropertyType::MetaDataObjectsAboveGraphSpace,
+        &data.page_metadata,
+        &mut object,
+    );
+    Timestamp::write(
+        PropertyType::TopologyCreationTimeStamp,
+        data.created_at,
+        &mut object,
+    );
+
+    object
+}
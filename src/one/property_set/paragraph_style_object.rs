@@ -5,6 +5,7 @@ use crate::one::property::paragraph_alignment::ParagraphAlignment;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A paragraph style.
 ///
@@ -13,6 +14,7 @@ use crate::onestore::object::Object;
 /// [\[MS-ONE\] 2.2.43]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/38eb9b74-cfaf-4df7-b061-a83968c7ff5b
 /// [\[MS-ONE\] 2.2.44]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/f0baabae-f42a-42e0-8cb2-869d420e865f
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) charset: Option<Charset>,
     pub(crate) bold: bool,
@@ -107,3 +109,98 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::ParagraphStyleObject.as_jcid());
+
+    Charset::write(PropertyType::Charset, data.charset, &mut object);
+    simple::write_bool(PropertyType::Bold, data.bold.then_some(true), &mut object);
+    simple::write_bool(
+        PropertyType::Italic,
+        data.italic.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Underline,
+        data.underline.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Strikethrough,
+        data.strikethrough.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Superscript,
+        data.superscript.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Subscript,
+        data.subscript.then_some(true),
+        &mut object,
+    );
+    simple::write_string(PropertyType::Font, data.font.as_deref(), &mut object);
+    simple::write_u16(PropertyType::FontSize, data.font_size, &mut object);
+    ColorRef::write(PropertyType::FontColor, data.font_color, &mut object);
+    ColorRef::write(PropertyType::Highlight, data.highlight, &mut object);
+    simple::write_string(
+        PropertyType::NextStyle,
+        data.next_style.as_deref(),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::ParagraphStyleId,
+        data.style_id.as_deref(),
+        &mut object,
+    );
+    ParagraphAlignment::write(data.paragraph_alignment, &mut object);
+    simple::write_f32(
+        PropertyType::ParagraphSpaceBefore,
+        data.paragraph_space_before,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::ParagraphSpaceAfter,
+        data.paragraph_space_after,
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::ParagraphLineSpacingExact,
+        data.paragraph_line_spacing_exact,
+        &mut object,
+    );
+    simple::write_u32(PropertyType::LanguageId, data.language_code, &mut object);
+    simple::write_bool(
+        PropertyType::MathFormatting,
+        data.math_formatting.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Hyperlink,
+        data.hyperlink.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::HyperlinkProtected,
+        data.hyperlink_protected.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::Hidden,
+        data.hidden.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::TextRunIsEmbeddedObject,
+        data.text_run_is_embedded_object.then_some(true),
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::EmbeddedObjectType,
+        data.text_run_object_type,
+        &mut object,
+    );
+
+    object
+}
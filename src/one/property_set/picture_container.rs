@@ -2,16 +2,22 @@ use crate::errors::{ErrorKind, Result};
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A picture container.
 ///
+/// File printouts store the printed XPS document in an (undocumented) XPS container
+/// instead.
+///
 /// See [\[MS-ONE\] 2.2.36].
 ///
 /// [\[MS-ONE\] 2.2.36]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/28112f88-80f5-49b2-8988-d4a66dcc4d80
 #[derive(Debug)]
 pub(crate) struct Data {
-    pub(crate) data: Vec<u8>,
+    /// The picture data, `None` if the file was parsed without loading payloads.
+    pub(crate) data: Option<Vec<u8>>,
     pub(crate) extension: Option<String>,
+    pub(crate) is_xps: bool,
 }
 
 pub(crate) fn parse(object: &Object) -> Result<Data> {
@@ -24,8 +30,34 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         .into());
     }
 
-    let data = object.file_data().map(|v| v.to_vec()).unwrap_or_default();
+    let data = object.file_data().map(|v| v.to_vec());
     let extension = simple::parse_string(PropertyType::PictureFileExtension, object)?;
+    let is_xps = object.id() == PropertySetId::XpsContainer.as_jcid();
+
+    Ok(Data {
+        data,
+        extension,
+        is_xps,
+    })
+}
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let id = if data.is_xps {
+        PropertySetId::XpsContainer
+    } else {
+        PropertySetId::PictureContainer
+    };
+
+    let mut object = ObjectBuilder::new(id.as_jcid());
+
+    if let Some(data) = data.data {
+        object.set_file_data(data);
+    }
+    simple::write_string(
+        PropertyType::PictureFileExtension,
+        data.extension.as_deref(),
+        &mut object,
+    );
 
-    Ok(Data { data, extension })
+    object
 }
@@ -0,0 +1,36 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::object_reference::ObjectReference;
+use crate::one::property::time::Timestamp;
+use crate::one::property::PropertyType;
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+
+/// A revision's metadata.
+///
+/// This is the object referenced by a revision's version metadata root (`jcidRevisionMetaData`
+/// in \[MS-ONE\]). It records who last modified the object space and when.
+#[derive(Debug)]
+pub(crate) struct Data {
+    pub(crate) author_most_recent: Option<ExGuid>,
+    pub(crate) last_modified: Option<Timestamp>,
+}
+
+pub(crate) fn parse(object: &Object) -> Result<Data> {
+    if object.id() != PropertySetId::RevisionMetadata.as_jcid() {
+        return Err(ErrorKind::MalformedOneNoteFileData(
+            format!("unexpected object type: 0x{:X}", object.id().0).into(),
+        )
+        .into());
+    }
+
+    let author_most_recent = ObjectReference::parse(PropertyType::AuthorMostRecent, object)?;
+    let last_modified = Timestamp::parse(PropertyType::LastModifiedTimeStamp, object)?;
+
+    let data = Data {
+        author_most_recent,
+        last_modified,
+    };
+
+    Ok(data)
+}
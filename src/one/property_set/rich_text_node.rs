@@ -8,6 +8,8 @@ use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::note_tag_container::Data as NoteTagData;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::utils::utf16_bytes;
 
 /// A rich text paragraph.
 ///
@@ -15,6 +17,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.23]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/980fae36-b5dd-4581-bf1e-5ab54177153d
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified_time: Time,
     pub(crate) tight_layout: bool,
@@ -115,3 +118,111 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+/// Build a rich text paragraph.
+///
+/// Note tags are not written.
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::RichTextNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified_time),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::LayoutTightLayout,
+        data.tight_layout.then_some(true),
+        &mut object,
+    );
+    ObjectReference::write_vec(
+        PropertyType::TextRunFormatting,
+        &data.text_run_formatting,
+        &mut object,
+    );
+    if !data.text_run_indices.is_empty() {
+        simple::write_vec_u32(
+            PropertyType::TextRunIndex,
+            Some(data.text_run_indices),
+            &mut object,
+        );
+    }
+    if !data.text_run_data_object.is_empty() {
+        ObjectReference::write_vec(
+            PropertyType::TextRunDataObject,
+            &data.text_run_data_object,
+            &mut object,
+        );
+    }
+    ObjectReference::write(
+        PropertyType::ParagraphStyle,
+        Some(data.paragraph_style),
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::ParagraphSpaceBefore,
+        Some(data.paragraph_space_before),
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::ParagraphSpaceAfter,
+        Some(data.paragraph_space_after),
+        &mut object,
+    );
+    simple::write_f32(
+        PropertyType::ParagraphLineSpacingExact,
+        data.paragraph_line_spacing_exact,
+        &mut object,
+    );
+    ParagraphAlignment::write(Some(data.paragraph_alignment), &mut object);
+
+    // The text isn't null-terminated
+    simple::write_vec(
+        PropertyType::RichEditTextUnicode,
+        data.text.as_deref().map(utf16_bytes),
+        &mut object,
+    );
+
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentInParent,
+        data.layout_alignment_in_parent,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentSelf,
+        data.layout_alignment_self,
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleTime,
+        data.is_title_time.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsBoilerText,
+        data.is_boiler_text.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleDate,
+        data.is_title_date.then_some(true),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::IsTitleText,
+        data.is_title_text.then_some(true),
+        &mut object,
+    );
+    simple::write_u16(
+        PropertyType::RichEditTextLangId,
+        data.language_code.map(|code| code as u16),
+        &mut object,
+    );
+    simple::write_bool(
+        PropertyType::ReadingOrderRtl,
+        data.rtl.then_some(true),
+        &mut object,
+    );
+
+    object
+}
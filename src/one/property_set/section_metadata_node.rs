@@ -3,6 +3,7 @@ use crate::one::property::color::Color;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A section's metadata.
 ///
@@ -10,6 +11,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.31]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/c8cd2fe2-593b-45f8-8da0-03ca6f6f704d
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) schema_revision_in_order_to_read: u32,
     pub(crate) schema_revision_in_order_to_write: u32,
@@ -51,3 +53,26 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::SectionMetadata.as_jcid());
+
+    simple::write_u32(
+        PropertyType::SchemaRevisionInOrderToRead,
+        Some(data.schema_revision_in_order_to_read),
+        &mut object,
+    );
+    simple::write_u32(
+        PropertyType::SchemaRevisionInOrderToWrite,
+        Some(data.schema_revision_in_order_to_write),
+        &mut object,
+    );
+    simple::write_string(
+        PropertyType::SectionDisplayName,
+        data.display_name.as_deref(),
+        &mut object,
+    );
+    Color::write(PropertyType::SectionColor, data.color, &mut object);
+
+    object
+}
@@ -5,6 +5,7 @@ use crate::one::property::time::Timestamp;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 use crate::shared::guid::Guid;
 
 /// A section.
@@ -13,6 +14,7 @@ use crate::shared::guid::Guid;
 ///
 /// [\[MS-ONE\] 2.2.23]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/980fae36-b5dd-4581-bf1e-5ab54177153d
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) context_id: ExGuid,
     pub(crate) entity_guid: Guid,
@@ -48,3 +50,25 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::SectionNode.as_jcid());
+
+    simple::write_guid(
+        PropertyType::NotebookManagementEntityGuid,
+        Some(data.entity_guid),
+        &mut object,
+    );
+    ObjectReference::write_vec(
+        PropertyType::ElementChildNodes,
+        &data.page_series,
+        &mut object,
+    );
+    Timestamp::write(
+        PropertyType::TopologyCreationTimeStamp,
+        Some(data.created_at),
+        &mut object,
+    );
+
+    object
+}
@@ -1,4 +1,5 @@
 use crate::errors::{ErrorKind, Result};
+use crate::one::property::color_ref::ColorRef;
 use crate::one::property::ink_dimensions::InkDimension;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
@@ -15,7 +16,7 @@ pub(crate) struct Data {
     pub(crate) transparency: Option<u8>,
     pub(crate) ink_height: f32,
     pub(crate) ink_width: f32,
-    pub(crate) color: Option<u32>,
+    pub(crate) color: Option<ColorRef>,
     pub(crate) dimensions: Vec<InkDimension>,
 }
 
@@ -34,13 +35,13 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
     let pen_tip = simple::parse_u8(PropertyType::InkPenTip, object)?;
     let raster_operation = simple::parse_u8(PropertyType::InkRasterOperation, object)?;
     let transparency = simple::parse_u8(PropertyType::InkTransparency, object)?;
-    let ink_width = simple::parse_f32(PropertyType::InkHeight, object)?.ok_or_else(|| {
+    let ink_height = simple::parse_f32(PropertyType::InkHeight, object)?.ok_or_else(|| {
         ErrorKind::MalformedOneNoteFileData("ink stroke properties has no height".into())
     })?;
-    let ink_height = simple::parse_f32(PropertyType::InkWidth, object)?.ok_or_else(|| {
+    let ink_width = simple::parse_f32(PropertyType::InkWidth, object)?.ok_or_else(|| {
         ErrorKind::MalformedOneNoteFileData("ink stroke properties has no width".into())
     })?;
-    let color = simple::parse_u32(PropertyType::InkColor, object)?;
+    let color = ColorRef::parse(PropertyType::InkColor, object)?;
     let dimensions = InkDimension::parse(PropertyType::InkDimensions, object)?;
 
     Ok(Data {
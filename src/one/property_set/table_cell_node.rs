@@ -7,6 +7,7 @@ use crate::one::property::time::Time;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A table cell.
 ///
@@ -14,6 +15,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.28]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e5660d6b-72c3-4d9f-bad0-435c00f42183
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Option<Time>,
     pub(crate) contents: Vec<ExGuid>,
@@ -49,3 +51,27 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::TableCellNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        data.last_modified,
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.contents, &mut object);
+    simple::write_f32(
+        PropertyType::LayoutMaxWidth,
+        data.layout_max_width,
+        &mut object,
+    );
+    data.outline_indent_distance.write(&mut object);
+    Color::write(
+        PropertyType::CellBackgroundColor,
+        data.background_color,
+        &mut object,
+    );
+
+    object
+}
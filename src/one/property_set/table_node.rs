@@ -7,6 +7,7 @@ use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::note_tag_container::Data as NoteTagData;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A table.
 ///
@@ -14,6 +15,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.26]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/9046980a-2410-4b2d-8a35-ec06e55648e0
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Time,
     pub(crate) rows: Vec<ExGuid>,
@@ -81,3 +83,56 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
 
     Ok(data)
 }
+
+/// Build a table.
+///
+/// Note tags are not written.
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::TableNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        Some(data.last_modified),
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.rows, &mut object);
+    simple::write_u32(PropertyType::RowCount, Some(data.row_count), &mut object);
+    simple::write_u32(PropertyType::ColumnCount, Some(data.col_count), &mut object);
+
+    // Both column arrays are prefixed with their length
+    if !data.cols_locked.is_empty() {
+        let mut cols_locked = vec![data.cols_locked.len() as u8];
+        cols_locked.extend(data.cols_locked);
+        simple::write_vec(
+            PropertyType::TableColumnsLocked,
+            Some(cols_locked),
+            &mut object,
+        );
+    }
+
+    let mut col_widths = vec![data.col_widths.len() as u8];
+    col_widths.extend(data.col_widths.iter().flat_map(|width| width.to_le_bytes()));
+    simple::write_vec(
+        PropertyType::TableColumnWidths,
+        Some(col_widths),
+        &mut object,
+    );
+
+    simple::write_bool(
+        PropertyType::TableBordersVisible,
+        (!data.borders_visible).then_some(false),
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentInParent,
+        data.layout_alignment_in_parent,
+        &mut object,
+    );
+    LayoutAlignment::write(
+        PropertyType::LayoutAlignmentSelf,
+        data.layout_alignment_self,
+        &mut object,
+    );
+
+    object
+}
@@ -5,6 +5,7 @@ use crate::one::property::time::Time;
 use crate::one::property::PropertyType;
 use crate::one::property_set::PropertySetId;
 use crate::onestore::object::Object;
+use crate::onestore::object_builder::ObjectBuilder;
 
 /// A table row.
 ///
@@ -12,6 +13,7 @@ use crate::onestore::object::Object;
 ///
 /// [\[MS-ONE\] 2.2.27]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/d22af1aa-5e0b-40ed-b914-f6397979d6b0
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct Data {
     pub(crate) last_modified: Option<Time>,
     pub(crate) cells: Vec<ExGuid>,
@@ -34,3 +36,16 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         cells,
     })
 }
+
+pub(crate) fn build(data: Data) -> ObjectBuilder {
+    let mut object = ObjectBuilder::new(PropertySetId::TableRowNode.as_jcid());
+
+    Time::write(
+        PropertyType::LastModifiedTime,
+        data.last_modified,
+        &mut object,
+    );
+    ObjectReference::write_vec(PropertyType::ElementChildNodes, &data.cells, &mut object);
+
+    object
+}
@@ -1,5 +1,6 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::color::Color;
 use crate::one::property::object_reference::ObjectReference;
 use crate::one::property::{simple, PropertyType};
 use crate::one::property_set::PropertySetId;
@@ -15,7 +16,7 @@ pub(crate) struct Data {
     pub(crate) children: Vec<ExGuid>,
     pub(crate) filename: Option<String>,
     pub(crate) ordering_id: Option<u32>,
-    // FIXME: Color!?
+    pub(crate) color: Option<Color>,
 }
 
 pub(crate) fn parse(object: &Object) -> Result<Data> {
@@ -32,10 +33,12 @@ pub(crate) fn parse(object: &Object) -> Result<Data> {
         .map(|s| s.replace("^M", "+"))
         .map(|s| s.replace("^J", ","));
     let ordering_id = simple::parse_u32(PropertyType::NotebookElementOrderingId, object)?;
+    let color = Color::parse(PropertyType::SectionColor, object)?;
 
     Ok(Data {
         children,
         filename,
         ordering_id,
+        color,
     })
 }
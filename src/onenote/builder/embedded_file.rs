@@ -0,0 +1,67 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::file_type::FileType;
+use crate::one::property_set::{embedded_file_container, embedded_file_node};
+use crate::onenote::builder::PageContext;
+
+/// A builder for an embedded file (an attachment).
+#[derive(Clone, Debug)]
+pub struct EmbeddedFileBuilder {
+    name: String,
+    data: Vec<u8>,
+    offset: Option<(f32, f32)>,
+}
+
+impl EmbeddedFileBuilder {
+    /// Create a builder for an embedded file with the given file name and contents.
+    pub fn new(name: &str, data: Vec<u8>) -> EmbeddedFileBuilder {
+        EmbeddedFileBuilder {
+            name: name.to_string(),
+            data,
+            offset: None,
+        }
+    }
+
+    /// Set the file's offset from the top left corner of the page in half-inch
+    /// increments.
+    ///
+    /// The offset is only used for files that are added directly to a page.
+    pub fn with_offset(mut self, horizontal: f32, vertical: f32) -> EmbeddedFileBuilder {
+        self.offset = Some((horizontal, vertical));
+        self
+    }
+
+    pub(crate) fn build(
+        &self,
+        context: &mut PageContext,
+        default_offset: Option<(f32, f32)>,
+    ) -> ExGuid {
+        let container = context.space.add_object(embedded_file_container::build(
+            embedded_file_container::Data(self.data.clone()),
+        ));
+
+        let offset = default_offset.map(|default| self.offset.unwrap_or(default));
+
+        context
+            .space
+            .add_object(embedded_file_node::build(embedded_file_node::Data {
+                last_modified: context.time,
+                picture_container: None,
+                layout_max_width: None,
+                layout_max_height: None,
+                is_layout_size_set_by_user: false,
+                text: None,
+                text_language_code: None,
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+                embedded_file_container: container,
+                embedded_file_name: self.name.clone(),
+                source_path: None,
+                file_type: FileType::Unknown,
+                picture_width: None,
+                picture_height: None,
+                note_tags: vec![],
+                offset_from_parent_horiz: offset.map(|(horizontal, _)| horizontal),
+                offset_from_parent_vert: offset.map(|(_, vertical)| vertical),
+            }))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property_set::{image_node, picture_container};
+use crate::onenote::builder::PageContext;
+
+/// A builder for an image.
+#[derive(Clone, Debug)]
+pub struct ImageBuilder {
+    data: Vec<u8>,
+    extension: Option<String>,
+    alt_text: Option<String>,
+    size: Option<(f32, f32)>,
+    offset: Option<(f32, f32)>,
+}
+
+impl ImageBuilder {
+    /// Create a builder for an image with the given image file contents.
+    pub fn new(data: Vec<u8>) -> ImageBuilder {
+        ImageBuilder {
+            data,
+            extension: None,
+            alt_text: None,
+            size: None,
+            offset: None,
+        }
+    }
+
+    /// Set the image's file extension including the leading dot (e.g. `.png`).
+    pub fn with_extension(mut self, extension: &str) -> ImageBuilder {
+        self.extension = Some(extension.to_string());
+        self
+    }
+
+    /// Set the image's alternative text.
+    pub fn with_alt_text(mut self, alt_text: &str) -> ImageBuilder {
+        self.alt_text = Some(alt_text.to_string());
+        self
+    }
+
+    /// Set the image's displayed size in half-inch increments.
+    pub fn with_size(mut self, width: f32, height: f32) -> ImageBuilder {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set the image's offset from the top left corner of the page in half-inch
+    /// increments.
+    ///
+    /// The offset is only used for images that are added directly to a page.
+    pub fn with_offset(mut self, horizontal: f32, vertical: f32) -> ImageBuilder {
+        self.offset = Some((horizontal, vertical));
+        self
+    }
+
+    pub(crate) fn build(
+        &self,
+        context: &mut PageContext,
+        default_offset: Option<(f32, f32)>,
+    ) -> ExGuid {
+        let container =
+            context
+                .space
+                .add_object(picture_container::build(picture_container::Data {
+                    data: Some(self.data.clone()),
+                    extension: self.extension.clone(),
+                    is_xps: false,
+                }));
+
+        let offset = default_offset.map(|default| self.offset.unwrap_or(default));
+        let width = self.size.map(|(width, _)| width);
+        let height = self.size.map(|(_, height)| height);
+
+        context
+            .space
+            .add_object(image_node::build(image_node::Data {
+                last_modified: context.time,
+                picture_container: Some(container),
+                upload_state: None,
+                layout_max_width: width,
+                layout_max_height: height,
+                is_layout_size_set_by_user: self.size.is_some(),
+                language_code: None,
+                alt_text: self.alt_text.clone(),
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+                image_filename: None,
+                displayed_page_number: None,
+                text: None,
+                text_language_code: None,
+                picture_width: width,
+                picture_height: height,
+                hyperlink_url: None,
+                note_tags: vec![],
+                offset_from_parent_horiz: offset.map(|(horizontal, _)| horizontal),
+                offset_from_parent_vert: offset.map(|(_, vertical)| vertical),
+                is_background: false,
+                iframe: vec![],
+            }))
+    }
+}
@@ -0,0 +1,198 @@
+use crate::errors::Result;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::time::{Time, Timestamp};
+use crate::one::property_set::{
+    page_metadata, page_series_node, section_metadata_node, section_node,
+};
+use crate::onenote::builder::page::PageBuilder;
+use crate::onenote::section::Section;
+use crate::onestore::store_builder::{ObjectSpaceBuilder, StoreBuilder};
+use crate::shared::guid::Guid;
+use crate::Parser;
+use std::time::SystemTime;
+
+pub(crate) mod embedded_file;
+pub(crate) mod image;
+pub(crate) mod outline;
+pub(crate) mod page;
+pub(crate) mod rich_text;
+pub(crate) mod table;
+
+/// The schema revision written by current versions of OneNote.
+const SCHEMA_REVISION: u32 = 40;
+
+/// The position of page-level content if none is set, in half-inch increments.
+///
+/// This places the content below the page title.
+const DEFAULT_OFFSET: (f32, f32) = (1.0, 2.3);
+
+/// A builder for a new OneNote section.
+///
+/// The section is written as a `.one` file in the FSSHTTPB format that is used by
+/// OneNote for Windows 10 and OneNote Online. The file should be saved as
+/// [`SectionBuilder::file_name()`] since OneNote checks the section's file name.
+///
+/// # Example
+///
+/// ```
+/// use onenote_parser::builder::{OutlineBuilder, OutlineElementBuilder, PageBuilder};
+/// use onenote_parser::builder::{RichTextBuilder, SectionBuilder};
+///
+/// let text = RichTextBuilder::new("Agenda");
+/// let outline = OutlineBuilder::new().with_element(OutlineElementBuilder::new().with_text(text));
+/// let page = PageBuilder::new("Weekly meeting").with_outline(outline);
+///
+/// let section = SectionBuilder::new("Meetings").with_page(page).build().unwrap();
+///
+/// assert_eq!(section.display_name(), "Meetings");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SectionBuilder {
+    name: String,
+    page_series: Vec<PageSeriesBuilder>,
+}
+
+impl SectionBuilder {
+    /// Create a builder for an empty section with the given display name.
+    pub fn new(name: &str) -> SectionBuilder {
+        SectionBuilder {
+            name: name.to_string(),
+            page_series: vec![],
+        }
+    }
+
+    /// Add a page in a new page series.
+    pub fn with_page(self, page: PageBuilder) -> SectionBuilder {
+        self.with_page_series(PageSeriesBuilder::new().with_page(page))
+    }
+
+    /// Add a page series.
+    pub fn with_page_series(mut self, page_series: PageSeriesBuilder) -> SectionBuilder {
+        self.page_series.push(page_series);
+        self
+    }
+
+    /// The file name of the section file.
+    ///
+    /// OneNote uses the file name as the section's display name.
+    pub fn file_name(&self) -> String {
+        format!("{}.one", self.name)
+    }
+
+    /// Write the section file.
+    ///
+    /// Each call creates a section with new IDs.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let now = SystemTime::now();
+        let mut store = StoreBuilder::new(Guid::new_v4(), &self.file_name());
+
+        let page_series = self
+            .page_series
+            .iter()
+            .map(|series| series.build(&mut store, now))
+            .collect();
+
+        let data_root = store.data_root();
+
+        let content = data_root.add_object(section_node::build(section_node::Data {
+            context_id: ExGuid::nil(),
+            entity_guid: Guid::new_v4(),
+            page_series,
+            created_at: Timestamp::from_system_time(now),
+        }));
+        let metadata =
+            data_root.add_object(section_metadata_node::build(section_metadata_node::Data {
+                schema_revision_in_order_to_read: SCHEMA_REVISION,
+                schema_revision_in_order_to_write: SCHEMA_REVISION,
+                display_name: None,
+                color: None,
+            }));
+
+        data_root.set_content_root(content);
+        data_root.set_metadata_root(metadata);
+
+        let mut data = vec![];
+        store.write(&mut data)?;
+
+        Ok(data)
+    }
+
+    /// Build the section.
+    ///
+    /// This writes the section file and parses it again.
+    pub fn build(&self) -> Result<Section> {
+        let data = self.to_bytes()?;
+
+        Parser::new().parse_section_from_bytes(&data, &self.file_name())
+    }
+}
+
+/// A builder for a page series.
+///
+/// A page series groups a page with its subpages (see [`PageBuilder::with_level()`]).
+#[derive(Clone, Debug)]
+pub struct PageSeriesBuilder {
+    pages: Vec<PageBuilder>,
+}
+
+impl PageSeriesBuilder {
+    /// Create a builder for an empty page series.
+    pub fn new() -> PageSeriesBuilder {
+        PageSeriesBuilder { pages: vec![] }
+    }
+
+    /// Add a page.
+    pub fn with_page(mut self, page: PageBuilder) -> PageSeriesBuilder {
+        self.pages.push(page);
+        self
+    }
+
+    fn build(&self, store: &mut StoreBuilder, now: SystemTime) -> ExGuid {
+        let mut page_spaces = vec![];
+        let mut metadata = vec![];
+
+        for page in &self.pages {
+            let (space, page_metadata) = page.build(now);
+
+            metadata.push(store.data_root().add_object(page_metadata));
+            page_spaces.push(store.add_object_space(space));
+        }
+
+        store
+            .data_root()
+            .add_object(page_series_node::build(page_series_node::Data {
+                entity_guid: Guid::new_v4(),
+                page_spaces,
+                page_metadata: metadata,
+                created_at: Some(Timestamp::from_system_time(now)),
+            }))
+    }
+}
+
+impl Default for PageSeriesBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state that is shared by the objects of a page.
+pub(crate) struct PageContext {
+    pub(crate) space: ObjectSpaceBuilder,
+    pub(crate) author: ExGuid,
+    pub(crate) time: Time,
+}
+
+/// Build the metadata object of a page.
+///
+/// The metadata is stored in the page's object space and in the section's data root.
+fn build_page_metadata(title: &str, level: i32, now: SystemTime) -> page_metadata::Data {
+    page_metadata::Data {
+        entity_guid: Guid::new_v4(),
+        cached_title: title.to_string(),
+        schema_revision_in_order_to_read: Some(SCHEMA_REVISION),
+        schema_revision_in_order_to_write: Some(SCHEMA_REVISION),
+        page_level: level,
+        created_at: Timestamp::from_system_time(now),
+        is_deleted: false,
+    }
+}
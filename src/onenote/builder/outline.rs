@@ -0,0 +1,208 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::outline_indent_distance::OutlineIndentDistance;
+use crate::one::property_set::{outline_element_node, outline_node};
+use crate::onenote::builder::embedded_file::EmbeddedFileBuilder;
+use crate::onenote::builder::image::ImageBuilder;
+use crate::onenote::builder::rich_text::RichTextBuilder;
+use crate::onenote::builder::table::TableBuilder;
+use crate::onenote::builder::PageContext;
+
+/// The indent distances of each outline level as used by OneNote, in half-inch increments.
+pub(crate) fn default_indent_distance() -> OutlineIndentDistance {
+    OutlineIndentDistance::new(vec![0.0, 0.5, 0.75, 0.75])
+}
+
+/// A builder for an outline.
+///
+/// An outline is a container of paragraphs, tables, images and embedded files that is
+/// positioned on the page.
+#[derive(Clone, Debug)]
+pub struct OutlineBuilder {
+    elements: Vec<OutlineElementBuilder>,
+    offset: Option<(f32, f32)>,
+    max_width: Option<f32>,
+    is_title: bool,
+}
+
+impl OutlineBuilder {
+    /// Create a builder for an empty outline.
+    pub fn new() -> OutlineBuilder {
+        OutlineBuilder {
+            elements: vec![],
+            offset: None,
+            max_width: None,
+            is_title: false,
+        }
+    }
+
+    /// Add an element to the outline.
+    pub fn with_element(mut self, element: OutlineElementBuilder) -> OutlineBuilder {
+        self.elements.push(element);
+        self
+    }
+
+    /// Set the outline's offset from the top left corner of the page in half-inch
+    /// increments.
+    pub fn with_offset(mut self, horizontal: f32, vertical: f32) -> OutlineBuilder {
+        self.offset = Some((horizontal, vertical));
+        self
+    }
+
+    /// Set the outline's maximum width in half-inch increments.
+    pub fn with_max_width(mut self, max_width: f32) -> OutlineBuilder {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Mark the outline as the contents of a page title.
+    pub(crate) fn title(mut self) -> OutlineBuilder {
+        self.is_title = true;
+        self
+    }
+
+    pub(crate) fn build(
+        &self,
+        context: &mut PageContext,
+        default_offset: Option<(f32, f32)>,
+    ) -> ExGuid {
+        let children = self
+            .elements
+            .iter()
+            .map(|element| element.build(context))
+            .collect();
+        let offset = self.offset.or(default_offset);
+
+        context
+            .space
+            .add_object(outline_node::build(outline_node::Data {
+                last_modified: context.time,
+                children,
+                child_level: 1,
+                layout_max_height: None,
+                layout_max_width: self.max_width,
+                layout_reserved_width: None,
+                layout_minimum_outline_width: None,
+                layout_tight_alignment: false,
+                is_layout_size_set_by_user: self.max_width.is_some(),
+                offset_from_parent_horiz: offset.map(|(horizontal, _)| horizontal),
+                offset_from_parent_vert: offset.map(|(_, vertical)| vertical),
+                list_spacing: None,
+                outline_indent_distance: default_indent_distance(),
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+                is_deletable: false,
+                is_title_date: false,
+                is_selectable: true,
+                is_title_text: self.is_title,
+                is_read_only: false,
+                descendants_cannot_be_moved: false,
+                tight_layout: false,
+            }))
+    }
+}
+
+impl Default for OutlineBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A builder for an outline element.
+///
+/// An outline element holds the contents of a paragraph and the elements that are
+/// indented below it.
+#[derive(Clone, Debug)]
+pub struct OutlineElementBuilder {
+    contents: Vec<ContentBuilder>,
+    children: Vec<OutlineElementBuilder>,
+}
+
+#[derive(Clone, Debug)]
+enum ContentBuilder {
+    RichText(RichTextBuilder),
+    Table(TableBuilder),
+    Image(ImageBuilder),
+    EmbeddedFile(EmbeddedFileBuilder),
+}
+
+impl OutlineElementBuilder {
+    /// Create a builder for an empty outline element.
+    pub fn new() -> OutlineElementBuilder {
+        OutlineElementBuilder {
+            contents: vec![],
+            children: vec![],
+        }
+    }
+
+    /// Add a rich text paragraph to the element.
+    pub fn with_text(mut self, text: RichTextBuilder) -> OutlineElementBuilder {
+        self.contents.push(ContentBuilder::RichText(text));
+        self
+    }
+
+    /// Add a table to the element.
+    pub fn with_table(mut self, table: TableBuilder) -> OutlineElementBuilder {
+        self.contents.push(ContentBuilder::Table(table));
+        self
+    }
+
+    /// Add an image to the element.
+    pub fn with_image(mut self, image: ImageBuilder) -> OutlineElementBuilder {
+        self.contents.push(ContentBuilder::Image(image));
+        self
+    }
+
+    /// Add an embedded file to the element.
+    pub fn with_embedded_file(mut self, file: EmbeddedFileBuilder) -> OutlineElementBuilder {
+        self.contents.push(ContentBuilder::EmbeddedFile(file));
+        self
+    }
+
+    /// Add an element that is indented below this element.
+    pub fn with_child(mut self, child: OutlineElementBuilder) -> OutlineElementBuilder {
+        self.children.push(child);
+        self
+    }
+
+    pub(crate) fn build(&self, context: &mut PageContext) -> ExGuid {
+        let contents = self
+            .contents
+            .iter()
+            .map(|content| match content {
+                ContentBuilder::RichText(text) => text.build(context),
+                ContentBuilder::Table(table) => table.build(context),
+                ContentBuilder::Image(image) => image.build(context, None),
+                ContentBuilder::EmbeddedFile(file) => file.build(context, None),
+            })
+            .collect();
+        let children = self
+            .children
+            .iter()
+            .map(|child| child.build(context))
+            .collect();
+
+        context
+            .space
+            .add_object(outline_element_node::build(outline_element_node::Data {
+                created_at: context.time,
+                last_modified: context.time,
+                children,
+                child_level: 1,
+                contents,
+                list_contents: vec![],
+                list_spacing: None,
+                author_original: context.author,
+                author_most_recent: context.author,
+                rtl: false,
+                is_deletable: false,
+                is_selectable: true,
+                is_title_text: false,
+            }))
+    }
+}
+
+impl Default for OutlineElementBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
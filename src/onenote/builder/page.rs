@@ -0,0 +1,172 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::author::Author;
+use crate::one::property::page_size::PageSize;
+use crate::one::property::time::Time;
+use crate::one::property_set::{
+    author_container, page_manifest_node, page_metadata, page_node, title_node,
+};
+use crate::onenote::builder::embedded_file::EmbeddedFileBuilder;
+use crate::onenote::builder::image::ImageBuilder;
+use crate::onenote::builder::outline::{OutlineBuilder, OutlineElementBuilder};
+use crate::onenote::builder::rich_text::RichTextBuilder;
+use crate::onenote::builder::{build_page_metadata, PageContext, DEFAULT_OFFSET};
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::store_builder::ObjectSpaceBuilder;
+use std::time::SystemTime;
+
+/// A builder for a page.
+#[derive(Clone, Debug)]
+pub struct PageBuilder {
+    title: String,
+    level: i32,
+    author: Option<String>,
+    contents: Vec<PageContentBuilder>,
+}
+
+#[derive(Clone, Debug)]
+enum PageContentBuilder {
+    Outline(OutlineBuilder),
+    Image(ImageBuilder),
+    EmbeddedFile(EmbeddedFileBuilder),
+}
+
+impl PageBuilder {
+    /// Create a builder for an empty page with the given title.
+    ///
+    /// If the title is empty the page is created without a title.
+    pub fn new(title: &str) -> PageBuilder {
+        PageBuilder {
+            title: title.to_string(),
+            level: 1,
+            author: None,
+            contents: vec![],
+        }
+    }
+
+    /// Set the page's level in the section page tree.
+    ///
+    /// Top-level pages have level 1, their subpages have level 2 and so on. Subpages must
+    /// be added to the same page series as their parent page.
+    pub fn with_level(mut self, level: i32) -> PageBuilder {
+        self.level = level;
+        self
+    }
+
+    /// Set the author of the page and its contents.
+    pub fn with_author(mut self, author: &str) -> PageBuilder {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Add an outline to the page.
+    ///
+    /// Outlines without an offset are placed below the page title.
+    pub fn with_outline(mut self, outline: OutlineBuilder) -> PageBuilder {
+        self.contents.push(PageContentBuilder::Outline(outline));
+        self
+    }
+
+    /// Add an image to the page.
+    ///
+    /// Images without an offset are placed below the page title.
+    pub fn with_image(mut self, image: ImageBuilder) -> PageBuilder {
+        self.contents.push(PageContentBuilder::Image(image));
+        self
+    }
+
+    /// Add an embedded file to the page.
+    ///
+    /// Embedded files without an offset are placed below the page title.
+    pub fn with_embedded_file(mut self, file: EmbeddedFileBuilder) -> PageBuilder {
+        self.contents.push(PageContentBuilder::EmbeddedFile(file));
+        self
+    }
+
+    /// Build the page's object space and its metadata object.
+    pub(crate) fn build(&self, now: SystemTime) -> (ObjectSpaceBuilder, ObjectBuilder) {
+        let mut context = PageContext {
+            space: ObjectSpaceBuilder::new(),
+            author: ExGuid::nil(),
+            time: Time::from_system_time(now),
+        };
+
+        context.author =
+            context
+                .space
+                .add_object(author_container::build(author_container::Data {
+                    author: self.author.clone().map(Author::new),
+                }));
+
+        let title = self.build_title(&mut context);
+
+        let content = self
+            .contents
+            .iter()
+            .map(|content| match content {
+                PageContentBuilder::Outline(outline) => {
+                    outline.build(&mut context, Some(DEFAULT_OFFSET))
+                }
+                PageContentBuilder::Image(image) => image.build(&mut context, Some(DEFAULT_OFFSET)),
+                PageContentBuilder::EmbeddedFile(file) => {
+                    file.build(&mut context, Some(DEFAULT_OFFSET))
+                }
+            })
+            .collect();
+
+        let page = context.space.add_object(page_node::build(page_node::Data {
+            last_modified: Some(context.time),
+            cached_title: Some(self.title.clone()),
+            author: self.author.clone().map(Author::new),
+            content,
+            title,
+            orientation_portrait: false,
+            page_width: None,
+            page_height: None,
+            page_margin_origin_x: None,
+            page_margin_origin_y: None,
+            page_margin_left: None,
+            page_margin_right: None,
+            page_margin_top: None,
+            page_margin_bottom: None,
+            page_size: PageSize::Auto,
+            rtl: false,
+        }));
+        let manifest = context
+            .space
+            .add_object(page_manifest_node::build(page_manifest_node::Data { page }));
+
+        let metadata = page_metadata::build(build_page_metadata(&self.title, self.level, now));
+        let metadata_id = context.space.add_object(metadata.clone());
+
+        context.space.set_content_root(manifest);
+        context.space.set_metadata_root(metadata_id);
+
+        (context.space, metadata)
+    }
+
+    fn build_title(&self, context: &mut PageContext) -> Option<ExGuid> {
+        if self.title.is_empty() {
+            return None;
+        }
+
+        let text = RichTextBuilder::new(&self.title);
+        let outline = OutlineBuilder::new()
+            .with_element(OutlineElementBuilder::new().with_text(text))
+            .title();
+
+        let outline_id = outline.build(context, None);
+
+        let title = context
+            .space
+            .add_object(title_node::build(title_node::Data {
+                last_modified_time: context.time,
+                children: vec![outline_id],
+                offset_horizontal: 0.0,
+                offset_vertical: 0.0,
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+            }));
+
+        Some(title)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::paragraph_alignment::ParagraphAlignment;
+use crate::one::property_set::{paragraph_style_object, rich_text_node};
+use crate::onenote::builder::PageContext;
+use crate::onenote::rich_text::ParagraphStyling;
+
+/// A builder for a rich text paragraph.
+///
+/// The paragraph's text is made up of runs that each have their own formatting.
+///
+/// # Example
+///
+/// ```
+/// use onenote_parser::builder::RichTextBuilder;
+/// use onenote_parser::property::rich_text::ParagraphStyling;
+///
+/// let text = RichTextBuilder::new("Status: ")
+///     .with_run("done", ParagraphStyling::new().with_bold(true));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RichTextBuilder {
+    runs: Vec<(String, ParagraphStyling)>,
+    paragraph_style: ParagraphStyling,
+    alignment: ParagraphAlignment,
+}
+
+impl RichTextBuilder {
+    /// Create a builder for a paragraph containing the text with the default formatting.
+    pub fn new(text: &str) -> RichTextBuilder {
+        let runs = if text.is_empty() {
+            vec![]
+        } else {
+            vec![(text.to_string(), ParagraphStyling::new())]
+        };
+
+        RichTextBuilder {
+            runs,
+            paragraph_style: ParagraphStyling::new(),
+            alignment: ParagraphAlignment::Left,
+        }
+    }
+
+    /// Append a text run with the given formatting.
+    pub fn with_run(mut self, text: &str, styling: ParagraphStyling) -> RichTextBuilder {
+        self.runs.push((text.to_string(), styling));
+        self
+    }
+
+    /// Set the paragraph's style.
+    ///
+    /// The paragraph style applies to all text runs. It's used for the paragraph's
+    /// spacing and for formatting that isn't set by the text runs.
+    pub fn with_paragraph_style(mut self, styling: ParagraphStyling) -> RichTextBuilder {
+        self.paragraph_style = styling;
+        self
+    }
+
+    /// Set the paragraph's alignment.
+    pub fn with_alignment(mut self, alignment: ParagraphAlignment) -> RichTextBuilder {
+        self.alignment = alignment;
+        self
+    }
+
+    pub(crate) fn build(&self, context: &mut PageContext) -> ExGuid {
+        let default_run = [(String::new(), ParagraphStyling::new())];
+        let runs = if self.runs.is_empty() {
+            &default_run[..]
+        } else {
+            &self.runs[..]
+        };
+
+        let paragraph_style = context
+            .space
+            .add_object(paragraph_style_object::build(build_style(
+                &self.paragraph_style,
+            )));
+
+        let text_run_formatting = runs
+            .iter()
+            .map(|(_, styling)| {
+                context
+                    .space
+                    .add_object(paragraph_style_object::build(build_style(styling)))
+            })
+            .collect();
+
+        // The indices mark the end of each text run but the last in UTF-16 code units
+        let text_run_indices = runs[..runs.len() - 1]
+            .iter()
+            .scan(0, |end, (text, _)| {
+                *end += text.encode_utf16().count() as u32;
+
+                Some(*end)
+            })
+            .collect();
+
+        let text = runs.iter().map(|(text, _)| text.as_str()).collect();
+
+        context
+            .space
+            .add_object(rich_text_node::build(rich_text_node::Data {
+                last_modified_time: context.time,
+                tight_layout: false,
+                text_run_formatting,
+                text_run_indices,
+                text_run_data_object: vec![],
+                paragraph_style,
+                paragraph_space_before: self.paragraph_style.paragraph_space_before.unwrap_or(0.0),
+                paragraph_space_after: self.paragraph_style.paragraph_space_after.unwrap_or(0.0),
+                paragraph_line_spacing_exact: self.paragraph_style.paragraph_line_spacing_exact,
+                paragraph_alignment: self.alignment,
+                text: Some(text),
+                is_title_time: false,
+                is_boiler_text: false,
+                is_title_date: false,
+                is_title_text: false,
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+                language_code: self.paragraph_style.language_code,
+                rtl: false,
+                note_tags: vec![],
+            }))
+    }
+}
+
+fn build_style(styling: &ParagraphStyling) -> paragraph_style_object::Data {
+    let styling = styling.clone();
+
+    paragraph_style_object::Data {
+        charset: styling.charset,
+        bold: styling.bold,
+        italic: styling.italic,
+        underline: styling.underline,
+        strikethrough: styling.strikethrough,
+        superscript: styling.superscript,
+        subscript: styling.subscript,
+        font: styling.font,
+        font_size: styling.font_size,
+        font_color: styling.font_color,
+        highlight: styling.highlight,
+        next_style: styling.next_style,
+        style_id: styling.style_id,
+        paragraph_alignment: styling.paragraph_alignment,
+        paragraph_space_before: styling.paragraph_space_before,
+        paragraph_space_after: styling.paragraph_space_after,
+        paragraph_line_spacing_exact: styling.paragraph_line_spacing_exact,
+        language_code: styling.language_code,
+        math_formatting: styling.math_formatting,
+        hyperlink: styling.hyperlink,
+        hyperlink_protected: false,
+        hidden: false,
+        text_run_is_embedded_object: false,
+        text_run_object_type: None,
+    }
+}
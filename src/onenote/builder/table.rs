@@ -0,0 +1,168 @@
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property_set::{table_cell_node, table_node, table_row_node};
+use crate::onenote::builder::outline::{default_indent_distance, OutlineElementBuilder};
+use crate::onenote::builder::rich_text::RichTextBuilder;
+use crate::onenote::builder::PageContext;
+
+/// The width of table columns if none is set, in half-inch increments.
+const DEFAULT_COLUMN_WIDTH: f32 = 2.0;
+
+/// A builder for a table.
+///
+/// The table has as many columns as its longest row.
+#[derive(Clone, Debug)]
+pub struct TableBuilder {
+    rows: Vec<TableRowBuilder>,
+    col_widths: Vec<f32>,
+    borders_visible: bool,
+}
+
+impl TableBuilder {
+    /// Create a builder for an empty table with visible borders.
+    pub fn new() -> TableBuilder {
+        TableBuilder {
+            rows: vec![],
+            col_widths: vec![],
+            borders_visible: true,
+        }
+    }
+
+    /// Add a row to the table.
+    pub fn with_row(mut self, row: TableRowBuilder) -> TableBuilder {
+        self.rows.push(row);
+        self
+    }
+
+    /// Set the column widths in half-inch increments.
+    ///
+    /// Columns without a width use a default width.
+    pub fn with_column_widths(mut self, widths: &[f32]) -> TableBuilder {
+        self.col_widths = widths.to_vec();
+        self
+    }
+
+    /// Set whether the table borders are visible.
+    pub fn with_borders_visible(mut self, borders_visible: bool) -> TableBuilder {
+        self.borders_visible = borders_visible;
+        self
+    }
+
+    pub(crate) fn build(&self, context: &mut PageContext) -> ExGuid {
+        let col_count = self
+            .rows
+            .iter()
+            .map(|row| row.cells.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut col_widths = self.col_widths.clone();
+        col_widths.resize(col_count, DEFAULT_COLUMN_WIDTH);
+
+        let rows = self.rows.iter().map(|row| row.build(context)).collect();
+
+        context
+            .space
+            .add_object(table_node::build(table_node::Data {
+                last_modified: context.time,
+                rows,
+                row_count: self.rows.len() as u32,
+                col_count: col_count as u32,
+                cols_locked: vec![],
+                col_widths,
+                borders_visible: self.borders_visible,
+                layout_alignment_in_parent: None,
+                layout_alignment_self: None,
+                note_tags: vec![],
+            }))
+    }
+}
+
+impl Default for TableBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A builder for a table row.
+#[derive(Clone, Debug)]
+pub struct TableRowBuilder {
+    cells: Vec<TableCellBuilder>,
+}
+
+impl TableRowBuilder {
+    /// Create a builder for an empty table row.
+    pub fn new() -> TableRowBuilder {
+        TableRowBuilder { cells: vec![] }
+    }
+
+    /// Add a cell to the row.
+    pub fn with_cell(mut self, cell: TableCellBuilder) -> TableRowBuilder {
+        self.cells.push(cell);
+        self
+    }
+
+    fn build(&self, context: &mut PageContext) -> ExGuid {
+        let cells = self.cells.iter().map(|cell| cell.build(context)).collect();
+
+        context
+            .space
+            .add_object(table_row_node::build(table_row_node::Data {
+                last_modified: Some(context.time),
+                cells,
+            }))
+    }
+}
+
+impl Default for TableRowBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A builder for a table cell.
+#[derive(Clone, Debug)]
+pub struct TableCellBuilder {
+    elements: Vec<OutlineElementBuilder>,
+}
+
+impl TableCellBuilder {
+    /// Create a builder for an empty table cell.
+    pub fn new() -> TableCellBuilder {
+        TableCellBuilder { elements: vec![] }
+    }
+
+    /// Add an outline element to the cell.
+    pub fn with_element(mut self, element: OutlineElementBuilder) -> TableCellBuilder {
+        self.elements.push(element);
+        self
+    }
+
+    /// Add a rich text paragraph to the cell.
+    pub fn with_text(self, text: RichTextBuilder) -> TableCellBuilder {
+        self.with_element(OutlineElementBuilder::new().with_text(text))
+    }
+
+    fn build(&self, context: &mut PageContext) -> ExGuid {
+        let contents = self
+            .elements
+            .iter()
+            .map(|element| element.build(context))
+            .collect();
+
+        context
+            .space
+            .add_object(table_cell_node::build(table_cell_node::Data {
+                last_modified: Some(context.time),
+                contents,
+                layout_max_width: None,
+                outline_indent_distance: default_indent_distance(),
+                background_color: None,
+            }))
+    }
+}
+
+impl Default for TableCellBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
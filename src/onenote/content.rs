@@ -4,8 +4,10 @@ use crate::one::property_set::PropertySetId;
 use crate::onenote::embedded_file::{parse_embedded_file, EmbeddedFile};
 use crate::onenote::image::{parse_image, Image};
 use crate::onenote::ink::{parse_ink, Ink};
+use crate::onenote::printout::Printout;
 use crate::onenote::rich_text::{parse_rich_text, RichText};
 use crate::onenote::table::{parse_table, Table};
+use crate::onenote::unknown::{parse_unknown, UnknownContent};
 use crate::onestore::object_space::ObjectSpace;
 
 /// The content of an outline.
@@ -26,8 +28,11 @@ pub enum Content {
     /// An ink drawing
     Ink(Ink),
 
-    /// Content of unknown type.
-    Unknown,
+    /// A file printout.
+    Printout(Printout),
+
+    /// Content of a type that isn't supported by this crate.
+    Unknown(UnknownContent),
 }
 
 impl Content {
@@ -75,6 +80,24 @@ impl Content {
             None
         }
     }
+
+    /// Return the printout data if it's a file printout content.
+    pub fn printout(&self) -> Option<&Printout> {
+        if let Content::Printout(printout) = self {
+            Some(printout)
+        } else {
+            None
+        }
+    }
+
+    /// Return the unknown content if it's of a type that isn't supported by this crate.
+    pub fn unknown(&self) -> Option<&UnknownContent> {
+        if let Content::Unknown(content) = self {
+            Some(content)
+        } else {
+            None
+        }
+    }
 }
 
 pub(crate) fn parse_content(content_id: ExGuid, space: &ObjectSpace) -> Result<Content> {
@@ -82,21 +105,16 @@ pub(crate) fn parse_content(content_id: ExGuid, space: &ObjectSpace) -> Result<C
         .get_object(content_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?
         .id();
-    let id = PropertySetId::from_jcid(content_type).ok_or_else(|| {
-        ErrorKind::MalformedOneNoteData(
-            format!("invalid property set id: 0x{:X}", content_type.0).into(),
-        )
-    })?;
-
-    let content = match id {
-        PropertySetId::ImageNode => Content::Image(parse_image(content_id, space)?),
-        PropertySetId::EmbeddedFileNode => {
+
+    let content = match PropertySetId::from_jcid(content_type) {
+        Some(PropertySetId::ImageNode) => Content::Image(parse_image(content_id, space)?),
+        Some(PropertySetId::EmbeddedFileNode) => {
             Content::EmbeddedFile(parse_embedded_file(content_id, space)?)
         }
-        PropertySetId::RichTextNode => Content::RichText(parse_rich_text(content_id, space)?),
-        PropertySetId::TableNode => Content::Table(parse_table(content_id, space)?),
-        PropertySetId::InkContainer => Content::Ink(parse_ink(content_id, space)?),
-        _ => Content::Unknown,
+        Some(PropertySetId::RichTextNode) => Content::RichText(parse_rich_text(content_id, space)?),
+        Some(PropertySetId::TableNode) => Content::Table(parse_table(content_id, space)?),
+        Some(PropertySetId::InkContainer) => Content::Ink(parse_ink(content_id, space)?),
+        _ => Content::Unknown(parse_unknown(content_id, space)?),
     };
 
     Ok(content)
@@ -1,8 +1,10 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property::file_type::FileType;
-use crate::one::property_set::{embedded_file_container, embedded_file_node};
+use crate::one::property::time::Time;
+use crate::one::property_set::{embedded_file_container, embedded_file_node, picture_container};
 use crate::onenote::note_tag::{parse_note_tags, NoteTag};
+use crate::onenote::recording::{parse_recording_info, RecordingInfo};
 use crate::onestore::object_space::ObjectSpace;
 
 /// An embedded file.
@@ -15,14 +17,25 @@ pub struct EmbeddedFile {
     pub(crate) filename: String,
     pub(crate) file_type: FileType,
     pub(crate) data: Vec<u8>,
+    pub(crate) source_path: Option<String>,
+    pub(crate) last_modified: Time,
+
+    pub(crate) preview_data: Option<Vec<u8>>,
+    pub(crate) preview_extension: Option<String>,
 
     pub(crate) layout_max_width: Option<f32>,
     pub(crate) layout_max_height: Option<f32>,
+    pub(crate) is_layout_size_set_by_user: bool,
+
+    pub(crate) picture_width: Option<f32>,
+    pub(crate) picture_height: Option<f32>,
 
     pub(crate) offset_horizontal: Option<f32>,
     pub(crate) offset_vertical: Option<f32>,
 
     pub(crate) note_tags: Vec<NoteTag>,
+
+    pub(crate) recording_info: Option<RecordingInfo>,
 }
 
 impl EmbeddedFile {
@@ -45,10 +58,45 @@ impl EmbeddedFile {
     }
 
     /// The file's binary data.
+    ///
+    /// This is empty if the file was parsed without loading payloads, see
+    /// [`ParseOptions::with_payloads()`](crate::ParseOptions::with_payloads).
     pub fn data(&self) -> &[u8] {
         &self.data
     }
 
+    /// The length and start time of an audio or video recording.
+    ///
+    /// The recording duration property of the section file hasn't been identified yet, so
+    /// this is read from the media file's header when the file is parsed. Returns `None`
+    /// if the file isn't a recording, the recording's format isn't supported or the file
+    /// was parsed without loading payloads.
+    pub fn recording_info(&self) -> Option<RecordingInfo> {
+        self.recording_info
+    }
+
+    /// The path the file was inserted from (if present).
+    pub fn source_path(&self) -> Option<&str> {
+        self.source_path.as_deref()
+    }
+
+    /// When the embedded file was last modified.
+    pub fn last_modified(&self) -> Time {
+        self.last_modified
+    }
+
+    /// The image OneNote displays for the embedded file (if present).
+    ///
+    /// This is the file's icon or a preview of its content.
+    pub fn preview_data(&self) -> Option<&[u8]> {
+        self.preview_data.as_deref()
+    }
+
+    /// The preview image's file extension.
+    pub fn preview_extension(&self) -> Option<&str> {
+        self.preview_extension.as_deref()
+    }
+
     /// The max width of the embedded file's icon in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.21].
@@ -67,6 +115,34 @@ impl EmbeddedFile {
         self.layout_max_height
     }
 
+    /// Whether the [`layout_max_width()`](Self::layout_max_width()) and
+    /// [`layout_max_height()`](Self::layout_max_height()) values are set by the user.
+    ///
+    /// See [\[MS-ONE\] 2.3.44].
+    ///
+    /// [\[MS-ONE\] 2.3.44]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/19227b81-43ab-484c-aaae-d33cf13e2602
+    pub fn is_layout_size_set_by_user(&self) -> bool {
+        self.is_layout_size_set_by_user
+    }
+
+    /// The width of the embedded file's icon or preview in half-inch increments.
+    ///
+    /// See [\[MS-ONE\] 2.3.100].
+    ///
+    /// [\[MS-ONE\] 2.3.100]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/b4feae22-69cb-4623-8d40-faf97a026465
+    pub fn picture_width(&self) -> Option<f32> {
+        self.picture_width
+    }
+
+    /// The height of the embedded file's icon or preview in half-inch increments.
+    ///
+    /// See [\[MS-ONE\] 2.3.101].
+    ///
+    /// [\[MS-ONE\] 2.3.101]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/65454c84-1a39-4e81-ba50-053666d92dd0
+    pub fn picture_height(&self) -> Option<f32> {
+        self.picture_height
+    }
+
     /// The horizontal offset from the page origin in half-inch increments.
     ///
     /// See [\[MS-ONE\] 2.3.18].
@@ -103,17 +179,38 @@ pub(crate) fn parse_embedded_file(file_id: ExGuid, space: &ObjectSpace) -> Resul
     })?;
     let container = embedded_file_container::parse(container_object)?;
 
-    // TODO: Resolve picture container
+    // The preview is optional, so a missing preview picture isn't an error
+    let preview = node
+        .picture_container
+        .and_then(|container_object_id| space.get_object(container_object_id))
+        .map(|container_object| picture_container::parse(container_object))
+        .transpose()?;
+
+    let (preview_data, preview_extension) = match preview {
+        Some(preview) => (preview.data, preview.extension),
+        None => (None, None),
+    };
+
+    let data = container.into_value();
+    let recording_info = parse_recording_info(node.file_type, &data);
 
     let file = EmbeddedFile {
         filename: node.embedded_file_name,
         file_type: node.file_type,
-        data: container.into_value(),
+        data,
+        source_path: node.source_path,
+        last_modified: node.last_modified,
+        preview_data,
+        preview_extension,
         layout_max_width: node.layout_max_width,
         layout_max_height: node.layout_max_height,
+        is_layout_size_set_by_user: node.is_layout_size_set_by_user,
+        picture_width: node.picture_width,
+        picture_height: node.picture_height,
         offset_horizontal: node.offset_from_parent_horiz,
         offset_vertical: node.offset_from_parent_vert,
         note_tags: parse_note_tags(node.note_tags, space)?,
+        recording_info,
     };
 
     Ok(file)
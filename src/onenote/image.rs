@@ -1,7 +1,7 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property::layout_alignment::LayoutAlignment;
-use crate::one::property_set::{image_node, picture_container};
+use crate::one::property_set::{image_node, picture_container, PropertySetId};
 use crate::onenote::iframe::{parse_iframe, IFrame};
 use crate::onenote::note_tag::{parse_note_tags, NoteTag};
 use crate::onestore::object_space::ObjectSpace;
@@ -15,6 +15,8 @@ use crate::onestore::object_space::ObjectSpace;
 pub struct Image {
     pub(crate) data: Option<Vec<u8>>,
     pub(crate) extension: Option<String>,
+    pub(crate) upload_state: ImageUploadState,
+    pub(crate) xps_container: Option<ExGuid>,
 
     pub(crate) layout_max_width: Option<f32>,
     pub(crate) layout_max_height: Option<f32>,
@@ -50,11 +52,20 @@ pub struct Image {
 impl Image {
     /// The image's binary data.
     ///
-    /// If `None` this means that the image data hasn't been uploaded yet.
+    /// If `None` the image data isn't stored in the file. Use [`Image::upload_state()`] to
+    /// find out why. Pages of file printouts don't have any picture data, see
+    /// [`Printout::xps_data()`](crate::page::Printout::xps_data) instead. The data is also
+    /// `None` if the file was parsed without loading payloads, see
+    /// [`ParseOptions::with_payloads()`](crate::ParseOptions::with_payloads).
     pub fn data(&self) -> Option<&[u8]> {
         self.data.as_deref()
     }
 
+    /// Whether the image data is stored in the file.
+    pub fn upload_state(&self) -> ImageUploadState {
+        self.upload_state
+    }
+
     /// The image's file extension.
     pub fn extension(&self) -> Option<&str> {
         self.extension.as_deref()
@@ -80,8 +91,6 @@ impl Image {
 
     /// Alternative text for the image.
     ///
-    /// Usually this seems to be the result of OneNote's OCR processing.
-    ///
     /// See [\[MS-ONE\] 2.2.79].
     ///
     /// [\[MS-ONE\] 2.2.79]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/f39569a6-84fa-4d5b-8a65-2b3e0ee36117
@@ -125,12 +134,12 @@ impl Image {
         self.displayed_page_number
     }
 
-    /// The text for the image.
+    /// The text recognized in the image by OneNote's OCR processing.
     pub fn text(&self) -> Option<&str> {
         self.text.as_deref()
     }
 
-    /// The text's language code (MS-LCID)
+    /// The language code (MS-LCID) of the recognized text.
     pub fn text_language_code(&self) -> Option<u32> {
         self.text_language_code
     }
@@ -196,29 +205,77 @@ impl Image {
     }
 }
 
+/// The upload state of an image's data.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ImageUploadState {
+    /// The image data is stored in the file.
+    Uploaded,
+
+    /// The image data hasn't been uploaded yet.
+    ///
+    /// OneNote marks images whose data is still being synchronized.
+    Pending,
+
+    /// The image data isn't stored in the file and its upload state isn't known.
+    ///
+    /// Contains the raw value of the image's upload state property.
+    Unknown(u32),
+
+    /// The image doesn't reference any image data.
+    Missing,
+}
+
+impl ImageUploadState {
+    /// The value of the upload state property marking image data as pending.
+    ///
+    /// The property isn't documented and none of the test samples set it, so only this
+    /// value is decoded and all others are reported as [`ImageUploadState::Unknown`].
+    const PENDING: u32 = 1;
+
+    fn parse(is_uploaded: bool, value: Option<u32>) -> ImageUploadState {
+        match (is_uploaded, value) {
+            (true, _) => ImageUploadState::Uploaded,
+            (false, Some(ImageUploadState::PENDING)) => ImageUploadState::Pending,
+            (false, Some(value)) => ImageUploadState::Unknown(value),
+            (false, None) => ImageUploadState::Missing,
+        }
+    }
+}
+
 pub(crate) fn parse_image(image_id: ExGuid, space: &ObjectSpace) -> Result<Image> {
     let node_object = space
         .get_object(image_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("image is missing".into()))?;
     let node = image_node::parse(node_object)?;
 
-    let container_data = node
+    let container_object = node
         .picture_container
         .map(|container_object_id| {
             space
                 .get_object(container_object_id)
                 .ok_or_else(|| ErrorKind::MalformedOneNoteData("image container is missing".into()))
         })
-        .transpose()?
-        .map(|container_object| picture_container::parse(container_object))
         .transpose()?;
 
-    let (data, extension) = if let Some(data) = container_data {
-        (Some(data.data), data.extension)
-    } else {
-        (None, None)
+    let is_uploaded = container_object.is_some();
+
+    // File printouts reference the printed XPS document instead of a picture. The document
+    // is shared by all pages of the printout, so it's read once when grouping the pages.
+    let is_xps =
+        container_object.is_some_and(|object| object.id() == PropertySetId::XpsContainer.as_jcid());
+    let xps_container = node.picture_container.filter(|_| is_xps);
+
+    let (data, extension) = match container_object.filter(|_| !is_xps) {
+        Some(object) => {
+            let data = picture_container::parse(object)?;
+
+            (data.data, data.extension)
+        }
+        None => (None, None),
     };
 
+    let upload_state = ImageUploadState::parse(is_uploaded, node.upload_state);
+
     let embed = node
         .iframe
         .into_iter()
@@ -230,18 +287,20 @@ pub(crate) fn parse_image(image_id: ExGuid, space: &ObjectSpace) -> Result<Image
     let image = Image {
         data,
         extension,
+        upload_state,
+        xps_container,
         layout_max_width: node.layout_max_width,
         layout_max_height: node.layout_max_height,
-        alt_text: node.alt_text.map(String::from),
+        alt_text: node.alt_text,
         layout_alignment_in_parent: node.layout_alignment_in_parent,
         layout_alignment_self: node.layout_alignment_self,
         image_filename: node.image_filename,
         displayed_page_number: node.displayed_page_number,
-        text: node.text.map(String::from),
+        text: node.text,
         text_language_code: node.text_language_code,
         picture_width: node.picture_width,
         picture_height: node.picture_height,
-        hyperlink_url: node.hyperlink_url.map(String::from),
+        hyperlink_url: node.hyperlink_url,
         offset_horizontal: node.offset_from_parent_horiz,
         offset_vertical: node.offset_from_parent_vert,
         is_background: node.is_background,
@@ -251,3 +310,28 @@ pub(crate) fn parse_image(image_id: ExGuid, space: &ObjectSpace) -> Result<Image
 
     Ok(image)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::onenote::image::ImageUploadState;
+
+    #[test]
+    fn test_parse_upload_state() {
+        assert_eq!(
+            ImageUploadState::parse(true, Some(1)),
+            ImageUploadState::Uploaded
+        );
+        assert_eq!(
+            ImageUploadState::parse(false, Some(1)),
+            ImageUploadState::Pending
+        );
+        assert_eq!(
+            ImageUploadState::parse(false, Some(3)),
+            ImageUploadState::Unknown(3)
+        );
+        assert_eq!(
+            ImageUploadState::parse(false, None),
+            ImageUploadState::Missing
+        );
+    }
+}
@@ -1,9 +1,14 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::color_ref::ColorRef;
 use crate::one::property_set::{
     ink_container, ink_data_node, ink_stroke_node, stroke_properties_node,
 };
 use crate::onestore::object_space::ObjectSpace;
+use crate::render::svg;
+
+/// The number of HIMETRIC units (0.01 mm) per half-inch.
+const HIMETRIC_PER_HALF_INCH: f32 = 1270.0;
 
 /// An ink object.
 #[derive(Clone, Debug)]
@@ -21,7 +26,7 @@ impl Ink {
         &self.ink_strokes
     }
 
-    /// The ink object's bounding box.
+    /// The ink object's bounding box in half-inch increments.
     pub fn bounding_box(&self) -> Option<InkBoundingBox> {
         self.bounding_box
     }
@@ -43,6 +48,14 @@ impl Ink {
     pub fn offset_vertical(&self) -> Option<f32> {
         self.offset_vertical
     }
+
+    /// Render the ink strokes as a standalone SVG image.
+    ///
+    /// The image covers the ink object's bounding box. The SVG user units are half-inch
+    /// increments and the image's physical size is given in inches.
+    pub fn to_svg(&self) -> String {
+        svg::render_ink(self)
+    }
 }
 
 /// An ink stroke.
@@ -53,45 +66,46 @@ pub struct InkStroke {
     pub(crate) transparency: Option<u8>,
     pub(crate) height: f32,
     pub(crate) width: f32,
-    pub(crate) color: Option<u32>,
+    pub(crate) color: Option<ColorRef>,
 }
 
 impl InkStroke {
     /// The ink stroke's path.
+    ///
+    /// The points' coordinates are absolute and in half-inch increments.
     pub fn path(&self) -> &[InkPoint] {
         &self.path
     }
 
     /// The pen tip used for the ink path.
     ///
-    /// The exact meaning is not specified.
+    /// 0 is a round (ball) pen tip and 1 is a rectangular pen tip.
     pub fn pen_tip(&self) -> Option<u8> {
         self.pen_tip
     }
 
     /// The path's transparency
     ///
-    /// The exact meaning is not specified. It seems like 0 means translucent and 255 means opaque.
+    /// 0 means opaque and 255 means fully transparent. Highlighters use partially
+    /// transparent pens.
     pub fn transparency(&self) -> Option<u8> {
         self.transparency
     }
 
-    /// The ink stroke's total height.
+    /// The height of the pen tip in half-inch increments.
     pub fn height(&self) -> f32 {
         self.height
     }
 
-    /// The ink stroke's total width.
-    ///
-    /// The exact meaning is not specified.
+    /// The width of the pen tip in half-inch increments.
     pub fn width(&self) -> f32 {
         self.width
     }
 
     /// The ink stroke's color.
     ///
-    /// The exact meaning is not specified.
-    pub fn color(&self) -> Option<u32> {
+    /// If no color is set, the stroke is black.
+    pub fn color(&self) -> Option<ColorRef> {
         self.color
     }
 }
@@ -104,12 +118,12 @@ pub struct InkPoint {
 }
 
 impl InkPoint {
-    /// The point's X coordinates.
+    /// The point's X coordinates in half-inch increments.
     pub fn x(&self) -> f32 {
         self.x
     }
 
-    /// The point's Y coordinates.
+    /// The point's Y coordinates in half-inch increments.
     pub fn y(&self) -> f32 {
         self.y
     }
@@ -125,30 +139,22 @@ pub struct InkBoundingBox {
 }
 
 impl InkBoundingBox {
-    /// The initial X coordinate of the bounding box.
-    ///
-    /// The exact meaning and unit are not specified.
+    /// The X coordinate of the bounding box's left edge in half-inch increments.
     pub fn x(&self) -> f32 {
         self.x
     }
 
-    /// The initial Y coordinate of the bounding box.
-    ///
-    /// The exact meaning and unit are not specified.
+    /// The Y coordinate of the bounding box's top edge in half-inch increments.
     pub fn y(&self) -> f32 {
         self.y
     }
 
-    /// The height of the bounding box.
-    ///
-    /// The exact meaning and unit are not specified.
+    /// The height of the bounding box in half-inch increments.
     pub fn height(&self) -> f32 {
         self.height
     }
 
-    /// The width of the bounding box.
-    ///
-    /// The exact meaning and unit are not specified.
+    /// The width of the bounding box in half-inch increments.
     pub fn width(&self) -> f32 {
         self.width
     }
@@ -208,34 +214,43 @@ pub(crate) fn parse_ink_data(
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("ink data node is missing".into()))?;
     let ink_data = ink_data_node::parse(ink_data_object)?;
 
+    let scale_x = scale_x.unwrap_or(1.0);
+    let scale_y = scale_y.unwrap_or(1.0);
+
     let strokes = ink_data
         .strokes
         .iter()
         .copied()
         .map(|ink_stroke_id| parse_ink_stroke(ink_stroke_id, space, scale_x, scale_y))
-        .collect::<Result<_>>()?;
+        .collect::<Result<Vec<_>>>()?;
 
-    let scale_x = scale_x.unwrap_or(1.0);
-    let scale_y = scale_y.unwrap_or(1.0);
+    // The bounding box uses the same coordinate space as the stroke paths
+    let (units_x, units_y) = strokes
+        .first()
+        .map(|(_, units)| *units)
+        .unwrap_or((HIMETRIC_PER_HALF_INCH, HIMETRIC_PER_HALF_INCH));
 
     let bounding_box = ink_data
         .bounding_box
         .map(|[x_min, y_min, x_max, y_max]| InkBoundingBox {
-            x: x_min as f32 * scale_x,
-            y: y_min as f32 * scale_y,
-            width: (x_max as f32 - x_min as f32) * scale_x,
-            height: (y_max as f32 - y_min as f32) * scale_y,
+            x: x_min as i32 as f32 * scale_x / units_x,
+            y: y_min as i32 as f32 * scale_y / units_y,
+            width: (x_max as i32 as f32 - x_min as i32 as f32) * scale_x / units_x,
+            height: (y_max as i32 as f32 - y_min as i32 as f32) * scale_y / units_y,
         });
 
+    let strokes = strokes.into_iter().map(|(stroke, _)| stroke).collect();
+
     Ok((strokes, bounding_box))
 }
 
+/// Parse an ink stroke along with the number of path coordinate units per half-inch.
 fn parse_ink_stroke(
     ink_stroke_id: ExGuid,
     space: &ObjectSpace,
-    scale_x: Option<f32>,
-    scale_y: Option<f32>,
-) -> Result<InkStroke> {
+    scale_x: f32,
+    scale_y: f32,
+) -> Result<(InkStroke, (f32, f32))> {
     let object = space
         .get_object(ink_stroke_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("ink stroke node is missing".into()))?;
@@ -246,24 +261,26 @@ fn parse_ink_stroke(
     })?;
     let props = stroke_properties_node::parse(props_object)?;
 
-    let path = parse_ink_path(data.path, &props, scale_x, scale_y)?;
+    let (path, units) = parse_ink_path(data.path, &props, scale_x, scale_y)?;
 
-    Ok(InkStroke {
+    let stroke = InkStroke {
         path,
         pen_tip: props.pen_tip,
         transparency: props.transparency,
-        height: props.ink_height,
-        width: props.ink_width,
+        height: props.ink_height / HIMETRIC_PER_HALF_INCH,
+        width: props.ink_width / HIMETRIC_PER_HALF_INCH,
         color: props.color,
-    })
+    };
+
+    Ok((stroke, units))
 }
 
 fn parse_ink_path(
     data: Vec<i64>,
     props: &stroke_properties_node::Data,
-    scale_x: Option<f32>,
-    scale_y: Option<f32>,
-) -> Result<Vec<InkPoint>> {
+    scale_x: f32,
+    scale_y: f32,
+) -> Result<(Vec<InkPoint>, (f32, f32))> {
     // Find dimension indexes
     let idx_x = props
         .dimensions
@@ -289,18 +306,28 @@ fn parse_ink_path(
     let x = &data[start_x..start_x + dimension_offset];
     let y = &data[start_y..start_y + dimension_offset];
 
-    let scale_x = scale_x.unwrap_or(1.0);
-    let scale_y = scale_y.unwrap_or(1.0);
+    // Coordinates without a known unit are HIMETRIC like the pen size
+    let units_x = props.dimensions[idx_x]
+        .units_per_half_inch()
+        .unwrap_or(HIMETRIC_PER_HALF_INCH);
+    let units_y = props.dimensions[idx_y]
+        .units_per_half_inch()
+        .unwrap_or(HIMETRIC_PER_HALF_INCH);
 
+    // The first point is absolute, the following points are relative to their predecessor
     let path = x
         .iter()
-        .copied()
-        .zip(y.iter().copied())
-        .map(|(x, y)| InkPoint {
-            x: scale_x * x as f32,
-            y: scale_y * y as f32,
+        .zip(y.iter())
+        .scan((0i64, 0i64), |(abs_x, abs_y), (x, y)| {
+            *abs_x += x;
+            *abs_y += y;
+
+            Some(InkPoint {
+                x: scale_x * *abs_x as f32 / units_x,
+                y: scale_y * *abs_y as f32 / units_y,
+            })
         })
         .collect();
 
-    Ok(path)
+    Ok((path, (units_x, units_y)))
 }
@@ -0,0 +1,274 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::color::Color;
+use crate::one::property_set::{page_metadata, page_series_node};
+use crate::onenote::page::{parse_page, parse_page_revision, Page};
+use crate::onenote::section::{parse_content, parse_metadata};
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::OneStore;
+use crate::shared::guid::Guid;
+use std::fmt;
+
+/// A OneNote section whose pages are parsed on demand.
+///
+/// Opening a lazy section only parses the section's root object space which holds the page
+/// metadata. The store borrows its data from the section file's buffer which may be
+/// memory-mapped. A page's object space is parsed when the page is first requested using
+/// [`LazySection::page()`] and kept with the section. The pages themselves aren't cached, so
+/// only the pages currently in use are kept in memory.
+pub struct LazySection<'a> {
+    store: OneStore<'a>,
+    id: Guid,
+    display_name: String,
+    color: Option<Color>,
+    pages: Vec<PageHeader>,
+}
+
+impl<'a> LazySection<'a> {
+    /// The section's unique ID.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
+    /// The section name.
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    /// The color of the section.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// The headers of all pages in the section in the order they appear in the section.
+    pub fn pages(&self) -> &[PageHeader] {
+        &self.pages
+    }
+
+    /// Write the section back into a `.one` file.
+    ///
+    /// The section is written in the FSSHTTPB packaging format it was read from, so the
+    /// returned data is identical to the section file's contents. Sections stored in the
+    /// revision store format used by the OneNote desktop applications can't be written.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut data = vec![];
+        self.store.write(&mut data)?;
+
+        Ok(data)
+    }
+
+    /// Parse the page belonging to a page header.
+    ///
+    /// The page's object space keeps borrowing from the section file's buffer. Only the
+    /// data the page exposes (e.g. the contents of images and embedded files) is copied
+    /// into the page. The page's revision history only lists the revisions, use
+    /// [`LazySection::page_revision()`] to parse the page as of a revision.
+    pub fn page(&self, header: &PageHeader) -> Result<Page> {
+        let space = self
+            .store
+            .object_space(header.space_id)?
+            .ok_or_else(|| ErrorKind::MalformedOneNoteData("page space is missing".into()))?;
+
+        parse_page(space)
+    }
+
+    /// Parse the page belonging to a page header as of one of the page's revisions.
+    ///
+    /// The revision IDs are listed by [`Page::revisions()`]. Returns `None` if the page
+    /// has no revision with the given ID. Older revisions may depend on objects that are
+    /// no longer stored in the file, these return an error.
+    pub fn page_revision(&self, header: &PageHeader, revision_id: ExGuid) -> Result<Option<Page>> {
+        let space = self
+            .store
+            .object_space(header.space_id)?
+            .ok_or_else(|| ErrorKind::MalformedOneNoteData("page space is missing".into()))?;
+
+        parse_page_revision(space, revision_id)
+    }
+}
+
+impl fmt::Debug for LazySection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazySection")
+            .field("id", &self.id)
+            .field("display_name", &self.display_name)
+            .field("color", &self.color)
+            .field("pages", &self.pages)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Summary information about a page that is available without parsing the page.
+///
+/// See [\[MS-ONE\] 2.2.30].
+///
+/// [\[MS-ONE\] 2.2.30]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/aaabcc70-5836-4dcb-8209-012ce5d45b3c
+#[derive(Clone, Debug)]
+pub struct PageHeader {
+    space_id: CellId,
+    id: Guid,
+    title: String,
+    level: i32,
+    page_series: usize,
+}
+
+impl PageHeader {
+    /// The page's unique ID.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
+    /// The page title as cached by OneNote.
+    ///
+    /// The cached title may differ from the title text of the parsed page.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The page's level in the section page tree.
+    ///
+    /// See [\[MS-ONE\] 2.3.74].
+    ///
+    /// [\[MS-ONE\] 2.3.74]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/a8632c90-e74a-4ef6-8852-707d4c8817cd
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    /// The index of the page series that contains the page.
+    pub fn page_series(&self) -> usize {
+        self.page_series
+    }
+}
+
+pub(crate) fn parse_lazy_section(store: OneStore, filename: String) -> Result<LazySection> {
+    let metadata = parse_metadata(store.data_root())?;
+    let content = parse_content(store.data_root())?;
+
+    let display_name = metadata
+        .display_name
+        .unwrap_or(filename)
+        .trim_end_matches(".one")
+        .to_string();
+
+    let mut pages = vec![];
+
+    for (index, page_series_id) in content.page_series.into_iter().enumerate() {
+        let object = store
+            .data_root()
+            .get_object(page_series_id)
+            .ok_or_else(|| {
+                ErrorKind::MalformedOneNoteData("page series object is missing".into())
+            })?;
+        let data = page_series_node::parse(object)?;
+
+        // The page series keeps a copy of each page's metadata so the page spaces don't
+        // have to be parsed. Older files may not list the metadata for all pages.
+        if data.page_metadata.len() == data.page_spaces.len() {
+            for (space_id, metadata_id) in data.page_spaces.into_iter().zip(data.page_metadata) {
+                let header = parse_page_header(space_id, metadata_id, store.data_root(), index)?;
+                pages.push(header);
+            }
+        } else {
+            for space_id in data.page_spaces {
+                let space = store.object_space(space_id)?.ok_or_else(|| {
+                    ErrorKind::MalformedOneNoteData("page space is missing".into())
+                })?;
+                let metadata_id = space.metadata_root().ok_or_else(|| {
+                    ErrorKind::MalformedOneNoteData("page metadata id is missing".into())
+                })?;
+
+                pages.push(parse_page_header(space_id, metadata_id, space, index)?);
+            }
+        }
+    }
+
+    Ok(LazySection {
+        store,
+        id: content.entity_guid,
+        display_name,
+        color: metadata.color,
+        pages,
+    })
+}
+
+fn parse_page_header(
+    space_id: CellId,
+    metadata_id: ExGuid,
+    space: &ObjectSpace,
+    page_series: usize,
+) -> Result<PageHeader> {
+    let metadata_object = space
+        .get_object(metadata_id)
+        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page metadata object is missing".into()))?;
+    let metadata = page_metadata::parse(metadata_object)?;
+
+    Ok(PageHeader {
+        space_id,
+        id: metadata.entity_guid,
+        title: metadata.cached_title,
+        level: metadata.page_level,
+        page_series,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::builder::{ImageBuilder, PageBuilder, PageSeriesBuilder, SectionBuilder};
+    use crate::Parser;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_parse_page_spaces_on_demand() {
+        let builder = SectionBuilder::new("Section").with_page_series(
+            PageSeriesBuilder::new()
+                .with_page(PageBuilder::new("First"))
+                .with_page(PageBuilder::new("Second")),
+        );
+        let data = builder.to_bytes().unwrap();
+
+        let section = Parser::new()
+            .open_section(&data, &builder.file_name())
+            .unwrap();
+
+        let titles: Vec<_> = section.pages().iter().map(|page| page.title()).collect();
+        assert_eq!(titles, ["First", "Second"]);
+        assert_eq!(section.store.object_spaces().count(), 0);
+
+        let page = section.page(&section.pages()[1]).unwrap();
+
+        assert_eq!(page.title_text(), Some("Second"));
+        assert_eq!(section.store.object_spaces().count(), 1);
+    }
+
+    #[test]
+    fn test_page_borrows_file_data() {
+        let image = vec![0x89, 0x50, 0x4E, 0x47, 1, 2, 3, 4];
+        let builder = SectionBuilder::new("Section").with_page_series(
+            PageSeriesBuilder::new()
+                .with_page(PageBuilder::new("Image").with_image(ImageBuilder::new(image.clone()))),
+        );
+        let data = builder.to_bytes().unwrap();
+
+        let section = Parser::new()
+            .open_section(&data, &builder.file_name())
+            .unwrap();
+        let page = section.page(&section.pages()[0]).unwrap();
+
+        assert_eq!(page.contents()[0].image().unwrap().data(), Some(&image[..]));
+
+        // The page space's objects still point into the section file's buffer
+        let (_, space) = section.store.object_spaces().next().unwrap();
+        let file_data: Vec<_> = space
+            .objects()
+            .filter_map(|(_, object)| object.file_data.as_ref())
+            .collect();
+
+        assert!(!file_data.is_empty());
+        let buffer = data.as_ptr_range();
+        assert!(file_data.iter().all(|blob| match blob {
+            Cow::Borrowed(blob) => buffer.contains(&blob.as_ptr()),
+            Cow::Owned(_) => false,
+        }));
+    }
+}
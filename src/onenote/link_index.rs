@@ -0,0 +1,162 @@
+use crate::onenote::notebook::Notebook;
+use crate::onenote::page::Page;
+use crate::onenote::section::{Section, SectionEntry};
+use crate::shared::guid::Guid;
+use std::collections::HashMap;
+
+/// An index resolving links between the pages of a notebook.
+///
+/// OneNote links to pages and sections of a notebook using `onenote:` URLs, e.g.
+/// `onenote:Section.one#Page&section-id={...}&page-id={...}&end`. The URL's path
+/// depends on where the notebook was stored when the link was created, so links
+/// are resolved using the section and page IDs only.
+#[derive(Clone, Debug)]
+pub struct LinkIndex<'a> {
+    sections: HashMap<Guid, &'a Section>,
+    pages: HashMap<Guid, Vec<(&'a Section, &'a Page)>>,
+}
+
+impl<'a> LinkIndex<'a> {
+    /// Build the link index for all sections and pages of a notebook.
+    pub fn new(notebook: &'a Notebook) -> LinkIndex<'a> {
+        let mut index = LinkIndex {
+            sections: HashMap::new(),
+            pages: HashMap::new(),
+        };
+
+        index.add_entries(notebook.entries());
+
+        index
+    }
+
+    fn add_entries(&mut self, entries: &'a [SectionEntry]) {
+        for entry in entries {
+            match entry {
+                SectionEntry::Section(section) => self.add_section(section),
+                SectionEntry::SectionGroup(group) => self.add_entries(group.entries()),
+            }
+        }
+    }
+
+    fn add_section(&mut self, section: &'a Section) {
+        self.sections.insert(section.id(), section);
+
+        for page in section
+            .page_series()
+            .iter()
+            .flat_map(|series| series.pages())
+        {
+            self.pages
+                .entry(page.id())
+                .or_default()
+                .push((section, page));
+        }
+    }
+
+    /// Resolve a `onenote:` link to the page it points to.
+    ///
+    /// Returns `None` if the URL isn't a page link or the page isn't part of the
+    /// notebook.
+    pub fn resolve(&self, url: &str) -> Option<(&'a Section, &'a Page)> {
+        let (section_id, page_id) = parse_link(url)?;
+
+        let pages = self.pages.get(&page_id?)?;
+
+        // Prefer the linked section in case a page ID isn't unique, e.g. for copied sections
+        pages
+            .iter()
+            .find(|(section, _)| Some(section.id()) == section_id)
+            .or_else(|| pages.first())
+            .copied()
+    }
+
+    /// Resolve a `onenote:` link to the section it points to.
+    ///
+    /// For page links this is the section containing the page. Returns `None` if
+    /// the URL isn't a section or page link or the section isn't part of the notebook.
+    pub fn resolve_section(&self, url: &str) -> Option<&'a Section> {
+        match parse_link(url)? {
+            (Some(section_id), _) => self.section(section_id),
+            (None, _) => self.resolve(url).map(|(section, _)| section),
+        }
+    }
+
+    /// Look up a section by its ID.
+    pub fn section(&self, id: Guid) -> Option<&'a Section> {
+        self.sections.get(&id).copied()
+    }
+
+    /// Look up a page and its section by the page ID.
+    pub fn page(&self, id: Guid) -> Option<(&'a Section, &'a Page)> {
+        self.pages.get(&id)?.first().copied()
+    }
+}
+
+/// Extract the section and page IDs from a `onenote:` link.
+///
+/// Returns `None` if the URL isn't a `onenote:` link.
+fn parse_link(url: &str) -> Option<(Option<Guid>, Option<Guid>)> {
+    let scheme = url.get(..8)?;
+    if !scheme.eq_ignore_ascii_case("onenote:") {
+        return None;
+    }
+
+    let (_, fragment) = url.split_once('#')?;
+
+    let mut section_id = None;
+    let mut page_id = None;
+
+    for (key, value) in fragment
+        .split('&')
+        .filter_map(|param| param.split_once('='))
+    {
+        match key {
+            "section-id" => section_id = parse_id(value),
+            "page-id" => page_id = parse_id(value),
+            _ => {}
+        }
+    }
+
+    Some((section_id, page_id))
+}
+
+/// Parse an ID from a link parameter. The braces may be percent-encoded.
+fn parse_id(value: &str) -> Option<Guid> {
+    let value = value.to_ascii_uppercase();
+    let value = value
+        .trim_start_matches("%7B")
+        .trim_end_matches("%7D")
+        .trim_start_matches('{')
+        .trim_end_matches('}');
+
+    Guid::from_str(value).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_link;
+    use crate::shared::guid::Guid;
+
+    #[test]
+    fn test_parse_link() {
+        let section_id = Guid::from_str("6B1A4A2A-9F4B-4C1E-8E1C-2A4B6F1D3C5E").unwrap();
+        let page_id = Guid::from_str("5EAF76B1-8DDE-8745-A8A7-1A9865CC478A").unwrap();
+
+        assert_eq!(
+            parse_link(
+                "onenote:https://example.com/Notebook/Section.one#Test%20Page\
+                 &section-id={6B1A4A2A-9F4B-4C1E-8E1C-2A4B6F1D3C5E}\
+                 &page-id={5EAF76B1-8DDE-8745-A8A7-1A9865CC478A}&end"
+            ),
+            Some((Some(section_id), Some(page_id)))
+        );
+        assert_eq!(
+            parse_link(
+                "onenote:#Test&section-id=%7b6b1a4a2a-9f4b-4c1e-8e1c-2a4b6f1d3c5e%7d&end\
+                 &base-path=https://example.com/Notebook"
+            ),
+            Some((Some(section_id), None))
+        );
+        assert_eq!(parse_link("https://example.com/#page-id={}"), None);
+    }
+}
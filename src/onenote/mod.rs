@@ -1,37 +1,78 @@
-use crate::errors::{ErrorKind, Result};
-use crate::fsshttpb::packaging::OneStorePackaging;
-use crate::onenote::notebook::Notebook;
-use crate::onenote::section::{Section, SectionEntry, SectionGroup};
-use crate::onestore::parse_store;
-use crate::reader::Reader;
-use std::ffi::OsStr;
+use crate::errors::{ErrorKind, Result, Warning};
+use crate::onenote::lazy_section::{parse_lazy_section, LazySection};
+use crate::onenote::notebook::{Notebook, TocEntry};
+use crate::onenote::options::ParseOptions;
+use crate::onenote::raw::RawStore;
+use crate::onenote::resolver::{NotebookResolver, ResolvedEntry};
+use crate::onenote::section::{Section, SectionEntry, SectionGroup, RECYCLE_BIN_NAME};
+use crate::onenote::source::{read_file, FsSource, NotebookSource, SourceResolver};
+use crate::onestore::{parse_store, OneStore, StoreOptions};
 use std::fs::File;
-use std::io::{BufReader, Read};
-use std::path::Path;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
 
+pub(crate) mod builder;
 pub(crate) mod content;
 pub(crate) mod embedded_file;
 pub(crate) mod iframe;
 pub(crate) mod image;
 pub(crate) mod ink;
+pub(crate) mod lazy_section;
+pub(crate) mod link_index;
 pub(crate) mod list;
 pub(crate) mod note_tag;
 pub(crate) mod notebook;
+pub(crate) mod options;
 pub(crate) mod outline;
 pub(crate) mod page;
 pub(crate) mod page_content;
 pub(crate) mod page_series;
+pub(crate) mod printout;
+pub(crate) mod raw;
+pub(crate) mod recording;
+pub(crate) mod resolver;
 pub(crate) mod rich_text;
 pub(crate) mod section;
+pub(crate) mod source;
 pub(crate) mod table;
+pub(crate) mod unknown;
 
 /// The OneNote file parser.
-pub struct Parser;
+pub struct Parser {
+    options: ParseOptions,
+    warnings: Vec<Warning>,
+}
 
 impl Parser {
     /// Create a new OneNote file parser.
     pub fn new() -> Parser {
-        Parser {}
+        Parser::with_options(ParseOptions::new())
+    }
+
+    /// Create a new OneNote file parser using custom parse options.
+    pub fn with_options(options: ParseOptions) -> Parser {
+        Parser {
+            options,
+            warnings: vec![],
+        }
+    }
+
+    /// The options used by the parser.
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// The errors skipped so far when parsing in lenient mode.
+    ///
+    /// Warnings are collected across all files parsed by the parser, use
+    /// [`Parser::take_warnings()`] to clear them.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Return the errors skipped so far and clear them.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
     }
 
     /// Parse a OneNote notebook.
@@ -41,39 +82,114 @@ impl Parser {
     /// sections from the folder that the table of contents file is in.
     pub fn parse_notebook(&mut self, path: &Path) -> Result<Notebook> {
         let file = File::open(path)?;
-        let data = Parser::read(file)?;
-        let packaging = OneStorePackaging::parse(&mut Reader::new(data.as_slice()))?;
-        let store = parse_store(&packaging)?;
+        let data = self.read(file, &path.to_string_lossy())?;
+        let store = self.toc_store(&data, &path.to_string_lossy())?;
 
-        if store.schema_guid() != guid!({E4DBFD38-E5C7-408B-A8A1-0E7B421E1F5F}) {
-            return Err(ErrorKind::NotATocFile {
-                file: path.to_string_lossy().to_string(),
-            }
-            .into());
-        }
+        let base_dir = path.parent().expect("no base dir found");
+        let mut source = FsSource::new(base_dir);
+        let mut resolver =
+            SourceResolver::new(&mut source, PathBuf::new(), self.options.max_file_size());
+
+        self.parse_toc_notebook(&store, path, &mut resolver)
+    }
+
+    /// Parse a OneNote notebook from a notebook source.
+    ///
+    /// The `path` argument must point to a `.onetoc2` file within the `source`. The
+    /// notebook's sections and section groups are read from the directory that contains
+    /// the table of contents file, e.g. from a ZIP archive using [`ZipSource`].
+    ///
+    /// [`ZipSource`]: crate::notebook::ZipSource
+    pub fn parse_notebook_from_source(
+        &mut self,
+        source: &mut dyn NotebookSource,
+        path: &Path,
+    ) -> Result<Notebook> {
+        let file = path.to_string_lossy();
+        let data = read_file(source.open(path)?, &file, self.options.max_file_size())?;
+
+        let store = self.toc_store(&data, &file)?;
+
+        let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut resolver = SourceResolver::new(source, base_dir, self.options.max_file_size());
 
+        self.parse_toc_notebook(&store, path, &mut resolver)
+    }
+
+    /// Parse a OneNote notebook from a table of contents file's contents.
+    ///
+    /// The `data` argument must contain the contents of a `.onetoc2` file and `name` is the
+    /// file's name. If the name includes the notebook's directory, e.g.
+    /// `Notebook/Open Notebook.onetoc2`, the directory name is used as the notebook name.
+    /// The notebook's sections and section groups are looked up using the `resolver`.
+    pub fn parse_notebook_from_bytes(
+        &mut self,
+        data: &[u8],
+        name: &str,
+        resolver: &mut dyn NotebookResolver,
+    ) -> Result<Notebook> {
+        let store = self.toc_store(data, name)?;
+
+        self.parse_toc_notebook(&store, Path::new(name), resolver)
+    }
+
+    /// Parse the recycle bin of a OneNote notebook.
+    ///
+    /// The `path` argument must point to the notebook's `.onetoc2` file. The recycle bin
+    /// contains the notebook's deleted sections and pages, which are left out by
+    /// [`Parser::parse_notebook()`]. All pages in the recycle bin are marked as deleted,
+    /// see [`Page::is_deleted()`]. Returns `None` if the notebook has no recycle bin.
+    ///
+    /// [`Page::is_deleted()`]: crate::page::Page::is_deleted()
+    pub fn parse_recycle_bin(&mut self, path: &Path) -> Result<Option<SectionGroup>> {
         let base_dir = path.parent().expect("no base dir found");
-        let sections = notebook::parse_toc(store.data_root())?
-            .iter()
-            .map(|name| {
-                let mut file = base_dir.to_path_buf();
-                file.push(name);
-
-                file
-            })
-            .filter(|p| p.exists())
-            .filter(|p| !p.ends_with("OneNote_RecycleBin"))
-            .map(|path| {
-                if path.is_file() {
-                    self.parse_section(&path).map(SectionEntry::Section)
-                } else {
-                    self.parse_section_group(&path)
-                        .map(SectionEntry::SectionGroup)
-                }
-            })
-            .collect::<Result<_>>()?;
-
-        Ok(Notebook { entries: sections })
+        let mut source = FsSource::new(base_dir);
+        let mut resolver =
+            SourceResolver::new(&mut source, PathBuf::new(), self.options.max_file_size());
+
+        self.parse_recycle_bin_from_resolver(&mut resolver)
+    }
+
+    /// Parse the recycle bin of a OneNote notebook from a notebook source.
+    ///
+    /// The `path` argument must point to the notebook's `.onetoc2` file within the
+    /// `source`. See [`Parser::parse_recycle_bin()`] for details.
+    pub fn parse_recycle_bin_from_source(
+        &mut self,
+        source: &mut dyn NotebookSource,
+        path: &Path,
+    ) -> Result<Option<SectionGroup>> {
+        let base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut resolver = SourceResolver::new(source, base_dir, self.options.max_file_size());
+
+        self.parse_recycle_bin_from_resolver(&mut resolver)
+    }
+
+    /// Parse the recycle bin of a OneNote notebook using a resolver.
+    ///
+    /// The recycle bin is looked up using the `resolver` just like the notebook's
+    /// section groups. See [`Parser::parse_recycle_bin()`] for details.
+    pub fn parse_recycle_bin_from_resolver(
+        &mut self,
+        resolver: &mut dyn NotebookResolver,
+    ) -> Result<Option<SectionGroup>> {
+        let path = [RECYCLE_BIN_NAME.to_string()];
+
+        let data = match resolver.resolve(&path)? {
+            Some(ResolvedEntry::SectionGroup(data)) => data,
+            _ => return Ok(None),
+        };
+
+        let entry = TocEntry {
+            name: RECYCLE_BIN_NAME.to_string(),
+            ordering_id: None,
+            color: None,
+        };
+
+        let mut group = self.parse_section_group(&data, entry, resolver, &path, true)?;
+        group.mark_deleted();
+
+        Ok(Some(group))
     }
 
     /// Parse a OneNote section file.
@@ -82,16 +198,8 @@ impl Parser {
     /// OneNote section.
     pub fn parse_section(&mut self, path: &Path) -> Result<Section> {
         let file = File::open(path)?;
-        let data = Parser::read(file)?;
-        let packaging = OneStorePackaging::parse(&mut Reader::new(data.as_slice()))?;
-        let store = parse_store(&packaging)?;
-
-        if store.schema_guid() != guid!({1F937CB4-B26F-445F-B9F8-17E20160E461}) {
-            return Err(ErrorKind::NotASectionFile {
-                file: path.to_string_lossy().to_string(),
-            }
-            .into());
-        }
+        let data = self.read(file, &path.to_string_lossy())?;
+        let store = self.section_store(&data, &path.to_string_lossy())?;
 
         section::parse_section(
             store,
@@ -99,45 +207,238 @@ impl Parser {
                 .expect("file without file name")
                 .to_string_lossy()
                 .to_string(),
+            &self.options,
+            &mut self.warnings,
         )
     }
 
-    fn parse_section_group(&mut self, path: &Path) -> Result<SectionGroup> {
-        let display_name = path
-            .file_name()
-            .expect("file without file name")
-            .to_string_lossy()
-            .to_string();
-
-        for entry in path.read_dir()? {
-            let entry = entry?;
-            let is_toc = entry
-                .path()
-                .extension()
-                .map(|ext| ext == OsStr::new("onetoc2"))
-                .unwrap_or_default();
-
-            if is_toc {
-                return self
-                    .parse_notebook(&entry.path())
-                    .map(|group| SectionGroup {
-                        display_name,
-                        entries: group.entries,
-                    });
+    /// Parse a OneNote section from a section file's contents.
+    ///
+    /// The `data` argument must contain the contents of a `.one` file and `name` is the
+    /// file's name. The name is used as the section name if the section doesn't specify
+    /// one.
+    pub fn parse_section_from_bytes(&mut self, data: &[u8], name: &str) -> Result<Section> {
+        let store = self.section_store(data, name)?;
+
+        section::parse_section(store, name.to_string(), &self.options, &mut self.warnings)
+    }
+
+    /// Parse a OneNote section from a reader.
+    ///
+    /// The section file is read starting at the reader's current position. See
+    /// [`Parser::parse_section_from_bytes()`] for details.
+    pub fn parse_section_from_reader<R: Read + Seek>(
+        &mut self,
+        reader: R,
+        name: &str,
+    ) -> Result<Section> {
+        let data = self.read(reader, name)?;
+
+        self.parse_section_from_bytes(&data, name)
+    }
+
+    /// Open a OneNote section for parsing its pages on demand.
+    ///
+    /// The `data` argument must contain the contents of a `.one` file, e.g. read from disk
+    /// or memory-mapped. The `file_name` is used as the section name if the section
+    /// doesn't specify one. Unlike [`Parser::parse_section()`] this only parses the
+    /// section's page headers, see [`LazySection`].
+    pub fn open_section<'a>(&mut self, data: &'a [u8], file_name: &str) -> Result<LazySection<'a>> {
+        let store = self.section_store(data, file_name)?;
+
+        parse_lazy_section(store, file_name.to_string())
+    }
+
+    /// Open a OneNote section or table of contents file for inspecting its objects.
+    ///
+    /// The `data` argument must contain the contents of a `.one` or `.onetoc2` file and
+    /// `file_name` is used in error messages. See [`RawStore`] for details.
+    pub fn open_raw<'a>(&mut self, data: &'a [u8], file_name: &str) -> Result<RawStore<'a>> {
+        let store = self.parse_store(data, file_name)?;
+
+        RawStore::new(store)
+    }
+
+    fn parse_toc_notebook(
+        &mut self,
+        store: &OneStore,
+        path: &Path,
+        resolver: &mut dyn NotebookResolver,
+    ) -> Result<Notebook> {
+        let toc = notebook::parse_toc(store.data_root())?;
+        let mut entries = self.parse_toc_entries(toc.entries, resolver, &[])?;
+
+        if self.options.include_recycle_bin() {
+            let recycle_bin = self.parse_recycle_bin_from_resolver(resolver);
+
+            if let Some(group) = self.skip_errors(recycle_bin, RECYCLE_BIN_NAME)? {
+                entries.push(SectionEntry::SectionGroup(group));
+            }
+        }
+
+        Ok(Notebook {
+            id: store.file_identity(),
+            display_name: notebook::display_name(path),
+            color: toc.color,
+            entries,
+        })
+    }
+
+    fn parse_toc_entries(
+        &mut self,
+        toc: Vec<TocEntry>,
+        resolver: &mut dyn NotebookResolver,
+        parent: &[String],
+    ) -> Result<Vec<SectionEntry>> {
+        let mut entries = vec![];
+
+        for entry in toc {
+            if entry.name == RECYCLE_BIN_NAME {
+                continue;
+            }
+
+            let mut path = parent.to_vec();
+            path.push(entry.name.clone());
+
+            let entry = self.parse_toc_entry(entry, resolver, &path);
+
+            if let Some(entry) = self.skip_errors(entry, &path.join("/"))? {
+                entries.push(entry);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn parse_toc_entry(
+        &mut self,
+        entry: TocEntry,
+        resolver: &mut dyn NotebookResolver,
+        path: &[String],
+    ) -> Result<Option<SectionEntry>> {
+        match resolver.resolve(path)? {
+            Some(ResolvedEntry::Section(data)) => {
+                let store = self.section_store(&data, &path.join("/"))?;
+                let mut section =
+                    section::parse_section(store, entry.name, &self.options, &mut self.warnings)?;
+
+                section.ordering_id = entry.ordering_id;
+                section.color = section.color.or(entry.color);
+
+                Ok(Some(SectionEntry::Section(section)))
+            }
+            Some(ResolvedEntry::SectionGroup(data)) => {
+                let follow = self.options.follow_section_groups();
+                let group = self.parse_section_group(&data, entry, resolver, path, follow)?;
+
+                Ok(Some(SectionEntry::SectionGroup(group)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn parse_section_group(
+        &mut self,
+        data: &[u8],
+        entry: TocEntry,
+        resolver: &mut dyn NotebookResolver,
+        path: &[String],
+        follow: bool,
+    ) -> Result<SectionGroup> {
+        let store = self.toc_store(data, &path.join("/"))?;
+
+        let entries = if follow {
+            let toc = notebook::parse_toc(store.data_root())?;
+
+            self.parse_toc_entries(toc.entries, resolver, path)?
+        } else {
+            vec![]
+        };
+
+        Ok(SectionGroup {
+            id: store.file_identity(),
+            display_name: entry.name,
+            color: entry.color,
+            ordering_id: entry.ordering_id,
+            entries,
+        })
+    }
+
+    /// Leave out entries that fail to parse when not in strict mode.
+    ///
+    /// The skipped errors are kept as warnings.
+    fn skip_errors<T>(&mut self, result: Result<Option<T>>, location: &str) -> Result<Option<T>> {
+        match result {
+            Err(err) if !self.options.is_strict() => {
+                self.warnings.push(Warning::new(location, err));
+
+                Ok(None)
             }
+            result => result,
         }
+    }
 
-        Err(ErrorKind::TocFileMissing {
-            dir: path.as_os_str().to_string_lossy().into_owned(),
+    fn section_store<'a>(&self, data: &'a [u8], file: &str) -> Result<OneStore<'a>> {
+        let store = self.parse_store(data, file)?;
+
+        if store.schema_guid() != guid!({1F937CB4-B26F-445F-B9F8-17E20160E461}) {
+            return Err(ErrorKind::NotASectionFile {
+                file: file.to_string(),
+            }
+            .into());
         }
-        .into())
+
+        Ok(store)
     }
 
-    fn read(file: File) -> Result<Vec<u8>> {
-        let size = file.metadata()?.len();
+    fn toc_store<'a>(&self, data: &'a [u8], file: &str) -> Result<OneStore<'a>> {
+        let store = self.parse_store(data, file)?;
+
+        if store.schema_guid() != guid!({E4DBFD38-E5C7-408B-A8A1-0E7B421E1F5F}) {
+            return Err(ErrorKind::NotATocFile {
+                file: file.to_string(),
+            }
+            .into());
+        }
+
+        Ok(store)
+    }
+
+    fn parse_store<'a>(&self, data: &'a [u8], file: &str) -> Result<OneStore<'a>> {
+        self.check_file_size(data.len() as u64, file)?;
+
+        // The object limit is enforced while parsing, including for object spaces that
+        // are parsed on demand
+        let options = StoreOptions::new(file)
+            .with_payloads(self.options.load_payloads())
+            .with_max_object_count(self.options.max_object_count());
+
+        parse_store(data, options)
+    }
+
+    fn check_file_size(&self, size: u64, file: &str) -> Result<()> {
+        match self.options.max_file_size() {
+            Some(max) if size > max as u64 => Err(ErrorKind::FileTooLarge {
+                file: file.to_string(),
+                size,
+                max,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn read<R: Read + Seek>(&self, mut reader: R, file: &str) -> Result<Vec<u8>> {
+        let position = reader.stream_position()?;
+        let size = reader.seek(SeekFrom::End(0))? - position;
+        reader.seek(SeekFrom::Start(position))?;
+
+        // Check the size before reading the whole file into memory
+        self.check_file_size(size, file)?;
+
         let mut data = Vec::with_capacity(size as usize);
 
-        let mut buf = BufReader::new(file);
+        let mut buf = BufReader::new(reader);
         buf.read_to_end(&mut data)?;
 
         Ok(data)
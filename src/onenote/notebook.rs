@@ -1,39 +1,97 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::color::Color;
 use crate::one::property_set::toc_container;
+use crate::onenote::link_index::LinkIndex;
 use crate::onenote::section::SectionEntry;
 use crate::onestore::object_space::ObjectSpace;
+use crate::shared::guid::Guid;
 use itertools::Itertools;
+use std::path::Path;
 
 /// A OneNote notebook.
 #[derive(Clone, Debug)]
 pub struct Notebook {
+    pub(crate) id: Guid,
+    pub(crate) display_name: String,
+    pub(crate) color: Option<Color>,
     pub(crate) entries: Vec<SectionEntry>,
 }
 
 impl Notebook {
+    /// The notebook's unique ID.
+    ///
+    /// This is the file identity of the notebook's table of contents file.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
+    /// The notebook name.
+    ///
+    /// This is the name of the directory containing the notebook's table of contents file.
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    /// The color of the notebook.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
     /// The section entries of this notebook.
     pub fn entries(&self) -> &[SectionEntry] {
         &self.entries
     }
+
+    /// Build an index to resolve links between the notebook's pages.
+    pub fn link_index(&self) -> LinkIndex<'_> {
+        LinkIndex::new(self)
+    }
+}
+
+/// A notebook's table of contents.
+pub(crate) struct Toc {
+    pub(crate) color: Option<Color>,
+    pub(crate) entries: Vec<TocEntry>,
+}
+
+/// A section or section group listed in a table of contents.
+pub(crate) struct TocEntry {
+    pub(crate) name: String,
+    pub(crate) ordering_id: Option<u32>,
+    pub(crate) color: Option<Color>,
+}
+
+/// The display name of a notebook from its table of contents file's path.
+pub(crate) fn display_name(toc_path: &Path) -> String {
+    toc_path
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .or_else(|| toc_path.file_stem())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
 }
 
-pub(crate) fn parse_toc(space: &ObjectSpace) -> Result<Vec<String>> {
+pub(crate) fn parse_toc(space: &ObjectSpace) -> Result<Toc> {
     let content_id = space
         .content_root()
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("notebook has no content root".into()))?;
+    let content = space.get_object(content_id).ok_or_else(|| {
+        ErrorKind::MalformedOneNoteData("notebook content root is missing".into())
+    })?;
+
+    let color = toc_container::parse(content)?.color;
 
-    let toc = parse_toc_entry(content_id, space)?
+    let entries = parse_toc_entry(content_id, space)?
         .into_iter()
-        .sorted_by_key(|(ordering_id, _)| *ordering_id)
-        .dedup_by(|(_, a), (_, b)| a == b)
-        .map(|(_, name)| name)
+        .sorted_by_key(|entry| entry.ordering_id)
+        .dedup_by(|a, b| a.name == b.name)
         .collect();
 
-    Ok(toc)
+    Ok(Toc { color, entries })
 }
 
-fn parse_toc_entry(content_id: ExGuid, space: &ObjectSpace) -> Result<Vec<(u32, String)>> {
+fn parse_toc_entry(content_id: ExGuid, space: &ObjectSpace) -> Result<Vec<TocEntry>> {
     let content = space.get_object(content_id).ok_or_else(|| {
         ErrorKind::MalformedOneNoteData("notebook content root is missing".into())
     })?;
@@ -45,7 +103,11 @@ fn parse_toc_entry(content_id: ExGuid, space: &ObjectSpace) -> Result<Vec<(u32,
             .ordering_id
             .ok_or_else(|| ErrorKind::MalformedOneNoteData("section has no order id".into()))?;
 
-        Ok(vec![(ordering_id, name)])
+        Ok(vec![TocEntry {
+            name,
+            ordering_id: Some(ordering_id),
+            color: toc.color,
+        }])
     } else {
         let children = toc
             .children
@@ -0,0 +1,121 @@
+/// Options controlling how the [`Parser`](crate::Parser) reads OneNote files.
+///
+/// The default options are used by [`Parser::new()`](crate::Parser::new()): parsing is
+/// strict, binary payloads are loaded, section groups are followed, the recycle bin
+/// is left out and no resource limits apply.
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    strict: bool,
+    load_payloads: bool,
+    include_recycle_bin: bool,
+    follow_section_groups: bool,
+    max_file_size: Option<usize>,
+    max_object_count: Option<usize>,
+}
+
+impl ParseOptions {
+    /// Create the default parse options.
+    pub fn new() -> ParseOptions {
+        ParseOptions {
+            strict: true,
+            load_payloads: true,
+            include_recycle_bin: false,
+            follow_section_groups: true,
+            max_file_size: None,
+            max_object_count: None,
+        }
+    }
+
+    /// Set whether parsing fails on the first error.
+    ///
+    /// In lenient mode sections and section groups that fail to parse are left out of the
+    /// notebook and pages that fail to parse are left out of their section. The skipped
+    /// errors are available from [`Parser::warnings()`](crate::Parser::warnings()).
+    pub fn with_strict(mut self, strict: bool) -> ParseOptions {
+        self.strict = strict;
+        self
+    }
+
+    /// Set whether the binary data of images and embedded files is loaded.
+    ///
+    /// If disabled, [`Image::data()`] returns `None` and [`EmbeddedFile::data()`] is
+    /// empty. The remaining metadata (file names, sizes, layout) is still available. The
+    /// binary data isn't copied from the file at all, so recording info isn't available
+    /// either, see [`EmbeddedFile::recording_info()`].
+    ///
+    /// [`Image::data()`]: crate::contents::Image::data
+    /// [`EmbeddedFile::data()`]: crate::contents::EmbeddedFile::data
+    /// [`EmbeddedFile::recording_info()`]: crate::contents::EmbeddedFile::recording_info
+    pub fn with_payloads(mut self, load_payloads: bool) -> ParseOptions {
+        self.load_payloads = load_payloads;
+        self
+    }
+
+    /// Set whether a notebook's recycle bin is included as a section group.
+    ///
+    /// See [`Parser::parse_recycle_bin()`](crate::Parser::parse_recycle_bin()).
+    pub fn with_recycle_bin(mut self, include_recycle_bin: bool) -> ParseOptions {
+        self.include_recycle_bin = include_recycle_bin;
+        self
+    }
+
+    /// Set whether the sections of section groups are parsed.
+    ///
+    /// If disabled, a notebook's section groups are listed without their entries.
+    pub fn with_section_groups(mut self, follow_section_groups: bool) -> ParseOptions {
+        self.follow_section_groups = follow_section_groups;
+        self
+    }
+
+    /// Set the maximum size of a section or table of contents file in bytes.
+    pub fn with_max_file_size(mut self, max_file_size: usize) -> ParseOptions {
+        self.max_file_size = Some(max_file_size);
+        self
+    }
+
+    /// Set the maximum number of objects stored in a section or table of contents file.
+    ///
+    /// Objects are counted while they are parsed so parsing stops as soon as the limit is
+    /// exceeded. For sections opened with [`Parser::open_section()`](crate::Parser::open_section())
+    /// the limit also applies to the pages parsed on demand.
+    pub fn with_max_object_count(mut self, max_object_count: usize) -> ParseOptions {
+        self.max_object_count = Some(max_object_count);
+        self
+    }
+
+    /// Whether parsing fails on the first error.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Whether the binary data of images and embedded files is loaded.
+    pub fn load_payloads(&self) -> bool {
+        self.load_payloads
+    }
+
+    /// Whether a notebook's recycle bin is included as a section group.
+    pub fn include_recycle_bin(&self) -> bool {
+        self.include_recycle_bin
+    }
+
+    /// Whether the sections of section groups are parsed.
+    pub fn follow_section_groups(&self) -> bool {
+        self.follow_section_groups
+    }
+
+    /// The maximum size of a file in bytes.
+    pub fn max_file_size(&self) -> Option<usize> {
+        self.max_file_size
+    }
+
+    /// The maximum number of objects stored in a file.
+    pub fn max_object_count(&self) -> Option<usize> {
+        self.max_object_count
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,9 +1,11 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property::layout_alignment::LayoutAlignment;
+use crate::one::property::time::Time;
 use crate::one::property_set::{outline_element_node, outline_group, outline_node, PropertySetId};
 use crate::onenote::content::{parse_content, Content};
 use crate::onenote::list::{parse_list, List};
+use crate::onenote::printout::group_printouts;
 use crate::onestore::object_space::ObjectSpace;
 
 /// A content outline.
@@ -207,6 +209,9 @@ impl OutlineGroup {
 pub struct OutlineElement {
     pub(crate) contents: Vec<Content>,
 
+    pub(crate) created_at: Time,
+    pub(crate) last_modified: Time,
+
     pub(crate) list_contents: Vec<List>,
     pub(crate) list_spacing: Option<f32>,
 
@@ -254,6 +259,16 @@ impl OutlineElement {
     pub fn children(&self) -> &[OutlineItem] {
         &self.children
     }
+
+    /// When the outline element was created.
+    pub fn created_at(&self) -> Time {
+        self.created_at
+    }
+
+    /// When the outline element was last modified.
+    pub fn last_modified(&self) -> Time {
+        self.last_modified
+    }
 }
 
 pub(crate) fn parse_outline(outline_id: ExGuid, space: &ObjectSpace) -> Result<Outline> {
@@ -354,6 +369,7 @@ pub(crate) fn parse_outline_element(
         .into_iter()
         .map(|content_id| parse_content(content_id, space))
         .collect::<Result<_>>()?;
+    let contents = group_printouts(contents, space);
 
     let list_contents = data
         .list_contents
@@ -362,6 +378,8 @@ pub(crate) fn parse_outline_element(
         .collect::<Result<_>>()?;
 
     let element = OutlineElement {
+        created_at: data.created_at,
+        last_modified: data.last_modified,
         child_level: data.child_level,
         list_spacing: data.list_spacing,
         children,
@@ -1,10 +1,19 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property::layout_alignment::LayoutAlignment;
-use crate::one::property_set::{page_manifest_node, page_metadata, page_node, title_node};
+use crate::one::property::time::{Time, Timestamp};
+use crate::one::property_set::{
+    author_container, page_manifest_node, page_metadata, page_node, revision_metadata, title_node,
+};
+use crate::onenote::embedded_file::EmbeddedFile;
 use crate::onenote::outline::{parse_outline, Outline};
 use crate::onenote::page_content::{parse_page_content, PageContent};
+use crate::onenote::printout::{group_printouts, page_printouts, printout_file, Printout};
+use crate::onenote::recording::{page_recordings, Recording};
 use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::revision::Revision;
+use crate::render::svg;
+use crate::shared::guid::Guid;
 
 /// A page.
 ///
@@ -14,14 +23,27 @@ use crate::onestore::object_space::ObjectSpace;
 /// [\[MS-ONE\] 2.2.19]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e381b7c7-b434-43a2-ba23-0d08bafd281a
 #[derive(Clone, Debug)]
 pub struct Page {
-    title: Option<Title>,
+    id: Guid,
+    pub(crate) title: Option<Title>,
+    cached_title: String,
     level: i32,
+    created_at: Timestamp,
+    last_modified: Option<Time>,
+    pub(crate) is_deleted: bool,
     author: Option<String>,
     height: Option<f32>,
-    contents: Vec<PageContent>,
+    pub(crate) contents: Vec<PageContent>,
+    pub(crate) revisions: Vec<PageRevision>,
 }
 
 impl Page {
+    /// The page's unique ID.
+    ///
+    /// The ID stays the same when the page is modified or moved to another section.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
     /// The page's title element.
     ///
     /// See [\[MS-ONE\] 2.2.64].
@@ -40,6 +62,32 @@ impl Page {
         self.level
     }
 
+    /// The page title as cached by OneNote.
+    ///
+    /// Use [`Page::title_text()`] to get the title from the page's contents.
+    pub fn cached_title(&self) -> &str {
+        &self.cached_title
+    }
+
+    /// When the page was created.
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// When the page was last modified.
+    pub fn last_modified(&self) -> Option<Time> {
+        self.last_modified
+    }
+
+    /// Whether the page has been deleted.
+    ///
+    /// Deleted pages are kept in the notebook's recycle bin. All pages read from the
+    /// recycle bin using [`Parser::parse_recycle_bin()`](crate::Parser::parse_recycle_bin())
+    /// are marked as deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.is_deleted
+    }
+
     /// The page's author.
     pub fn author(&self) -> Option<&str> {
         self.author.as_deref()
@@ -59,6 +107,46 @@ impl Page {
         &self.contents
     }
 
+    /// The page's revision history, newest first.
+    ///
+    /// The first revision is the revision the page's current content belongs to. Use
+    /// [`LazySection::page_revision()`] to parse the page as of a revision.
+    ///
+    /// See [\[MS-ONESTORE\] 2.1.8].
+    ///
+    /// [\[MS-ONESTORE\] 2.1.8]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/a8ca2a90-d92a-4cf7-bf68-ed18ae476a11
+    /// [`LazySection::page_revision()`]: crate::section::LazySection::page_revision()
+    pub fn revisions(&self) -> &[PageRevision] {
+        &self.revisions
+    }
+
+    /// Render all ink placed on the page as a single SVG image.
+    ///
+    /// The image's origin is the page origin so it can be laid over the page's other
+    /// contents. Ink contained in outlines flows with the outline's text and isn't part of
+    /// the image. Returns `None` if the page has no ink.
+    pub fn ink_to_svg(&self) -> Option<String> {
+        svg::render_page(self)
+    }
+
+    /// The file printouts inserted into the page, including the ones inside outlines.
+    pub fn printouts(&self) -> Vec<&Printout> {
+        page_printouts(self)
+    }
+
+    /// The printed file of a printout if it's attached to the page as well.
+    pub fn printout_file(&self, printout: &Printout) -> Option<&EmbeddedFile> {
+        printout_file(self, printout)
+    }
+
+    /// The audio and video recordings on the page along with the notes created while
+    /// recording.
+    ///
+    /// Notes are matched to recordings by their creation time, see [`Recording`].
+    pub fn recordings(&self) -> Vec<Recording<'_>> {
+        page_recordings(self)
+    }
+
     /// The page's title text.
     ///
     /// This is calculated using a heuristic similar to the one OneNote uses.
@@ -87,6 +175,37 @@ impl Page {
     }
 }
 
+/// A revision of a page.
+///
+/// OneNote stores a page as a chain of revisions where each revision only contains the
+/// changes made to the page. The page as of a revision is resolved from the revision and
+/// all the revisions it depends on, see [`LazySection::page_revision()`].
+///
+/// [`LazySection::page_revision()`]: crate::section::LazySection::page_revision()
+#[derive(Clone, Debug)]
+pub struct PageRevision {
+    id: ExGuid,
+    author: Option<String>,
+    last_modified: Option<Timestamp>,
+}
+
+impl PageRevision {
+    /// The revision's ID.
+    pub fn id(&self) -> ExGuid {
+        self.id
+    }
+
+    /// The name of the author who created the revision (if recorded).
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// When the revision was created (if recorded).
+    pub fn last_modified(&self) -> Option<Timestamp> {
+        self.last_modified
+    }
+}
+
 /// A page title.
 ///
 /// See [\[MS-ONE\] 2.2.29].
@@ -146,6 +265,35 @@ impl Title {
 }
 
 pub(crate) fn parse_page(page_space: &ObjectSpace) -> Result<Page> {
+    let mut page = parse_page_content_as_of(page_space)?;
+    page.revisions = parse_revisions(page_space)?;
+
+    Ok(page)
+}
+
+/// Parse the page as of one of its revisions.
+///
+/// The returned page's revision history starts at that revision. Returns `None` if the
+/// page has no revision with the given ID.
+pub(crate) fn parse_page_revision(page_space: &ObjectSpace, id: ExGuid) -> Result<Option<Page>> {
+    let index = match page_space
+        .revisions()
+        .iter()
+        .position(|revision| revision.id() == id)
+    {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+
+    let space = page_space
+        .at_revision(index)
+        .ok_or_else(|| ErrorKind::MalformedOneNoteData("page revision is missing".into()))?;
+
+    parse_page(&space).map(Some)
+}
+
+/// Parse the page from an object space without looking at its revision history.
+fn parse_page_content_as_of(page_space: &ObjectSpace) -> Result<Page> {
     let metadata = parse_metadata(page_space)?;
     let manifest = parse_manifest(page_space)?;
 
@@ -162,16 +310,74 @@ pub(crate) fn parse_page(page_space: &ObjectSpace) -> Result<Page> {
         .into_iter()
         .map(|content_id| parse_page_content(content_id, page_space))
         .collect::<Result<_>>()?;
+    let contents = group_printouts(contents, page_space);
 
     Ok(Page {
+        id: metadata.entity_guid,
         title,
+        cached_title: metadata.cached_title,
         level,
+        created_at: metadata.created_at,
+        last_modified: data.last_modified,
+        is_deleted: metadata.is_deleted,
         author: data.author.map(|author| author.into_value()),
         height: data.page_height,
         contents,
+        revisions: vec![],
     })
 }
 
+fn parse_revisions(page_space: &ObjectSpace) -> Result<Vec<PageRevision>> {
+    page_space
+        .revisions()
+        .iter()
+        .enumerate()
+        .map(|(index, revision)| {
+            let space = page_space.at_revision(index).ok_or_else(|| {
+                ErrorKind::MalformedOneNoteData("page revision is missing".into())
+            })?;
+
+            let (author, last_modified) = parse_revision_metadata(revision, &space)?;
+
+            Ok(PageRevision {
+                id: revision.id(),
+                author,
+                last_modified,
+            })
+        })
+        .collect()
+}
+
+/// Parse the author and timestamp of a revision.
+///
+/// These are only available if the revision itself contains a revision metadata object.
+/// Revisions inheriting the metadata object from the revision they depend on don't record
+/// their own author and timestamp.
+fn parse_revision_metadata(
+    revision: &Revision,
+    space: &ObjectSpace,
+) -> Result<(Option<String>, Option<Timestamp>)> {
+    let metadata_object = match space
+        .version_metadata_root()
+        .and_then(|id| revision.objects().get(&id))
+    {
+        Some(object) => object,
+        None => return Ok((None, None)),
+    };
+
+    let metadata = revision_metadata::parse(metadata_object)?;
+
+    let author = metadata
+        .author_most_recent
+        .and_then(|id| space.get_object(id))
+        .map(author_container::parse)
+        .transpose()?
+        .and_then(|container| container.author)
+        .map(|author| author.into_value());
+
+    Ok((author, metadata.last_modified))
+}
+
 fn parse_title(title_id: ExGuid, space: &ObjectSpace) -> Result<Title> {
     let title_object = space
         .get_object(title_id)
@@ -222,3 +428,53 @@ fn parse_metadata(space: &ObjectSpace) -> Result<page_metadata::Data> {
 
     page_metadata::parse(metadata_object)
 }
+
+#[cfg(test)]
+mod test {
+    use crate::builder::{PageBuilder, PageSeriesBuilder, SectionBuilder};
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::onenote::page::{parse_page, parse_page_revision};
+    use crate::onestore::object_space::ObjectSpace;
+    use crate::onestore::revision::Revision;
+    use crate::onestore::{parse_store, StoreOptions};
+
+    fn section_data(title: &str) -> Vec<u8> {
+        SectionBuilder::new("Section")
+            .with_page_series(PageSeriesBuilder::new().with_page(PageBuilder::new(title)))
+            .to_bytes()
+            .unwrap()
+    }
+
+    fn page_revision<'a>(data: &'a [u8]) -> Revision<'a> {
+        let store = parse_store(data, StoreOptions::default()).unwrap();
+        store.load_object_spaces().unwrap();
+
+        let (_, space) = store.object_spaces().next().unwrap();
+
+        space.revisions()[0].clone()
+    }
+
+    #[test]
+    fn test_parse_revisions() {
+        let draft = section_data("Draft");
+        let published = section_data("Published");
+
+        let revisions = vec![page_revision(&published), page_revision(&draft)];
+        let space = ObjectSpace::new(ExGuid::nil(), ExGuid::nil(), revisions);
+        let page = parse_page(&space).unwrap();
+
+        let pages: Vec<_> = page
+            .revisions()
+            .iter()
+            .map(|revision| parse_page_revision(&space, revision.id()).unwrap().unwrap())
+            .collect();
+        let titles: Vec<_> = pages.iter().map(|page| page.title_text()).collect();
+
+        assert_eq!(page.title_text(), Some("Published"));
+        assert_eq!(titles, [Some("Published"), Some("Draft")]);
+        assert_eq!(pages[1].revisions().len(), 1);
+        assert!(parse_page_revision(&space, ExGuid::nil())
+            .unwrap()
+            .is_none());
+    }
+}
@@ -5,6 +5,8 @@ use crate::onenote::embedded_file::{parse_embedded_file, EmbeddedFile};
 use crate::onenote::image::{parse_image, Image};
 use crate::onenote::ink::{parse_ink, Ink};
 use crate::onenote::outline::{parse_outline, Outline};
+use crate::onenote::printout::Printout;
+use crate::onenote::unknown::{parse_unknown, UnknownContent};
 use crate::onestore::object_space::ObjectSpace;
 
 /// The contents of a page.
@@ -15,7 +17,8 @@ pub enum PageContent {
     Image(Image),
     EmbeddedFile(EmbeddedFile),
     Ink(Ink),
-    Unknown,
+    Printout(Printout),
+    Unknown(UnknownContent),
 }
 
 impl PageContent {
@@ -54,6 +57,24 @@ impl PageContent {
             None
         }
     }
+
+    /// Return the printout data if it's a file printout content.
+    pub fn printout(&self) -> Option<&Printout> {
+        if let PageContent::Printout(printout) = self {
+            Some(printout)
+        } else {
+            None
+        }
+    }
+
+    /// Return the unknown content if it's of a type that isn't supported by this crate.
+    pub fn unknown(&self) -> Option<&UnknownContent> {
+        if let PageContent::Unknown(content) = self {
+            Some(content)
+        } else {
+            None
+        }
+    }
 }
 
 pub(crate) fn parse_page_content(content_id: ExGuid, space: &ObjectSpace) -> Result<PageContent> {
@@ -61,20 +82,15 @@ pub(crate) fn parse_page_content(content_id: ExGuid, space: &ObjectSpace) -> Res
         .get_object(content_id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page content is missing".into()))?
         .id();
-    let id = PropertySetId::from_jcid(content_type).ok_or_else(|| {
-        ErrorKind::MalformedOneNoteData(
-            format!("invalid property set id: {:?}", content_type).into(),
-        )
-    })?;
 
-    let content = match id {
-        PropertySetId::ImageNode => PageContent::Image(parse_image(content_id, space)?),
-        PropertySetId::EmbeddedFileNode => {
+    let content = match PropertySetId::from_jcid(content_type) {
+        Some(PropertySetId::ImageNode) => PageContent::Image(parse_image(content_id, space)?),
+        Some(PropertySetId::EmbeddedFileNode) => {
             PageContent::EmbeddedFile(parse_embedded_file(content_id, space)?)
         }
-        PropertySetId::OutlineNode => PageContent::Outline(parse_outline(content_id, space)?),
-        PropertySetId::InkContainer => PageContent::Ink(parse_ink(content_id, space)?),
-        _ => PageContent::Unknown,
+        Some(PropertySetId::OutlineNode) => PageContent::Outline(parse_outline(content_id, space)?),
+        Some(PropertySetId::InkContainer) => PageContent::Ink(parse_ink(content_id, space)?),
+        _ => PageContent::Unknown(parse_unknown(content_id, space)?),
     };
 
     Ok(content)
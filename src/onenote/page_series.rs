@@ -1,6 +1,7 @@
-use crate::errors::{ErrorKind, Result};
+use crate::errors::{ErrorKind, Result, Warning};
 use crate::fsshttpb::data::exguid::ExGuid;
 use crate::one::property_set::page_series_node;
+use crate::onenote::options::ParseOptions;
 use crate::onenote::page::{parse_page, Page};
 use crate::onestore::OneStore;
 
@@ -12,7 +13,7 @@ use crate::onestore::OneStore;
 /// [\[MS-ONE\] 2.2.18]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e2957d3b-a2a8-4756-8662-4e67fefa9f4e
 #[derive(Clone, Debug)]
 pub struct PageSeries {
-    pages: Vec<Page>,
+    pub(crate) pages: Vec<Page>,
 }
 
 impl PageSeries {
@@ -22,23 +23,36 @@ impl PageSeries {
     }
 }
 
-pub(crate) fn parse_page_series(id: ExGuid, store: &OneStore) -> Result<PageSeries> {
+pub(crate) fn parse_page_series(
+    id: ExGuid,
+    store: &OneStore,
+    options: &ParseOptions,
+    warnings: &mut Vec<Warning>,
+) -> Result<PageSeries> {
     let object = store
         .data_root()
         .get_object(id)
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("page series object is missing".into()))?;
     let data = page_series_node::parse(object)?;
 
-    let pages = data
-        .page_spaces
-        .into_iter()
-        .map(|page_space_id| {
-            store
-                .object_space(page_space_id)
-                .ok_or_else(|| ErrorKind::MalformedOneNoteData("page space is missing".into()))
-        })
-        .map(|page_space| parse_page(page_space?))
-        .collect::<Result<_>>()?;
+    let mut pages = vec![];
+
+    for page_space_id in data.page_spaces {
+        let page = store
+            .object_space(page_space_id)
+            .and_then(|space| {
+                space.ok_or_else(|| {
+                    ErrorKind::MalformedOneNoteData("page space is missing".into()).into()
+                })
+            })
+            .and_then(parse_page);
+
+        match page {
+            Ok(page) => pages.push(page),
+            Err(err) if !options.is_strict() => warnings.push(Warning::new(store.file(), err)),
+            Err(err) => return Err(err),
+        }
+    }
 
     Ok(PageSeries { pages })
 }
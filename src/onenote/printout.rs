@@ -0,0 +1,336 @@
+use crate::onenote::content::Content;
+use crate::onenote::embedded_file::EmbeddedFile;
+use crate::onenote::image::Image;
+use crate::onenote::outline::{OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onestore::object_space::ObjectSpace;
+
+/// A file printout inserted into a page.
+///
+/// When inserting a printout of a file with multiple pages (e.g. a PDF or XPS document)
+/// OneNote adds an image for each of the file's pages. The printout groups these images
+/// back into a single document.
+#[derive(Clone, Debug)]
+pub struct Printout {
+    pub(crate) file_name: Option<String>,
+    pub(crate) pages: Vec<Image>,
+    pub(crate) xps_data: Option<Vec<u8>>,
+}
+
+impl Printout {
+    /// The name of the printed file.
+    ///
+    /// If the printed file is attached to the page as well it can be looked up using
+    /// [`Page::printout_file()`].
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// The images showing the printout's pages, in page order.
+    ///
+    /// The page number of each image is available from
+    /// [`Image::displayed_page_number()`].
+    pub fn pages(&self) -> &[Image] {
+        &self.pages
+    }
+
+    /// The printed document in XPS format (if present).
+    ///
+    /// The document is `None` if the file was parsed without loading payloads, see
+    /// [`ParseOptions::with_payloads()`](crate::ParseOptions::with_payloads).
+    pub fn xps_data(&self) -> Option<&[u8]> {
+        self.xps_data.as_deref()
+    }
+}
+
+/// Content that can hold printout images, i.e. page contents and outline contents.
+pub(crate) trait PrintoutContent: Sized {
+    fn into_printout_image(self) -> Result<Image, Self>;
+
+    fn printout_mut(&mut self) -> Option<&mut Printout>;
+
+    fn from_printout(printout: Printout) -> Self;
+}
+
+impl PrintoutContent for PageContent {
+    fn into_printout_image(self) -> Result<Image, Self> {
+        match self {
+            PageContent::Image(image) if image.displayed_page_number.is_some() => Ok(image),
+            content => Err(content),
+        }
+    }
+
+    fn printout_mut(&mut self) -> Option<&mut Printout> {
+        if let PageContent::Printout(printout) = self {
+            Some(printout)
+        } else {
+            None
+        }
+    }
+
+    fn from_printout(printout: Printout) -> Self {
+        PageContent::Printout(printout)
+    }
+}
+
+impl PrintoutContent for Content {
+    fn into_printout_image(self) -> Result<Image, Self> {
+        match self {
+            Content::Image(image) if image.displayed_page_number.is_some() => Ok(image),
+            content => Err(content),
+        }
+    }
+
+    fn printout_mut(&mut self) -> Option<&mut Printout> {
+        if let Content::Printout(printout) = self {
+            Some(printout)
+        } else {
+            None
+        }
+    }
+
+    fn from_printout(printout: Printout) -> Self {
+        Content::Printout(printout)
+    }
+}
+
+/// Group the printout images of a page or outline element into printouts.
+///
+/// Images belong to a printout if they have a page number. Consecutive images of the same
+/// file with increasing page numbers are part of the same printout. The printed XPS
+/// document is read once for each printout.
+pub(crate) fn group_printouts<T: PrintoutContent>(contents: Vec<T>, space: &ObjectSpace) -> Vec<T> {
+    let mut grouped: Vec<T> = vec![];
+
+    for content in contents {
+        let image = match content.into_printout_image() {
+            Ok(image) => image,
+            Err(content) => {
+                grouped.push(content);
+                continue;
+            }
+        };
+
+        if let Some(printout) = grouped.last_mut().and_then(T::printout_mut) {
+            let last_page_number = printout
+                .pages
+                .last()
+                .and_then(|image| image.displayed_page_number);
+
+            if printout.file_name == image.image_filename
+                && last_page_number < image.displayed_page_number
+            {
+                if printout.xps_data.is_none() {
+                    printout.xps_data = parse_xps_data(&image, space);
+                }
+
+                printout.pages.push(image);
+                continue;
+            }
+        }
+
+        grouped.push(T::from_printout(Printout {
+            file_name: image.image_filename.clone(),
+            xps_data: parse_xps_data(&image, space),
+            pages: vec![image],
+        }));
+    }
+
+    grouped
+}
+
+fn parse_xps_data(image: &Image, space: &ObjectSpace) -> Option<Vec<u8>> {
+    let container = space.get_object(image.xps_container?)?;
+
+    container.file_data().map(|data| data.to_vec())
+}
+
+/// Collect the printouts of a page, including the ones inside outlines.
+pub(crate) fn page_printouts(page: &Page) -> Vec<&Printout> {
+    let mut printouts = vec![];
+
+    for content in page.contents() {
+        match content {
+            PageContent::Printout(printout) => printouts.push(printout),
+            PageContent::Outline(outline) => walk_items(outline.items(), &mut |content| {
+                if let Content::Printout(printout) = content {
+                    printouts.push(printout);
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    printouts
+}
+
+/// Find the embedded file of a page that has been printed by the printout.
+pub(crate) fn printout_file<'a>(page: &'a Page, printout: &Printout) -> Option<&'a EmbeddedFile> {
+    let file_name = printout.file_name()?;
+    let mut files = vec![];
+
+    for content in page.contents() {
+        match content {
+            PageContent::EmbeddedFile(file) => files.push(file),
+            PageContent::Outline(outline) => walk_items(outline.items(), &mut |content| {
+                if let Content::EmbeddedFile(file) = content {
+                    files.push(file);
+                }
+            }),
+            _ => {}
+        }
+    }
+
+    files.into_iter().find(|file| file.filename() == file_name)
+}
+
+fn walk_items<'a>(items: &'a [OutlineItem], f: &mut impl FnMut(&'a Content)) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => walk_items(group.outlines(), f),
+            OutlineItem::Element(element) => walk_element(element, f),
+        }
+    }
+}
+
+fn walk_element<'a>(element: &'a OutlineElement, f: &mut impl FnMut(&'a Content)) {
+    for content in element.contents() {
+        if let Content::Table(table) = content {
+            let cells = table.contents().iter().flat_map(|row| row.contents());
+
+            for element in cells.flat_map(|cell| cell.contents()) {
+                walk_element(element, f);
+            }
+        }
+
+        f(content);
+    }
+
+    walk_items(element.children(), f);
+}
+
+#[cfg(test)]
+mod test {
+    use super::group_printouts;
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::one::property_set::PropertySetId;
+    use crate::onenote::content::Content;
+    use crate::onenote::image::{Image, ImageUploadState};
+    use crate::onenote::page_content::PageContent;
+    use crate::onestore::mapping_table::MappingTable;
+    use crate::onestore::object::Object;
+    use crate::onestore::object_space::ObjectSpace;
+    use crate::onestore::revision::Revision;
+    use crate::onestore::types::object_prop_set::ObjectPropSet;
+    use crate::onestore::types::prop_set::PropertySet;
+    use crate::shared::guid::Guid;
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    /// The ID of the XPS container referenced by the printout images.
+    fn xps_container() -> ExGuid {
+        ExGuid::from_guid(Guid::nil(), 1)
+    }
+
+    fn space() -> ObjectSpace<'static> {
+        let container = Object {
+            context_id: ExGuid::nil(),
+            jc_id: PropertySetId::XpsContainer.as_jcid(),
+            props: ObjectPropSet {
+                object_ids: vec![],
+                object_space_ids: vec![],
+                context_ids: vec![],
+                properties: PropertySet::from_values(vec![]),
+            },
+            file_data: Some(Cow::Borrowed(b"xps")),
+            mapping: MappingTable::from_entries(std::iter::empty(), std::iter::empty()),
+        };
+        let objects = HashMap::from([(xps_container(), container)]);
+        let revision = Revision::new(ExGuid::nil(), HashMap::new(), objects);
+
+        ObjectSpace::new(ExGuid::nil(), ExGuid::nil(), vec![revision])
+    }
+
+    fn image(file_name: &str, page_number: Option<u32>) -> PageContent {
+        PageContent::Image(image_data(file_name, page_number))
+    }
+
+    fn image_data(file_name: &str, page_number: Option<u32>) -> Image {
+        Image {
+            data: None,
+            extension: None,
+            upload_state: ImageUploadState::Uploaded,
+            xps_container: page_number.map(|_| xps_container()),
+            layout_max_width: None,
+            layout_max_height: None,
+            alt_text: None,
+            layout_alignment_in_parent: None,
+            layout_alignment_self: None,
+            image_filename: Some(file_name.to_string()),
+            displayed_page_number: page_number,
+            text: None,
+            text_language_code: None,
+            picture_width: None,
+            picture_height: None,
+            hyperlink_url: None,
+            offset_horizontal: None,
+            offset_vertical: None,
+            is_background: false,
+            note_tags: vec![],
+            embeds: vec![],
+        }
+    }
+
+    #[test]
+    fn test_group_printouts() {
+        let contents = group_printouts(
+            vec![
+                image("a.pdf", Some(1)),
+                image("a.pdf", Some(2)),
+                image("b.pdf", Some(1)),
+                image("b.pdf", Some(1)),
+                image("c.png", None),
+            ],
+            &space(),
+        );
+
+        let pages = |content: &PageContent| {
+            let printout = content.printout().unwrap();
+            let numbers: Vec<_> = printout
+                .pages()
+                .iter()
+                .map(|image| image.displayed_page_number().unwrap())
+                .collect();
+
+            (printout.file_name().unwrap().to_string(), numbers)
+        };
+
+        assert_eq!(contents.len(), 4);
+        assert_eq!(pages(&contents[0]), ("a.pdf".to_string(), vec![1, 2]));
+        assert_eq!(pages(&contents[1]), ("b.pdf".to_string(), vec![1]));
+        assert_eq!(pages(&contents[2]), ("b.pdf".to_string(), vec![1]));
+        assert!(contents[3].image().is_some());
+
+        assert_eq!(
+            contents[0].printout().unwrap().xps_data(),
+            Some(&b"xps"[..])
+        );
+    }
+
+    #[test]
+    fn test_group_outline_printouts() {
+        let contents = group_printouts(
+            vec![
+                Content::Image(image_data("a.pdf", Some(1))),
+                Content::Image(image_data("a.pdf", Some(2))),
+                Content::Image(image_data("c.png", None)),
+            ],
+            &space(),
+        );
+
+        assert_eq!(contents.len(), 2);
+        assert_eq!(contents[0].printout().unwrap().pages().len(), 2);
+        assert!(contents[1].image().is_some());
+    }
+}
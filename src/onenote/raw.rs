@@ -0,0 +1,429 @@
+//! Read-only access to the objects a OneNote file is made of.
+
+use crate::errors::Result;
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::PropertyType;
+use crate::one::property_set::PropertySetId;
+use crate::onestore::object::Object;
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::types::prop_set::PropertySet;
+use crate::onestore::types::property::PropertyValue;
+use crate::onestore::OneStore;
+use crate::shared::guid::Guid;
+use itertools::Itertools;
+use std::fmt;
+
+/// The object graph of a OneNote section or table of contents file.
+///
+/// The raw store gives access to the object spaces, objects and properties the file is
+/// made of without interpreting them. This is useful to inspect data that isn't exposed by
+/// the [`Section`](crate::section::Section) and [`Notebook`](crate::notebook::Notebook)
+/// APIs. Use [`Parser::open_raw()`](crate::Parser::open_raw()) to open a file.
+///
+/// Each object space shows the objects as of its newest revision.
+pub struct RawStore<'a> {
+    store: OneStore<'a>,
+}
+
+impl<'a> RawStore<'a> {
+    pub(crate) fn new(store: OneStore<'a>) -> Result<RawStore<'a>> {
+        store.load_object_spaces()?;
+
+        Ok(RawStore { store })
+    }
+
+    /// The file's unique ID.
+    pub fn file_identity(&self) -> Guid {
+        self.store.file_identity()
+    }
+
+    /// The ID of the file's schema.
+    ///
+    /// This tells whether the file is a section or a table of contents file.
+    pub fn schema(&self) -> Guid {
+        self.store.schema_guid()
+    }
+
+    /// The object space that holds the file's root objects.
+    ///
+    /// For sections this contains the section node and the page series.
+    pub fn data_root(&self) -> RawObjectSpace<'_> {
+        RawObjectSpace {
+            id: self.store.data_root_id(),
+            space: self.store.data_root(),
+        }
+    }
+
+    /// All object spaces except the data root, ordered by their IDs.
+    ///
+    /// For sections there is one object space per page.
+    pub fn object_spaces(&self) -> Vec<RawObjectSpace<'_>> {
+        self.store
+            .object_spaces()
+            .map(|(id, space)| RawObjectSpace { id, space })
+            .sorted_by_key(|space| space.sort_key())
+            .collect()
+    }
+
+    /// Look up a referenced object space.
+    pub fn object_space(&self, id: CellId) -> Option<RawObjectSpace<'_>> {
+        if id == self.store.data_root_id() {
+            return Some(self.data_root());
+        }
+
+        // All object spaces have been parsed when opening the store
+        self.store
+            .object_space(id)
+            .ok()
+            .flatten()
+            .map(|space| RawObjectSpace { id, space })
+    }
+}
+
+impl fmt::Debug for RawStore<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawStore")
+            .field("file_identity", &self.file_identity())
+            .field("schema", &self.schema())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A set of objects that is stored and versioned together.
+///
+/// See [\[MS-ONESTORE\] 2.1.4].
+///
+/// [\[MS-ONESTORE\] 2.1.4]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/1329433f-02a5-4e83-ab41-80d57ade38d9
+#[derive(Clone, Copy)]
+pub struct RawObjectSpace<'s> {
+    id: CellId,
+    space: &'s ObjectSpace<'s>,
+}
+
+impl<'s> RawObjectSpace<'s> {
+    /// The object space's ID.
+    ///
+    /// This is the ID other objects use to reference the object space.
+    pub fn id(&self) -> CellId {
+        self.id
+    }
+
+    /// The ID of the object space's content root object.
+    pub fn content_root(&self) -> Option<ExGuid> {
+        self.space.content_root()
+    }
+
+    /// The ID of the object space's metadata root object.
+    pub fn metadata_root(&self) -> Option<ExGuid> {
+        self.space.metadata_root()
+    }
+
+    /// All objects of the object space, ordered by their IDs.
+    pub fn objects(&self) -> Vec<RawObject<'s>> {
+        self.space
+            .objects()
+            .map(|(id, object)| RawObject { id, object })
+            .sorted_by_key(|object| (object.id.guid, object.id.value))
+            .collect()
+    }
+
+    /// Look up a referenced object.
+    pub fn object(&self, id: ExGuid) -> Option<RawObject<'s>> {
+        self.space
+            .get_object(id)
+            .map(|object| RawObject { id, object })
+    }
+
+    fn sort_key(&self) -> (Guid, u32, Guid, u32) {
+        let CellId(context, id) = self.id;
+
+        (context.guid, context.value, id.guid, id.value)
+    }
+}
+
+impl fmt::Debug for RawObjectSpace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawObjectSpace")
+            .field("id", &self.id)
+            .field("content_root", &self.content_root())
+            .field("metadata_root", &self.metadata_root())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object and its properties.
+///
+/// See [\[MS-ONESTORE\] 2.1.5].
+///
+/// [\[MS-ONESTORE\] 2.1.5]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/ce60b62f-82e5-401a-bf2c-3255457732ad
+#[derive(Clone, Copy)]
+pub struct RawObject<'s> {
+    id: ExGuid,
+    object: &'s Object<'s>,
+}
+
+impl<'s> RawObject<'s> {
+    pub(crate) fn new(id: ExGuid, object: &'s Object<'s>) -> RawObject<'s> {
+        RawObject { id, object }
+    }
+
+    /// The object's ID.
+    pub fn id(&self) -> ExGuid {
+        self.id
+    }
+
+    /// The object's type (JCID).
+    ///
+    /// See [\[MS-ONESTORE\] 2.6.14].
+    ///
+    /// [\[MS-ONESTORE\] 2.6.14]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/388c266c-08e4-4ea4-af0e-5e2c5d1b995c
+    pub fn jc_id(&self) -> u32 {
+        self.object.id().0
+    }
+
+    /// The name of the object's type if it's known to this crate (e.g. `PageNode`).
+    pub fn type_name(&self) -> Option<String> {
+        PropertySetId::from_jcid(self.object.id()).map(|id| format!("{:?}", id))
+    }
+
+    /// The object's file data.
+    ///
+    /// This is only present for objects that hold the contents of images and embedded files.
+    pub fn file_data(&self) -> Option<&'s [u8]> {
+        self.object.file_data.as_deref()
+    }
+
+    /// The object's properties in the order they are stored in.
+    ///
+    /// References to other objects and object spaces are resolved to their IDs.
+    pub fn properties(&self) -> Vec<RawProperty> {
+        convert_properties(
+            self.object.props().properties(),
+            &mut References::new(Some(self.object)),
+        )
+    }
+
+    /// Look up a property by its ID.
+    ///
+    /// The ID's type bits are ignored, so both `0x1C001C0A` and `0x1C0A` find the `Font`
+    /// property.
+    pub fn property(&self, id: u32) -> Option<RawProperty> {
+        self.properties()
+            .into_iter()
+            .find(|prop| prop.id() & 0x3ffffff == id & 0x3ffffff)
+    }
+}
+
+impl fmt::Debug for RawObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawObject")
+            .field("id", &self.id)
+            .field("jc_id", &format_args!("0x{:08X}", self.jc_id()))
+            .field("type_name", &self.type_name())
+            .field("properties", &self.properties())
+            .finish()
+    }
+}
+
+/// An object property.
+///
+/// See [\[MS-ONESTORE\] 2.6.6].
+///
+/// [\[MS-ONESTORE\] 2.6.6]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/17d8c39e-6cc2-4fcd-8d10-aee950fd0ab2
+#[derive(Clone, Debug)]
+pub struct RawProperty {
+    id: u32,
+    value: RawValue,
+}
+
+impl RawProperty {
+    /// The property's ID including its type bits (e.g. `0x1C001C0A`).
+    ///
+    /// This is the property ID as listed in [\[MS-ONE\] 2.1.12].
+    ///
+    /// [\[MS-ONE\] 2.1.12]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/e9bf7da8-7aab-4668-be5e-e0c421175e3c
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The name of the property if it's known to this crate (e.g. `Font`).
+    pub fn name(&self) -> Option<String> {
+        PropertyType::from_id(self.id).map(|prop_type| format!("{:?}", prop_type))
+    }
+
+    /// The property's value.
+    pub fn value(&self) -> &RawValue {
+        &self.value
+    }
+}
+
+/// The value of an object property.
+#[derive(Clone, Debug)]
+pub enum RawValue {
+    /// A property without a value.
+    Empty,
+
+    /// A boolean value.
+    Bool(bool),
+
+    /// An 8-bit value.
+    U8(u8),
+
+    /// A 16-bit value.
+    U16(u16),
+
+    /// A 32-bit value.
+    U32(u32),
+
+    /// A 64-bit value.
+    U64(u64),
+
+    /// Binary data such as strings or arrays of numbers.
+    Bytes(Vec<u8>),
+
+    /// A reference to an object in the same object space.
+    ///
+    /// The reference is `None` if it can't be resolved.
+    ObjectId(Option<ExGuid>),
+
+    /// References to objects in the same object space.
+    ///
+    /// References that can't be resolved are left out.
+    ObjectIds(Vec<ExGuid>),
+
+    /// A reference to another object space.
+    ObjectSpaceId(Option<CellId>),
+
+    /// References to other object spaces.
+    ObjectSpaceIds(Vec<CellId>),
+
+    /// A reference to a context.
+    ContextId(Option<ExGuid>),
+
+    /// References to contexts.
+    ContextIds(Vec<ExGuid>),
+
+    /// An array of property sets along with the ID of the property the array holds.
+    PropertyValues(u32, Vec<Vec<RawProperty>>),
+
+    /// A nested property set.
+    ///
+    /// References in nested property sets aren't resolved.
+    PropertySet(Vec<RawProperty>),
+}
+
+/// The object's references that haven't been assigned to a property yet.
+///
+/// Properties use an object's references in the order the properties are stored in.
+struct References<'s> {
+    object: Option<&'s Object<'s>>,
+    objects: usize,
+    object_spaces: usize,
+    contexts: usize,
+}
+
+impl<'s> References<'s> {
+    fn new(object: Option<&'s Object<'s>>) -> References<'s> {
+        References {
+            object,
+            objects: 0,
+            object_spaces: 0,
+            contexts: 0,
+        }
+    }
+
+    fn next_objects(&mut self, count: usize) -> Vec<ExGuid> {
+        let start = self.objects;
+        self.objects += count;
+
+        let object = match self.object {
+            Some(object) => object,
+            None => return vec![],
+        };
+        let ids = object.props().object_ids();
+
+        (start..self.objects)
+            .filter_map(|index| object.mapping().get_object(index, *ids.get(index)?))
+            .collect()
+    }
+
+    fn next_object_spaces(&mut self, count: usize) -> Vec<CellId> {
+        let start = self.object_spaces;
+        self.object_spaces += count;
+
+        let object = match self.object {
+            Some(object) => object,
+            None => return vec![],
+        };
+        let ids = object.props().object_space_ids();
+
+        (start..self.object_spaces)
+            .filter_map(|index| object.mapping().get_object_space(index, *ids.get(index)?))
+            .collect()
+    }
+
+    fn next_contexts(&mut self, count: usize) -> Vec<ExGuid> {
+        let start = self.contexts;
+        self.contexts += count;
+
+        let object = match self.object {
+            Some(object) => object,
+            None => return vec![],
+        };
+        let ids = object.props().context_ids();
+
+        // Contexts are stored in the mapping table after the object references
+        let offset = object.props().object_ids().len();
+
+        (start..self.contexts)
+            .filter_map(|index| {
+                object
+                    .mapping()
+                    .get_object(offset + index, *ids.get(index)?)
+            })
+            .collect()
+    }
+}
+
+fn convert_properties(props: &PropertySet, refs: &mut References<'_>) -> Vec<RawProperty> {
+    props
+        .iter()
+        .map(|(id, value)| RawProperty {
+            id: id | value.prop_type() << 26,
+            value: convert_value(value, refs),
+        })
+        .collect()
+}
+
+fn convert_value(value: &PropertyValue, refs: &mut References<'_>) -> RawValue {
+    match value {
+        PropertyValue::Empty => RawValue::Empty,
+        PropertyValue::Bool(value) => RawValue::Bool(*value),
+        PropertyValue::U8(value) => RawValue::U8(*value),
+        PropertyValue::U16(value) => RawValue::U16(*value),
+        PropertyValue::U32(value) => RawValue::U32(*value),
+        PropertyValue::U64(value) => RawValue::U64(*value),
+        PropertyValue::Vec(data) => RawValue::Bytes(data.clone()),
+        PropertyValue::ObjectId => RawValue::ObjectId(refs.next_objects(1).pop()),
+        PropertyValue::ObjectIds(count) => RawValue::ObjectIds(refs.next_objects(*count as usize)),
+        PropertyValue::ObjectSpaceId => RawValue::ObjectSpaceId(refs.next_object_spaces(1).pop()),
+        PropertyValue::ObjectSpaceIds(count) => {
+            RawValue::ObjectSpaceIds(refs.next_object_spaces(*count as usize))
+        }
+        PropertyValue::ContextId => RawValue::ContextId(refs.next_contexts(1).pop()),
+        PropertyValue::ContextIds(count) => {
+            RawValue::ContextIds(refs.next_contexts(*count as usize))
+        }
+        PropertyValue::PropertyValues(id, sets) => RawValue::PropertyValues(
+            id.value() & 0x7fffffff,
+            sets.iter()
+                .map(|set| convert_properties(set, refs))
+                .collect(),
+        ),
+        PropertyValue::PropertySet(set) => {
+            // Nested property sets don't use the object's references
+            RawValue::PropertySet(convert_properties(set, &mut References::new(None)))
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use crate::one::property::file_type::FileType;
+use crate::one::property::time::Timestamp;
+use crate::onenote::content::Content;
+use crate::onenote::embedded_file::EmbeddedFile;
+use crate::onenote::outline::{OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::reader::Reader;
+use crate::shared::guid::Guid;
+use std::time::{Duration, SystemTime};
+
+/// The number of seconds between January 1, 1601 (UTC) and January 1, 1904 (UTC).
+const MP4_FILETIME_OFFSET: u64 = 9_561_628_800;
+
+/// The length and start time of an audio or video recording.
+///
+/// The section file's recording duration property isn't documented, so this is read from
+/// the header of the recorded media file instead. Recordings in the ASF (`.wma`, `.wmv`)
+/// and MP4 (`.m4a`, `.mp4`) formats are supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
+pub struct RecordingInfo {
+    started_at: Option<Timestamp>,
+    duration: Duration,
+}
+
+impl RecordingInfo {
+    /// When the recording was started (if known).
+    pub fn started_at(&self) -> Option<Timestamp> {
+        self.started_at
+    }
+
+    /// The recording's length.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// An audio or video recording on a page along with the notes created while it was
+/// recording.
+///
+/// OneNote links notes to the recording so they can be used to play back the recording
+/// from the point the note was taken. As these links aren't documented, notes are
+/// matched to the recording by comparing their creation time to the recording's start
+/// time and length as stored in the media file's header. This is a heuristic: notes
+/// created on another device or edited into the page later may be matched as well.
+#[derive(Clone, Debug)]
+pub struct Recording<'a> {
+    file: &'a EmbeddedFile,
+    info: RecordingInfo,
+    notes: Vec<TimedNote<'a>>,
+}
+
+impl<'a> Recording<'a> {
+    /// The recorded media file.
+    pub fn file(&self) -> &'a EmbeddedFile {
+        self.file
+    }
+
+    /// The recording's length and start time.
+    pub fn info(&self) -> RecordingInfo {
+        self.info
+    }
+
+    /// The notes whose creation time falls within the recording, ordered by their offset
+    /// into the recording.
+    ///
+    /// This is empty if the recording's start time isn't known.
+    pub fn notes_created_during_recording(&self) -> &[TimedNote<'a>] {
+        &self.notes
+    }
+}
+
+/// A note created while recording.
+#[derive(Clone, Copy, Debug)]
+pub struct TimedNote<'a> {
+    offset: Duration,
+    element: &'a OutlineElement,
+}
+
+impl<'a> TimedNote<'a> {
+    /// The time into the recording at which the note was created.
+    ///
+    /// The offset has a precision of one second.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// The outline element containing the note.
+    pub fn element(&self) -> &'a OutlineElement {
+        self.element
+    }
+}
+
+/// Find the recordings on a page and the notes created while recording.
+pub(crate) fn page_recordings(page: &Page) -> Vec<Recording<'_>> {
+    let mut files = vec![];
+    let mut elements = vec![];
+
+    for content in page.contents() {
+        match content {
+            PageContent::Outline(outline) => {
+                collect_items(outline.items(), &mut files, &mut elements)
+            }
+            PageContent::EmbeddedFile(file) => files.push(file),
+            _ => {}
+        }
+    }
+
+    files
+        .into_iter()
+        .filter_map(|file| {
+            let info = file.recording_info()?;
+            let notes = info
+                .started_at
+                .map(|started_at| {
+                    notes_created_during_recording(&elements, started_at.into(), info.duration)
+                })
+                .unwrap_or_default();
+
+            Some(Recording { file, info, notes })
+        })
+        .collect()
+}
+
+fn notes_created_during_recording<'a>(
+    elements: &[&'a OutlineElement],
+    started_at: SystemTime,
+    duration: Duration,
+) -> Vec<TimedNote<'a>> {
+    // Element times are stored with a precision of one second
+    let subsec = started_at
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|time| Duration::from_nanos(time.subsec_nanos() as u64))
+        .unwrap_or_default();
+    let start = started_at - subsec;
+    let Some(end) = started_at.checked_add(duration) else {
+        return vec![];
+    };
+
+    let mut notes: Vec<_> = elements
+        .iter()
+        .filter(|element| {
+            element
+                .contents()
+                .iter()
+                .any(|content| !matches!(content, Content::EmbeddedFile(_)))
+        })
+        .filter_map(|element| {
+            let created_at = element.created_at().to_system_time();
+            if created_at > end {
+                return None;
+            }
+
+            let offset = created_at.duration_since(start).ok()?;
+
+            Some(TimedNote { offset, element })
+        })
+        .collect();
+
+    notes.sort_by_key(|note| note.offset);
+
+    notes
+}
+
+fn collect_items<'a>(
+    items: &'a [OutlineItem],
+    files: &mut Vec<&'a EmbeddedFile>,
+    elements: &mut Vec<&'a OutlineElement>,
+) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => collect_items(group.outlines(), files, elements),
+            OutlineItem::Element(element) => collect_element(element, files, elements),
+        }
+    }
+}
+
+fn collect_element<'a>(
+    element: &'a OutlineElement,
+    files: &mut Vec<&'a EmbeddedFile>,
+    elements: &mut Vec<&'a OutlineElement>,
+) {
+    elements.push(element);
+
+    for content in element.contents() {
+        match content {
+            Content::EmbeddedFile(file) => files.push(file),
+            Content::Table(table) => {
+                let cells = table.contents().iter().flat_map(|row| row.contents());
+
+                for element in cells.flat_map(|cell| cell.contents()) {
+                    collect_element(element, files, elements);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    collect_items(element.children(), files, elements);
+}
+
+/// Read the length and start time of a recording from the media file's header.
+pub(crate) fn parse_recording_info(file_type: FileType, data: &[u8]) -> Option<RecordingInfo> {
+    match file_type {
+        FileType::Audio | FileType::Video => parse_asf(data).or_else(|| parse_mp4(data)),
+        FileType::Unknown => None,
+    }
+}
+
+/// Parse the file properties of an ASF file.
+///
+/// See the Advanced Systems Format specification sections 3.1 and 3.2.
+fn parse_asf(data: &[u8]) -> Option<RecordingInfo> {
+    let mut reader = Reader::new(data);
+
+    let header_id = Guid::from_str("75B22630-668E-11CF-A6D9-00AA0062CE6C").ok()?;
+    let file_properties_id = Guid::from_str("8CABDCA1-A947-11CF-8EE4-00C00C205365").ok()?;
+
+    if Guid::parse(&mut reader).ok()? != header_id {
+        return None;
+    }
+
+    let size = reader.get_u64().ok()?;
+    let header = reader.read((size as usize).checked_sub(24)?).ok()?;
+
+    // Skip the header object count and the reserved bytes
+    let mut reader = Reader::new(header.get(6..)?);
+
+    while reader.remaining() > 0 {
+        let id = Guid::parse(&mut reader).ok()?;
+        let size = reader.get_u64().ok()?;
+        let data = reader.read((size as usize).checked_sub(24)?).ok()?;
+
+        if id == file_properties_id {
+            return parse_asf_file_properties(data);
+        }
+    }
+
+    None
+}
+
+fn parse_asf_file_properties(data: &[u8]) -> Option<RecordingInfo> {
+    let mut reader = Reader::new(data);
+
+    // Skip the file ID and size
+    reader.advance(24).ok()?;
+    let creation_date = reader.get_u64().ok()?;
+    reader.advance(8).ok()?;
+    let play_duration = reader.get_u64().ok()?;
+    reader.advance(8).ok()?;
+    let preroll = reader.get_u64().ok()?;
+    let flags = reader.get_u32().ok()?;
+
+    // The duration and creation date aren't set for broadcasts
+    if flags & 0x1 != 0 {
+        return None;
+    }
+
+    let play_duration = Duration::new(
+        play_duration / 10_000_000,
+        (play_duration % 10_000_000) as u32 * 100,
+    );
+    let duration = play_duration
+        .checked_sub(Duration::from_millis(preroll))
+        .unwrap_or_default();
+
+    let started_at = Some(creation_date)
+        .filter(|date| *date != 0)
+        .map(Timestamp::from_filetime);
+
+    Some(RecordingInfo {
+        started_at,
+        duration,
+    })
+}
+
+/// Parse the movie header of an MP4 file.
+///
+/// See ISO/IEC 14496-12 section 8.2.2.
+fn parse_mp4(data: &[u8]) -> Option<RecordingInfo> {
+    if data.get(4..8)? != b"ftyp" {
+        return None;
+    }
+
+    let movie = find_mp4_box(data, b"moov")?;
+    let header = find_mp4_box(movie, b"mvhd")?;
+
+    // Skip the version and flags
+    let fields = header.get(4..)?;
+    let (creation_time, timescale, duration) = match header.first()? {
+        1 => (
+            read_u64_be(fields, 0)?,
+            read_u32_be(fields, 16)?,
+            read_u64_be(fields, 20)?,
+        ),
+        _ => (
+            read_u32_be(fields, 0)? as u64,
+            read_u32_be(fields, 8)?,
+            read_u32_be(fields, 12)? as u64,
+        ),
+    };
+
+    if timescale == 0 {
+        return None;
+    }
+
+    let timescale = timescale as u64;
+    let duration = Duration::new(
+        duration / timescale,
+        ((duration % timescale) * 1_000_000_000 / timescale) as u32,
+    );
+
+    let started_at = Some(creation_time)
+        .filter(|time| *time != 0)
+        .and_then(|time| time.checked_add(MP4_FILETIME_OFFSET))
+        .and_then(|time| time.checked_mul(10_000_000))
+        .map(Timestamp::from_filetime);
+
+    Some(RecordingInfo {
+        started_at,
+        duration,
+    })
+}
+
+/// Find a box in a sequence of MP4 boxes and return its content.
+fn find_mp4_box<'a>(mut data: &'a [u8], name: &[u8; 4]) -> Option<&'a [u8]> {
+    while data.len() >= 8 {
+        let (header_size, size) = match read_u32_be(data, 0)? {
+            0 => (8, data.len()),
+            1 => (16, read_u64_be(data, 8)? as usize),
+            size => (8, size as usize),
+        };
+
+        let content = data.get(header_size..size)?;
+        if &data[4..8] == name {
+            return Some(content);
+        }
+
+        data = &data[size..];
+    }
+
+    None
+}
+
+fn read_u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64_be(data: &[u8], offset: usize) -> Option<u64> {
+    let high = read_u32_be(data, offset)? as u64;
+    let low = read_u32_be(data, offset + 4)? as u64;
+
+    Some(high << 32 | low)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{notes_created_during_recording, parse_recording_info};
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::one::property::file_type::FileType;
+    use crate::one::property::time::Time;
+    use crate::onenote::content::Content;
+    use crate::onenote::outline::OutlineElement;
+    use crate::onenote::unknown::UnknownContent;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn element(created_at_secs: u64) -> OutlineElement {
+        let created_at = Time::from_system_time(UNIX_EPOCH + Duration::from_secs(created_at_secs));
+        let content = UnknownContent {
+            id: ExGuid::nil(),
+            jc_id: 0,
+            text: vec![],
+            properties: vec![],
+        };
+
+        OutlineElement {
+            contents: vec![Content::Unknown(content)],
+            created_at,
+            last_modified: created_at,
+            list_contents: vec![],
+            list_spacing: None,
+            child_level: 0,
+            children: vec![],
+        }
+    }
+
+    #[test]
+    fn test_notes_created_during_recording() {
+        let start = 1_577_836_800;
+        let elements = [
+            element(start + 30),
+            element(start - 10),
+            element(start + 5),
+            element(start + 120),
+        ];
+        let elements: Vec<_> = elements.iter().collect();
+
+        let started_at = UNIX_EPOCH + Duration::from_millis(start * 1000 + 500);
+        let notes = notes_created_during_recording(&elements, started_at, Duration::from_secs(60));
+
+        let offsets: Vec<_> = notes.iter().map(|note| note.offset().as_secs()).collect();
+        assert_eq!(offsets, [5, 30]);
+    }
+
+    #[test]
+    fn test_notes_created_during_recording_overflow() {
+        let elements = [element(1_577_836_800)];
+        let elements: Vec<_> = elements.iter().collect();
+
+        let started_at = UNIX_EPOCH + Duration::from_secs(1_577_836_800);
+        let notes = notes_created_during_recording(&elements, started_at, Duration::MAX);
+
+        assert!(notes.is_empty());
+    }
+
+    #[test]
+    fn test_parse_asf() {
+        let mut properties = vec![];
+        properties.extend_from_slice(&[0; 24]);
+        properties.extend_from_slice(&132_223_104_000_000_000u64.to_le_bytes());
+        properties.extend_from_slice(&[0; 8]);
+        properties.extend_from_slice(&(95 * 10_000_000u64).to_le_bytes());
+        properties.extend_from_slice(&[0; 8]);
+        properties.extend_from_slice(&5_000u64.to_le_bytes());
+        properties.extend_from_slice(&2u32.to_le_bytes());
+
+        let mut header = vec![];
+        header.extend_from_slice(&[1, 0, 0, 0, 1, 2]);
+        header.extend_from_slice(&[
+            0xA1, 0xDC, 0xAB, 0x8C, 0x47, 0xA9, 0xCF, 0x11, 0x8E, 0xE4, 0x00, 0xC0, 0x0C, 0x20,
+            0x53, 0x65,
+        ]);
+        header.extend_from_slice(&(properties.len() as u64 + 24).to_le_bytes());
+        header.extend_from_slice(&properties);
+
+        let mut data = vec![
+            0x30, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62,
+            0xCE, 0x6C,
+        ];
+        data.extend_from_slice(&(header.len() as u64 + 24).to_le_bytes());
+        data.extend_from_slice(&header);
+
+        let info = parse_recording_info(FileType::Audio, &data).unwrap();
+        assert_eq!(info.duration(), Duration::from_secs(90));
+        assert_eq!(
+            info.started_at().unwrap().to_system_time(),
+            UNIX_EPOCH + Duration::from_secs(1_577_836_800)
+        );
+
+        assert_eq!(parse_recording_info(FileType::Unknown, &data), None);
+    }
+
+    #[test]
+    fn test_parse_mp4() {
+        let mut header = vec![0; 4];
+        header.extend_from_slice(&3_660_681_600u32.to_be_bytes());
+        header.extend_from_slice(&[0; 4]);
+        header.extend_from_slice(&1_000u32.to_be_bytes());
+        header.extend_from_slice(&12_500u32.to_be_bytes());
+
+        let mut movie = vec![];
+        movie.extend_from_slice(&(header.len() as u32 + 8).to_be_bytes());
+        movie.extend_from_slice(b"mvhd");
+        movie.extend_from_slice(&header);
+
+        let mut data = vec![0, 0, 0, 8];
+        data.extend_from_slice(b"ftyp");
+        data.extend_from_slice(&(movie.len() as u32 + 8).to_be_bytes());
+        data.extend_from_slice(b"moov");
+        data.extend_from_slice(&movie);
+
+        let info = parse_recording_info(FileType::Video, &data).unwrap();
+        assert_eq!(info.duration(), Duration::from_millis(12_500));
+        assert_eq!(
+            info.started_at().unwrap().to_system_time(),
+            UNIX_EPOCH + Duration::from_secs(1_577_836_800)
+        );
+    }
+}
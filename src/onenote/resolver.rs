@@ -0,0 +1,26 @@
+use crate::errors::Result;
+
+/// Resolves the section files and section groups of a notebook.
+///
+/// A notebook's table of contents only lists the names of the notebook's sections and
+/// section groups. On disk these are the section files and the sub-directories next to the
+/// table of contents file. A resolver provides access to them from any other storage, e.g.
+/// an archive or an object store.
+pub trait NotebookResolver {
+    /// Resolve an entry of a notebook's table of contents.
+    ///
+    /// The `path` contains the names of the section groups leading to the entry followed by
+    /// the entry's name, starting at the notebook's root. Returns `None` if the entry
+    /// doesn't exist.
+    fn resolve(&mut self, path: &[String]) -> Result<Option<ResolvedEntry>>;
+}
+
+/// The data of a resolved notebook entry.
+#[derive(Clone, Debug)]
+pub enum ResolvedEntry {
+    /// The contents of a section file (`.one`).
+    Section(Vec<u8>),
+
+    /// The contents of a section group's table of contents file (`.onetoc2`).
+    SectionGroup(Vec<u8>),
+}
@@ -9,6 +9,7 @@ use crate::onenote::ink::{parse_ink_data, Ink, InkBoundingBox};
 use crate::onenote::note_tag::{parse_note_tags, NoteTag};
 use crate::onestore::object_space::ObjectSpace;
 use itertools::Itertools;
+use std::ops::Range;
 
 /// A rich text paragraph.
 ///
@@ -25,6 +26,13 @@ use itertools::Itertools;
 /// Text runs can be rendered by splitting the paragraph text at the text run
 /// indices and then applying each text run formatting to its respective
 /// substring.
+///
+/// # Hyperlinks
+///
+/// OneNote stores the target of a hyperlink as a hidden field code in front of
+/// the link's display text. The field codes are removed from the paragraph text
+/// and the text runs while parsing, the link targets are available from
+/// [`RichText::links()`].
 #[derive(Clone, Debug)]
 pub struct RichText {
     pub(crate) text: String,
@@ -43,6 +51,7 @@ pub struct RichText {
 
     pub(crate) note_tags: Vec<NoteTag>,
     pub(crate) embedded_objects: Vec<EmbeddedObject>,
+    pub(crate) links: Vec<Link>,
 }
 
 impl RichText {
@@ -137,6 +146,34 @@ impl RichText {
     pub fn embedded_objects(&self) -> &[EmbeddedObject] {
         &self.embedded_objects
     }
+
+    /// The hyperlinks in this paragraph.
+    pub fn links(&self) -> &[Link] {
+        &self.links
+    }
+}
+
+/// A hyperlink in a rich text paragraph.
+#[derive(Clone, Debug)]
+pub struct Link {
+    pub(crate) display_range: Range<u32>,
+    pub(crate) url: String,
+}
+
+impl Link {
+    /// The range of the paragraph text that is displayed for the link.
+    ///
+    /// Like the text run indices the range is given in UTF-16 code units. The display
+    /// text always consists of whole text runs. It is empty if no text is marked as
+    /// the link's display text.
+    pub fn display_range(&self) -> Range<u32> {
+        self.display_range.clone()
+    }
+
+    /// The link's target URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
 }
 
 /// An object embedded in a rich text paragraph.
@@ -165,10 +202,19 @@ impl EmbeddedInkContainer {
         &self.ink
     }
 
-    /// The ink object's bounding box.
+    /// The area the ink is displayed in within the paragraph.
+    ///
+    /// Unlike [`Ink::bounding_box()`] the unit of this bounding box isn't known.
     pub fn bounding_box(&self) -> Option<&InkBoundingBox> {
         self.bounding_box.as_ref()
     }
+
+    /// Render the embedded ink as a standalone SVG image.
+    ///
+    /// See [`Ink::to_svg()`].
+    pub fn to_svg(&self) -> String {
+        self.ink.to_svg()
+    }
 }
 
 /// A space in an embedded ink handwriting object.
@@ -358,10 +404,152 @@ impl ParagraphStyling {
     }
 }
 
+impl ParagraphStyling {
+    /// Create an empty styling that uses the default formatting.
+    ///
+    /// Use the `with_*` methods to set the formatting when building rich text (see
+    /// [`RichTextBuilder`](crate::builder::RichTextBuilder)).
+    pub fn new() -> ParagraphStyling {
+        ParagraphStyling {
+            charset: None,
+            bold: false,
+            italic: false,
+            underline: false,
+            strikethrough: false,
+            superscript: false,
+            subscript: false,
+            font: None,
+            font_size: None,
+            font_color: None,
+            highlight: None,
+            next_style: None,
+            style_id: None,
+            paragraph_alignment: None,
+            paragraph_space_before: None,
+            paragraph_space_after: None,
+            paragraph_line_spacing_exact: None,
+            language_code: None,
+            math_formatting: false,
+            hyperlink: false,
+        }
+    }
+
+    /// Set the text's charset.
+    pub fn with_charset(mut self, charset: Charset) -> ParagraphStyling {
+        self.charset = Some(charset);
+        self
+    }
+
+    /// Set whether the text is bold.
+    pub fn with_bold(mut self, bold: bool) -> ParagraphStyling {
+        self.bold = bold;
+        self
+    }
+
+    /// Set whether the text is italic.
+    pub fn with_italic(mut self, italic: bool) -> ParagraphStyling {
+        self.italic = italic;
+        self
+    }
+
+    /// Set whether the text is underlined.
+    pub fn with_underline(mut self, underline: bool) -> ParagraphStyling {
+        self.underline = underline;
+        self
+    }
+
+    /// Set whether the text has strike-through formatting.
+    pub fn with_strikethrough(mut self, strikethrough: bool) -> ParagraphStyling {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Set whether the text is formatted as superscript.
+    pub fn with_superscript(mut self, superscript: bool) -> ParagraphStyling {
+        self.superscript = superscript;
+        self
+    }
+
+    /// Set whether the text is formatted as subscript.
+    pub fn with_subscript(mut self, subscript: bool) -> ParagraphStyling {
+        self.subscript = subscript;
+        self
+    }
+
+    /// Set the font for this text.
+    pub fn with_font(mut self, font: &str) -> ParagraphStyling {
+        self.font = Some(font.to_string());
+        self
+    }
+
+    /// Set the font size for this text in half-point increments.
+    pub fn with_font_size(mut self, font_size: u16) -> ParagraphStyling {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Set the font color for this text.
+    pub fn with_font_color(mut self, font_color: ColorRef) -> ParagraphStyling {
+        self.font_color = Some(font_color);
+        self
+    }
+
+    /// Set the background color for this text.
+    pub fn with_highlight(mut self, highlight: ColorRef) -> ParagraphStyling {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Set the paragraph style's name.
+    pub fn with_style_id(mut self, style_id: &str) -> ParagraphStyling {
+        self.style_id = Some(style_id.to_string());
+        self
+    }
+
+    /// Set the paragraph alignment.
+    pub fn with_paragraph_alignment(mut self, alignment: ParagraphAlignment) -> ParagraphStyling {
+        self.paragraph_alignment = Some(alignment);
+        self
+    }
+
+    /// Set the paragraph's top margin in half-inch increments.
+    pub fn with_paragraph_space_before(mut self, space: f32) -> ParagraphStyling {
+        self.paragraph_space_before = Some(space);
+        self
+    }
+
+    /// Set the paragraph's bottom margin in half-inch increments.
+    pub fn with_paragraph_space_after(mut self, space: f32) -> ParagraphStyling {
+        self.paragraph_space_after = Some(space);
+        self
+    }
+
+    /// Set the paragraph's line spacing in half-inch increments.
+    pub fn with_paragraph_line_spacing_exact(mut self, spacing: f32) -> ParagraphStyling {
+        self.paragraph_line_spacing_exact = Some(spacing);
+        self
+    }
+
+    /// Set the LCID language code for the text.
+    pub fn with_language_code(mut self, language_code: u32) -> ParagraphStyling {
+        self.language_code = Some(language_code);
+        self
+    }
+}
+
+impl Default for ParagraphStyling {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Embedded object types
 const INK_SPACE_BLOB: u32 = 0x00020026;
 const INK_END_OF_LINE_BLOB: u32 = 0x00020027;
 
+/// The start of the hidden field code containing a hyperlink's target URL.
+const HYPERLINK_FIELD_CODE: &str = "\u{fddf}HYPERLINK \"";
+
 pub(crate) fn parse_rich_text(content_id: ExGuid, space: &ObjectSpace) -> Result<RichText> {
     let object = space
         .get_object(content_id)
@@ -395,7 +583,7 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: &ObjectSpace) -> Result
         .flat_map(|(object_data, style_data)| {
             style_data
                 .text_run_is_embedded_object
-                .then(|| (style_data.text_run_object_type, object_data))
+                .then_some((style_data.text_run_object_type, object_data))
         })
         .collect_vec();
 
@@ -448,11 +636,13 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: &ObjectSpace) -> Result
         data.text.unwrap_or_default()
     };
 
+    let (text, text_run_indices, styles, links) = parse_links(text, data.text_run_indices, styles);
+
     let text = RichText {
         text,
         embedded_objects,
         text_run_formatting: styles,
-        text_run_indices: data.text_run_indices,
+        text_run_indices,
         paragraph_style,
         paragraph_space_before: data.paragraph_space_before,
         paragraph_space_after: data.paragraph_space_after,
@@ -461,11 +651,120 @@ pub(crate) fn parse_rich_text(content_id: ExGuid, space: &ObjectSpace) -> Result
         layout_alignment_in_parent: data.layout_alignment_in_parent,
         layout_alignment_self: data.layout_alignment_self,
         note_tags: parse_note_tags(data.note_tags, space)?,
+        links,
     };
 
     Ok(text)
 }
 
+/// Remove the hyperlink field codes from a paragraph's text and collect the links.
+///
+/// The text run indices are adjusted to the stripped text. Text runs that only
+/// contained a field code are removed along with their formatting.
+fn parse_links(
+    text: String,
+    indices: Vec<u32>,
+    styles: Vec<ParagraphStyling>,
+) -> (String, Vec<u32>, Vec<ParagraphStyling>, Vec<Link>) {
+    let utf16_len = |text: &str| text.encode_utf16().count() as u32;
+
+    // Find the field codes and their position in UTF-16 code units
+    let mut field_codes = vec![];
+    let mut stripped = String::with_capacity(text.len());
+    let mut position = 0;
+
+    while let Some(start) = text[position..].find(HYPERLINK_FIELD_CODE) {
+        let start = position + start;
+        let url_start = start + HYPERLINK_FIELD_CODE.len();
+        let url_end = match text[url_start..].find('"') {
+            Some(len) => url_start + len,
+            None => break,
+        };
+        let end = url_end + 1;
+
+        stripped.push_str(&text[position..start]);
+        position = end;
+
+        let removed = utf16_len(&text[..start])..utf16_len(&text[..end]);
+        field_codes.push((removed, text[url_start..url_end].to_string()));
+    }
+
+    if field_codes.is_empty() {
+        return (text, indices, styles, vec![]);
+    }
+
+    stripped.push_str(&text[position..]);
+
+    // Map an offset in the original text to the stripped text
+    let map_offset = |offset: u32| {
+        let removed: u32 = field_codes
+            .iter()
+            .map(|(range, _)| offset.clamp(range.start, range.end) - range.start)
+            .sum();
+
+        offset - removed
+    };
+
+    let mut run_indices = vec![];
+    let mut run_styles = vec![];
+    let mut styles = styles.into_iter();
+    let mut run_start = 0;
+
+    for index in indices {
+        let style = styles.next();
+
+        if index > run_start && map_offset(index) == map_offset(run_start) {
+            run_start = index;
+            continue;
+        }
+
+        run_start = index;
+        run_indices.push(map_offset(index));
+        run_styles.extend(style);
+    }
+
+    run_styles.extend(styles);
+
+    // The display text consists of the hyperlink text runs following the field code
+    let text_len = utf16_len(&stripped);
+    let run_end = |run: usize| run_indices.get(run).copied().unwrap_or(text_len);
+    let is_hyperlink = |run: usize| {
+        run_styles
+            .get(run)
+            .map(|style| style.hyperlink)
+            .unwrap_or_default()
+    };
+
+    let starts = field_codes
+        .iter()
+        .map(|(range, _)| map_offset(range.start))
+        .collect_vec();
+
+    let links = field_codes
+        .into_iter()
+        .zip(&starts)
+        .enumerate()
+        .map(|(i, ((_, url), &start))| {
+            let limit = starts.get(i + 1).copied().unwrap_or(text_len);
+            let first_run = run_indices.iter().take_while(|end| **end <= start).count();
+
+            let end = (first_run..=run_indices.len())
+                .take_while(|run| is_hyperlink(*run))
+                .map(run_end)
+                .last()
+                .unwrap_or(start)
+                .min(limit);
+
+            Link {
+                display_range: start..end,
+                url,
+            }
+        })
+        .collect();
+
+    (stripped, run_indices, run_styles, links)
+}
+
 fn parse_embedded_ink_data(
     embedded_id: ExGuid,
     space: &ObjectSpace,
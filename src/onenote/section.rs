@@ -1,9 +1,15 @@
-use crate::errors::{ErrorKind, Result};
+use crate::errors::{ErrorKind, Result, Warning};
 use crate::one::property::color::Color;
 use crate::one::property_set::{section_metadata_node, section_node};
+use crate::onenote::options::ParseOptions;
 use crate::onenote::page_series::{parse_page_series, PageSeries};
+use crate::onenote::unknown::{collect_unknown_types, UnknownContentType};
 use crate::onestore::object_space::ObjectSpace;
 use crate::onestore::OneStore;
+use crate::shared::guid::Guid;
+
+/// The name of the section group containing a notebook's deleted sections and pages.
+pub(crate) const RECYCLE_BIN_NAME: &str = "OneNote_RecycleBin";
 
 /// An entry in a section list.
 #[allow(missing_docs)]
@@ -21,12 +27,21 @@ pub enum SectionEntry {
 /// [\[MS-ONE\] 2.2.17]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/6913913f-b7d1-4b29-ab09-231ea3835ac2
 #[derive(Clone, Debug)]
 pub struct Section {
+    id: Guid,
     display_name: String,
     page_series: Vec<PageSeries>,
-    color: Option<Color>,
+    pub(crate) color: Option<Color>,
+    pub(crate) ordering_id: Option<u32>,
 }
 
 impl Section {
+    /// The section's unique ID.
+    ///
+    /// This is the ID used by links to the section.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
     /// The section name.
     pub fn display_name(&self) -> &str {
         &self.display_name
@@ -41,28 +56,105 @@ impl Section {
     pub fn color(&self) -> Option<Color> {
         self.color
     }
+
+    /// The section's position in the notebook's table of contents.
+    ///
+    /// Notebook entries are sorted by this value. Returns `None` if the section was
+    /// parsed on its own.
+    pub fn ordering_id(&self) -> Option<u32> {
+        self.ordering_id
+    }
+
+    /// The types of content in the section's pages that aren't supported by this crate.
+    ///
+    /// This lists each type of [`UnknownContent`](crate::contents::UnknownContent) found in
+    /// the current version of the pages, ordered by the type's JCID. Applications can use
+    /// it to warn users that an export of the section is missing some of its contents.
+    pub fn unknown_content_types(&self) -> Vec<UnknownContentType> {
+        collect_unknown_types(
+            self.page_series
+                .iter()
+                .flat_map(|series| series.pages.iter()),
+        )
+    }
+
+    /// Mark all pages of the section as deleted.
+    pub(crate) fn mark_deleted(&mut self) {
+        for page in self
+            .page_series
+            .iter_mut()
+            .flat_map(|series| series.pages.iter_mut())
+        {
+            page.is_deleted = true;
+        }
+    }
 }
 
 /// A group of sections.
 #[derive(Clone, Debug)]
 pub struct SectionGroup {
+    pub(crate) id: Guid,
     pub(crate) display_name: String,
+    pub(crate) color: Option<Color>,
+    pub(crate) ordering_id: Option<u32>,
     pub(crate) entries: Vec<SectionEntry>,
 }
 
 impl SectionGroup {
+    /// The group's unique ID.
+    ///
+    /// This is the file identity of the group's table of contents file.
+    pub fn id(&self) -> Guid {
+        self.id
+    }
+
     /// The group name.
     pub fn display_name(&self) -> &str {
         &self.display_name
     }
 
+    /// The color of the section group.
+    pub fn color(&self) -> Option<Color> {
+        self.color
+    }
+
+    /// The group's position in the parent's table of contents.
+    ///
+    /// Notebook entries are sorted by this value. Returns `None` for the recycle bin
+    /// as it isn't listed in the table of contents.
+    pub fn ordering_id(&self) -> Option<u32> {
+        self.ordering_id
+    }
+
+    /// Whether the group is the notebook's recycle bin.
+    ///
+    /// See [`Parser::parse_recycle_bin()`](crate::Parser::parse_recycle_bin()).
+    pub fn is_recycle_bin(&self) -> bool {
+        self.display_name == RECYCLE_BIN_NAME
+    }
+
+    /// Mark all pages of the group's sections as deleted.
+    pub(crate) fn mark_deleted(&mut self) {
+        for entry in &mut self.entries {
+            match entry {
+                SectionEntry::Section(section) => section.mark_deleted(),
+                SectionEntry::SectionGroup(group) => group.mark_deleted(),
+            }
+        }
+    }
+
     /// The sections contained within the group.
     pub fn entries(&self) -> &[SectionEntry] {
         &self.entries
     }
 }
 
-pub(crate) fn parse_section(store: OneStore, filename: String) -> Result<Section> {
+pub(crate) fn parse_section(
+    store: OneStore,
+    filename: String,
+    options: &ParseOptions,
+    warnings: &mut Vec<Warning>,
+) -> Result<Section> {
     let metadata = parse_metadata(store.data_root())?;
     let content = parse_content(store.data_root())?;
 
@@ -75,17 +167,19 @@ pub(crate) fn parse_section(store: OneStore, filename: String) -> Result<Section
     let page_series = content
         .page_series
         .into_iter()
-        .map(|page_series_id| parse_page_series(page_series_id, &store))
+        .map(|page_series_id| parse_page_series(page_series_id, &store, options, warnings))
         .collect::<Result<_>>()?;
 
     Ok(Section {
+        id: content.entity_guid,
         display_name,
         page_series,
         color: metadata.color,
+        ordering_id: None,
     })
 }
 
-fn parse_content(space: &ObjectSpace) -> Result<section_node::Data> {
+pub(crate) fn parse_content(space: &ObjectSpace) -> Result<section_node::Data> {
     let content_root_id = space
         .content_root()
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("section has no content root".into()))?;
@@ -96,7 +190,7 @@ fn parse_content(space: &ObjectSpace) -> Result<section_node::Data> {
     section_node::parse(content_object)
 }
 
-fn parse_metadata(space: &ObjectSpace) -> Result<section_metadata_node::Data> {
+pub(crate) fn parse_metadata(space: &ObjectSpace) -> Result<section_metadata_node::Data> {
     let metadata_root_id = space
         .metadata_root()
         .ok_or_else(|| ErrorKind::MalformedOneNoteData("section has no metadata root".into()))?;
@@ -0,0 +1,248 @@
+use crate::errors::{ErrorKind, Result};
+use crate::onenote::resolver::{NotebookResolver, ResolvedEntry};
+#[cfg(feature = "zip")]
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::Read;
+#[cfg(feature = "zip")]
+use std::io::{self, Seek};
+#[cfg(feature = "zip")]
+use std::path::Component;
+use std::path::{Path, PathBuf};
+
+/// A file system containing the files of a notebook.
+///
+/// Paths are relative to the source's root directory. The root directory itself is
+/// represented by an empty path.
+pub trait NotebookSource {
+    /// List the names of the files and directories contained in a directory.
+    fn entries(&mut self, dir: &Path) -> Result<Vec<String>>;
+
+    /// Open a file for reading.
+    fn open(&mut self, path: &Path) -> Result<Box<dyn Read + '_>>;
+
+    /// Whether the path points to a directory.
+    fn is_dir(&mut self, path: &Path) -> Result<bool>;
+}
+
+/// A notebook source backed by a directory on the file system.
+#[derive(Clone, Debug)]
+pub struct FsSource {
+    root: PathBuf,
+}
+
+impl FsSource {
+    /// Create a source for the files in the `root` directory.
+    pub fn new<P: Into<PathBuf>>(root: P) -> FsSource {
+        FsSource { root: root.into() }
+    }
+
+    fn path(&self, path: &Path) -> PathBuf {
+        let path = self.root.join(path);
+
+        if path.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            path
+        }
+    }
+}
+
+impl NotebookSource for FsSource {
+    fn entries(&mut self, dir: &Path) -> Result<Vec<String>> {
+        let mut entries = vec![];
+
+        for entry in self.path(dir).read_dir()? {
+            entries.push(entry?.file_name().to_string_lossy().into_owned());
+        }
+
+        entries.sort();
+
+        Ok(entries)
+    }
+
+    fn open(&mut self, path: &Path) -> Result<Box<dyn Read + '_>> {
+        Ok(Box::new(File::open(self.path(path))?))
+    }
+
+    fn is_dir(&mut self, path: &Path) -> Result<bool> {
+        Ok(self.path(path).is_dir())
+    }
+}
+
+/// A notebook source backed by a ZIP archive.
+///
+/// This requires the `zip` feature.
+#[cfg(feature = "zip")]
+pub struct ZipSource<R> {
+    archive: zip::ZipArchive<R>,
+}
+
+#[cfg(feature = "zip")]
+impl<R: Read + Seek> ZipSource<R> {
+    /// Create a source for the files in a ZIP archive.
+    pub fn new(reader: R) -> Result<ZipSource<R>> {
+        let archive = zip::ZipArchive::new(reader).map_err(io::Error::from)?;
+
+        Ok(ZipSource { archive })
+    }
+
+    /// The archive's name for a path, using `/` as the separator.
+    fn archive_path(path: &Path) -> String {
+        path.components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// The prefix of all archive entries contained in a directory.
+    fn dir_prefix(path: &Path) -> String {
+        let path = Self::archive_path(path);
+
+        if path.is_empty() {
+            path
+        } else {
+            path + "/"
+        }
+    }
+}
+
+#[cfg(feature = "zip")]
+impl<R: Read + Seek> NotebookSource for ZipSource<R> {
+    fn entries(&mut self, dir: &Path) -> Result<Vec<String>> {
+        let prefix = Self::dir_prefix(dir);
+
+        // Directories don't necessarily have their own archive entry, so they are
+        // derived from the file names
+        let entries: BTreeSet<_> = self
+            .archive
+            .file_names()
+            .filter_map(|name| name.strip_prefix(&prefix))
+            .filter_map(|name| name.split('/').next())
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .collect();
+
+        Ok(entries.into_iter().collect())
+    }
+
+    fn open(&mut self, path: &Path) -> Result<Box<dyn Read + '_>> {
+        let file = self
+            .archive
+            .by_name(&Self::archive_path(path))
+            .map_err(io::Error::from)?;
+
+        Ok(Box::new(file))
+    }
+
+    fn is_dir(&mut self, path: &Path) -> Result<bool> {
+        let prefix = Self::dir_prefix(path);
+
+        Ok(self
+            .archive
+            .file_names()
+            .any(|name| name.starts_with(&prefix)))
+    }
+}
+
+/// Read a file from a notebook source.
+///
+/// Sources don't report a file's size, so reading stops as soon as the file exceeds
+/// `max_file_size` instead of buffering the whole file first.
+pub(crate) fn read_file(
+    reader: impl Read,
+    file: &str,
+    max_file_size: Option<usize>,
+) -> Result<Vec<u8>> {
+    // Read one byte more than allowed to find out whether the file is too large
+    let limit = max_file_size.map_or(u64::MAX, |max| max as u64 + 1);
+
+    let mut data = vec![];
+    reader.take(limit).read_to_end(&mut data)?;
+
+    match max_file_size {
+        Some(max) if data.len() > max => Err(ErrorKind::FileTooLarge {
+            file: file.to_string(),
+            size: data.len() as u64,
+            max,
+        }
+        .into()),
+        _ => Ok(data),
+    }
+}
+
+/// A resolver looking up notebook entries in a notebook source.
+pub(crate) struct SourceResolver<'a> {
+    source: &'a mut dyn NotebookSource,
+    base_dir: PathBuf,
+    max_file_size: Option<usize>,
+}
+
+impl<'a> SourceResolver<'a> {
+    /// Create a resolver for the notebook in the `base_dir` directory of the source.
+    ///
+    /// Section files larger than `max_file_size` fail to resolve.
+    pub(crate) fn new(
+        source: &'a mut dyn NotebookSource,
+        base_dir: PathBuf,
+        max_file_size: Option<usize>,
+    ) -> Self {
+        SourceResolver {
+            source,
+            base_dir,
+            max_file_size,
+        }
+    }
+
+    fn read(&mut self, path: &Path) -> Result<Vec<u8>> {
+        let reader = self.source.open(path)?;
+
+        read_file(reader, &path.to_string_lossy(), self.max_file_size)
+    }
+}
+
+impl NotebookResolver for SourceResolver<'_> {
+    fn resolve(&mut self, path: &[String]) -> Result<Option<ResolvedEntry>> {
+        let (name, parents) = match path.split_last() {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+
+        let mut dir = self.base_dir.clone();
+        dir.extend(parents);
+
+        if !self.source.entries(&dir)?.contains(name) {
+            return Ok(None);
+        }
+
+        let entry_path = dir.join(name);
+
+        if !self.source.is_dir(&entry_path)? {
+            let data = self.read(&entry_path)?;
+
+            return Ok(Some(ResolvedEntry::Section(data)));
+        }
+
+        let toc = self
+            .source
+            .entries(&entry_path)?
+            .into_iter()
+            .find(|entry| Path::new(entry).extension() == Some(OsStr::new("onetoc2")));
+
+        match toc {
+            Some(toc) => {
+                let data = self.read(&entry_path.join(toc))?;
+
+                Ok(Some(ResolvedEntry::SectionGroup(data)))
+            }
+            None => Err(ErrorKind::TocFileMissing {
+                dir: entry_path.as_os_str().to_string_lossy().into_owned(),
+            }
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::PropertyType;
+use crate::one::property_set::PropertySetId;
+use crate::onenote::content::Content;
+use crate::onenote::outline::{Outline, OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onenote::raw::{RawObject, RawProperty, RawValue};
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::types::jcid::JcId;
+use std::collections::BTreeMap;
+
+/// Content of a type that isn't supported by this crate.
+///
+/// The content keeps the object's type, the text found in its properties and its raw
+/// properties so that applications can tell users that something was left out.
+#[derive(Clone, Debug)]
+pub struct UnknownContent {
+    pub(crate) id: ExGuid,
+    pub(crate) jc_id: u32,
+    pub(crate) text: Vec<String>,
+    pub(crate) properties: Vec<RawProperty>,
+}
+
+impl UnknownContent {
+    /// The ID of the content's object.
+    pub fn id(&self) -> ExGuid {
+        self.id
+    }
+
+    /// The content's object type (JCID).
+    pub fn jc_id(&self) -> u32 {
+        self.jc_id
+    }
+
+    /// The name of the content's object type if it's known to this crate (e.g. `MathNode`).
+    pub fn type_name(&self) -> Option<String> {
+        type_name(self.jc_id)
+    }
+
+    /// The text found in the content's properties.
+    ///
+    /// Text is detected by looking at the property values, so this is a best-effort
+    /// guess that may miss text or include binary data that happens to look like text.
+    pub fn text(&self) -> &[String] {
+        &self.text
+    }
+
+    /// The content's properties in the order they are stored in.
+    pub fn properties(&self) -> &[RawProperty] {
+        &self.properties
+    }
+}
+
+/// A type of unknown content found in a section.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownContentType {
+    pub(crate) jc_id: u32,
+    pub(crate) count: usize,
+}
+
+impl UnknownContentType {
+    /// The content's object type (JCID).
+    pub fn jc_id(&self) -> u32 {
+        self.jc_id
+    }
+
+    /// The name of the content's object type if it's known to this crate (e.g. `MathNode`).
+    pub fn type_name(&self) -> Option<String> {
+        type_name(self.jc_id)
+    }
+
+    /// How often content of this type was found.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+fn type_name(jc_id: u32) -> Option<String> {
+    PropertySetId::from_jcid(JcId(jc_id)).map(|id| format!("{:?}", id))
+}
+
+pub(crate) fn parse_unknown(content_id: ExGuid, space: &ObjectSpace) -> Result<UnknownContent> {
+    let object = space
+        .get_object(content_id)
+        .ok_or_else(|| ErrorKind::MalformedOneNoteData("unknown content is missing".into()))?;
+    let object = RawObject::new(content_id, object);
+
+    let properties = object.properties();
+    let text = properties.iter().flat_map(property_text).collect();
+
+    Ok(UnknownContent {
+        id: content_id,
+        jc_id: object.jc_id(),
+        text,
+        properties,
+    })
+}
+
+fn property_text(prop: &RawProperty) -> Vec<String> {
+    match prop.value() {
+        RawValue::Bytes(data) if prop.id() == PropertyType::TextExtendedAscii as u32 => {
+            decode_ascii(data).into_iter().collect()
+        }
+        RawValue::Bytes(data) => decode_utf16(data).into_iter().collect(),
+        RawValue::PropertyValues(_, sets) => {
+            sets.iter().flatten().flat_map(property_text).collect()
+        }
+        RawValue::PropertySet(props) => props.iter().flat_map(property_text).collect(),
+        _ => vec![],
+    }
+}
+
+fn decode_ascii(data: &[u8]) -> Option<String> {
+    let text: String = data
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as char)
+        .collect();
+
+    Some(text).filter(|text| looks_like_text(text))
+}
+
+fn decode_utf16(data: &[u8]) -> Option<String> {
+    let chunks = data.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+
+    let data: Vec<_> = chunks
+        .map(|v| u16::from_le_bytes([v[0], v[1]]))
+        .take_while(|c| *c != 0)
+        .collect();
+
+    String::from_utf16(&data)
+        .ok()
+        .filter(|text| looks_like_text(text))
+}
+
+/// Check whether a decoded value is likely to be text rather than binary data.
+fn looks_like_text(text: &str) -> bool {
+    // OneNote uses vertical tabs for line breaks within paragraphs
+    let is_text_char = |c: char| !c.is_control() || matches!(c, '\t' | '\n' | '\r' | '\u{0b}');
+
+    text.chars().all(is_text_char) && text.chars().any(char::is_alphanumeric)
+}
+
+/// Count the unknown content of the pages by type.
+pub(crate) fn collect_unknown_types<'a>(
+    pages: impl Iterator<Item = &'a Page>,
+) -> Vec<UnknownContentType> {
+    let mut counts = BTreeMap::new();
+
+    for page in pages {
+        collect_page(page, &mut counts);
+    }
+
+    counts
+        .into_iter()
+        .map(|(jc_id, count)| UnknownContentType { jc_id, count })
+        .collect()
+}
+
+fn collect_page(page: &Page, counts: &mut BTreeMap<u32, usize>) {
+    if let Some(title) = &page.title {
+        collect_outlines(&title.contents, counts);
+    }
+
+    for content in &page.contents {
+        match content {
+            PageContent::Outline(outline) => collect_items(&outline.items, counts),
+            PageContent::Unknown(content) => *counts.entry(content.jc_id).or_default() += 1,
+            PageContent::Image(_)
+            | PageContent::EmbeddedFile(_)
+            | PageContent::Ink(_)
+            | PageContent::Printout(_) => {}
+        }
+    }
+}
+
+fn collect_outlines(outlines: &[Outline], counts: &mut BTreeMap<u32, usize>) {
+    for outline in outlines {
+        collect_items(&outline.items, counts);
+    }
+}
+
+fn collect_items(items: &[OutlineItem], counts: &mut BTreeMap<u32, usize>) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => collect_items(&group.outlines, counts),
+            OutlineItem::Element(element) => collect_element(element, counts),
+        }
+    }
+}
+
+fn collect_element(element: &OutlineElement, counts: &mut BTreeMap<u32, usize>) {
+    for content in &element.contents {
+        match content {
+            Content::Table(table) => {
+                let cells = table.contents.iter().flat_map(|row| &row.contents);
+
+                for element in cells.flat_map(|cell| &cell.contents) {
+                    collect_element(element, counts);
+                }
+            }
+            Content::Unknown(content) => *counts.entry(content.jc_id).or_default() += 1,
+            Content::RichText(_)
+            | Content::Image(_)
+            | Content::EmbeddedFile(_)
+            | Content::Ink(_)
+            | Content::Printout(_) => {}
+        }
+    }
+
+    collect_items(&element.children, counts);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode_ascii, decode_utf16};
+    use crate::utils::utf16_bytes;
+
+    #[test]
+    fn test_decode_text() {
+        let mut data = utf16_bytes("E = mc²");
+        data.extend([0, 0]);
+
+        assert_eq!(decode_utf16(&data).as_deref(), Some("E = mc²"));
+        assert_eq!(decode_ascii(b"Hello\0").as_deref(), Some("Hello"));
+    }
+
+    #[test]
+    fn test_ignore_binary_data() {
+        assert_eq!(decode_utf16(&[1, 0, 0, 0, 2, 0, 0, 0]), None);
+        assert_eq!(decode_utf16(&[0x41, 0]), Some("A".to_string()));
+        assert_eq!(decode_utf16(&[0x00, 0xd8, 0x41, 0]), None);
+        assert_eq!(decode_utf16(&[0x41]), None);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::errors::{ErrorKind, Result};
+use crate::Reader;
+use std::convert::TryFrom;
+
+/// A reference to a chunk of data in a revision store file.
+///
+/// The file format uses several encodings for these references (with 32 or 64 bit offsets and
+/// sizes as well as compressed variants used by file nodes) which are all normalized into this
+/// type.
+///
+/// See \[MS-ONESTORE\] 2.2.4.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct ChunkReference {
+    pub(crate) stp: u64,
+    pub(crate) cb: u64,
+}
+
+impl ChunkReference {
+    /// Parse a `FileChunkReference32` structure.
+    pub(crate) fn parse_32(reader: Reader) -> Result<ChunkReference> {
+        let stp = Self::expand(reader.get_u32()? as u64, u32::MAX as u64, 1);
+        let cb = reader.get_u32()?;
+
+        Ok(ChunkReference { stp, cb: cb as u64 })
+    }
+
+    /// Parse a `FileChunkReference64x32` structure.
+    pub(crate) fn parse_64x32(reader: Reader) -> Result<ChunkReference> {
+        let stp = reader.get_u64()?;
+        let cb = reader.get_u32()?;
+
+        Ok(ChunkReference { stp, cb: cb as u64 })
+    }
+
+    /// Parse a `FileNodeChunkReference` structure.
+    ///
+    /// The encoding of the reference is determined by the `StpFormat` and `CbFormat` fields
+    /// of the file node header (see \[MS-ONESTORE\] 2.2.4.2).
+    pub(crate) fn parse_node(
+        reader: Reader,
+        stp_format: u8,
+        cb_format: u8,
+    ) -> Result<ChunkReference> {
+        // A nil reference has all bits of the (possibly compressed) offset set
+        let stp = match stp_format {
+            0 => reader.get_u64()?,
+            1 => Self::expand(reader.get_u32()? as u64, u32::MAX as u64, 1),
+            2 => Self::expand(reader.get_u16()? as u64, u16::MAX as u64, 8),
+            3 => Self::expand(reader.get_u32()? as u64, u32::MAX as u64, 8),
+            _ => {
+                return Err(
+                    ErrorKind::MalformedOneStoreData("invalid file node stp format".into()).into(),
+                )
+            }
+        };
+
+        let cb = match cb_format {
+            0 => reader.get_u32()? as u64,
+            1 => reader.get_u64()?,
+            2 => reader.get_u8()? as u64 * 8,
+            3 => reader.get_u16()? as u64 * 8,
+            _ => {
+                return Err(
+                    ErrorKind::MalformedOneStoreData("invalid file node cb format".into()).into(),
+                )
+            }
+        };
+
+        Ok(ChunkReference { stp, cb })
+    }
+
+    /// Whether the reference is `fcrNil`, i.e. doesn't point to any data.
+    pub(crate) fn is_nil(&self) -> bool {
+        self.stp == u64::MAX && self.cb == 0
+    }
+
+    /// Whether the reference is `fcrZero`.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.stp == 0 && self.cb == 0
+    }
+
+    /// Look up the referenced data in the file.
+    pub(crate) fn data<'a>(&self, file: &'a [u8]) -> Result<&'a [u8]> {
+        let start = usize::try_from(self.stp).ok();
+        let end = start.and_then(|start| start.checked_add(usize::try_from(self.cb).ok()?));
+
+        match (start, end) {
+            (Some(start), Some(end)) if end <= file.len() => Ok(&file[start..end]),
+            _ => Err(ErrorKind::MalformedOneStoreData(
+                format!(
+                    "file chunk reference 0x{:X}+0x{:X} is out of bounds",
+                    self.stp, self.cb
+                )
+                .into(),
+            )
+            .into()),
+        }
+    }
+
+    fn expand(value: u64, nil: u64, factor: u64) -> u64 {
+        if value == nil {
+            u64::MAX
+        } else {
+            value * factor
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::onestore::file::chunk_reference::ChunkReference;
+    use crate::reader::Reader;
+
+    #[test]
+    fn test_parse_node_compressed() {
+        let data = [0x02, 0x00, 0x03];
+        let reference = ChunkReference::parse_node(&mut Reader::new(&data), 2, 2).unwrap();
+
+        assert_eq!(reference, ChunkReference { stp: 16, cb: 24 });
+    }
+
+    #[test]
+    fn test_parse_node_nil() {
+        let data = [0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00];
+        let reference = ChunkReference::parse_node(&mut Reader::new(&data), 1, 0).unwrap();
+
+        assert!(reference.is_nil());
+
+        let data = [0xFF, 0xFF, 0x00];
+        let reference = ChunkReference::parse_node(&mut Reader::new(&data), 2, 2).unwrap();
+
+        assert!(reference.is_nil());
+    }
+}
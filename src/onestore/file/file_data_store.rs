@@ -0,0 +1,71 @@
+use crate::errors::{ErrorKind, Result};
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::onestore::file::file_node::FileNode;
+use crate::onestore::file::file_node_list::parse_file_node_list;
+use crate::onestore::file::transaction_log::TransactionLog;
+use crate::reader::Reader;
+use crate::shared::guid::Guid;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// The file data objects (e.g. images or embedded files) stored in a revision store file.
+///
+/// See \[MS-ONESTORE\] 2.1.13 and 2.6.13.
+#[derive(Debug, Default)]
+pub(crate) struct FileDataStore<'a> {
+    objects: HashMap<Guid, &'a [u8]>,
+}
+
+impl<'a> FileDataStore<'a> {
+    pub(crate) fn parse(
+        file: &'a [u8],
+        list: ChunkReference,
+        log: &TransactionLog,
+    ) -> Result<FileDataStore<'a>> {
+        let mut objects = HashMap::new();
+
+        for node in parse_file_node_list(file, list, log)? {
+            if let FileNode::FileDataStoreObjectReference { data, id } = node {
+                objects.insert(id, Self::parse_object(data.data(file)?)?);
+            }
+        }
+
+        Ok(FileDataStore { objects })
+    }
+
+    /// Look up a file data object by its `FileDataReference` string.
+    ///
+    /// References to files stored outside of the revision store file (`<file>` references)
+    /// and invalid references (`<invfdo>`) can't be resolved and return `None`.
+    pub(crate) fn get(&self, reference: &str) -> Option<&'a [u8]> {
+        let guid = reference
+            .strip_prefix("<ifndf>")?
+            .trim_start_matches('{')
+            .trim_end_matches('}');
+
+        let guid = Guid::from_str(guid).ok()?;
+
+        self.objects.get(&guid).copied()
+    }
+
+    fn parse_object(data: &'a [u8]) -> Result<&'a [u8]> {
+        let mut reader = Reader::new(data);
+
+        if Guid::parse(&mut reader)? != guid!({BDE316E7-2665-4511-A4C4-8D4D0B7A9EAC}) {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "invalid file data store object header".into(),
+            )
+            .into());
+        }
+
+        let size = reader.get_u64()?;
+        let _unused = reader.get_u32()?;
+        let _reserved = reader.get_u64()?;
+
+        let size = usize::try_from(size).map_err(|_| {
+            ErrorKind::MalformedOneStoreData("file data store object is too large".into())
+        })?;
+
+        reader.read(size)
+    }
+}
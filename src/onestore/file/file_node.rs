@@ -0,0 +1,281 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::onestore::types::compact_id::CompactId;
+use crate::onestore::types::jcid::JcId;
+use crate::shared::guid::Guid;
+use crate::utils::Utf16ToString;
+use crate::Reader;
+
+/// A file node.
+///
+/// File nodes are the building blocks of a revision store file. Only the file nodes that are
+/// required to reconstruct the object spaces of a file are parsed into dedicated variants,
+/// everything else is reported as [`FileNode::Other`].
+///
+/// See \[MS-ONESTORE\] 2.4.3 and 2.5.
+#[derive(Debug)]
+pub(crate) enum FileNode {
+    ObjectSpaceManifestRoot {
+        root: ExGuid,
+    },
+    ObjectSpaceManifestListReference {
+        list: ChunkReference,
+        id: ExGuid,
+    },
+    RevisionManifestListReference {
+        list: ChunkReference,
+    },
+    RevisionManifestStart {
+        id: ExGuid,
+        dependent: ExGuid,
+        role: u32,
+        context: ExGuid,
+        encoding: u16,
+    },
+    RevisionManifestEnd,
+    GlobalIdTableStart,
+    GlobalIdTableEntry {
+        index: u32,
+        guid: Guid,
+    },
+    GlobalIdTableEntryMap {
+        from: u32,
+        to: u32,
+    },
+    GlobalIdTableEntryCopy {
+        from: u32,
+        count: u32,
+        to: u32,
+    },
+    GlobalIdTableEnd,
+    ObjectDeclaration {
+        data: ChunkReference,
+        id: CompactId,
+        jc_id: JcId,
+        encoding: u8,
+    },
+    ObjectRevision {
+        data: ChunkReference,
+        id: CompactId,
+    },
+    ObjectDeclarationFileData {
+        id: CompactId,
+        jc_id: JcId,
+        reference: String,
+        extension: String,
+    },
+    RootObjectReference {
+        id: CompactId,
+        role: u32,
+    },
+    RootObjectReferenceExGuid {
+        id: ExGuid,
+        role: u32,
+    },
+    RevisionRoleDeclaration {
+        id: ExGuid,
+        role: u32,
+        context: ExGuid,
+    },
+    ObjectGroupListReference {
+        list: ChunkReference,
+    },
+    FileDataStoreListReference {
+        list: ChunkReference,
+    },
+    FileDataStoreObjectReference {
+        data: ChunkReference,
+        id: Guid,
+    },
+    ObjectDataEncryptionKey,
+    ChunkTerminator,
+    Other,
+}
+
+impl FileNode {
+    /// Parse a file node from its 32 bit header and the data following it.
+    pub(crate) fn parse(header: u32, reader: Reader) -> Result<FileNode> {
+        let node_id = (header & 0x3FF) as u16;
+
+        let node = match node_id {
+            0x004 => FileNode::ObjectSpaceManifestRoot {
+                root: parse_exguid(reader)?,
+            },
+            0x008 => FileNode::ObjectSpaceManifestListReference {
+                list: parse_reference(header, reader)?,
+                id: parse_exguid(reader)?,
+            },
+            0x010 => FileNode::RevisionManifestListReference {
+                list: parse_reference(header, reader)?,
+            },
+            0x01B => {
+                let id = parse_exguid(reader)?;
+                let dependent = parse_exguid(reader)?;
+                let _time_creation = reader.get_u64()?;
+                let role = reader.get_u32()?;
+                let encoding = reader.get_u16()?;
+
+                FileNode::RevisionManifestStart {
+                    id,
+                    dependent,
+                    role,
+                    context: ExGuid::nil(),
+                    encoding,
+                }
+            }
+            0x01E | 0x01F => {
+                let id = parse_exguid(reader)?;
+                let dependent = parse_exguid(reader)?;
+                let role = reader.get_u32()?;
+                let encoding = reader.get_u16()?;
+                let context = if node_id == 0x01F {
+                    parse_exguid(reader)?
+                } else {
+                    ExGuid::nil()
+                };
+
+                FileNode::RevisionManifestStart {
+                    id,
+                    dependent,
+                    role,
+                    context,
+                    encoding,
+                }
+            }
+            0x01C => FileNode::RevisionManifestEnd,
+            0x021 | 0x022 => FileNode::GlobalIdTableStart,
+            0x024 => FileNode::GlobalIdTableEntry {
+                index: reader.get_u32()?,
+                guid: Guid::parse(reader)?,
+            },
+            0x025 => FileNode::GlobalIdTableEntryMap {
+                from: reader.get_u32()?,
+                to: reader.get_u32()?,
+            },
+            0x026 => FileNode::GlobalIdTableEntryCopy {
+                from: reader.get_u32()?,
+                count: reader.get_u32()?,
+                to: reader.get_u32()?,
+            },
+            0x028 => FileNode::GlobalIdTableEnd,
+            0x02D | 0x02E => {
+                let data = parse_reference(header, reader)?;
+                let id = CompactId::parse(reader)?;
+                let flags = reader.get_u16()?;
+                let _reserved = reader.get_u32()?;
+
+                // Objects declared this way are always property sets
+                let jc_id = JcId(0x00020000 | (flags & 0x3FF) as u32);
+                let encoding = ((flags >> 10) & 0xF) as u8;
+
+                FileNode::ObjectDeclaration {
+                    data,
+                    id,
+                    jc_id,
+                    encoding,
+                }
+            }
+            0x041 | 0x042 => FileNode::ObjectRevision {
+                data: parse_reference(header, reader)?,
+                id: CompactId::parse(reader)?,
+            },
+            0x072 | 0x073 => {
+                let id = CompactId::parse(reader)?;
+                let jc_id = JcId::parse(reader)?;
+
+                if node_id == 0x072 {
+                    reader.get_u8()?;
+                } else {
+                    reader.get_u32()?;
+                }
+
+                let reference = parse_string(reader)?;
+                let extension = parse_string(reader)?;
+
+                FileNode::ObjectDeclarationFileData {
+                    id,
+                    jc_id,
+                    reference,
+                    extension,
+                }
+            }
+            0x059 => FileNode::RootObjectReference {
+                id: CompactId::parse(reader)?,
+                role: reader.get_u32()?,
+            },
+            0x05A => FileNode::RootObjectReferenceExGuid {
+                id: parse_exguid(reader)?,
+                role: reader.get_u32()?,
+            },
+            0x05C | 0x05D => {
+                let id = parse_exguid(reader)?;
+                let role = reader.get_u32()?;
+                let context = if node_id == 0x05D {
+                    parse_exguid(reader)?
+                } else {
+                    ExGuid::nil()
+                };
+
+                FileNode::RevisionRoleDeclaration { id, role, context }
+            }
+            0x07C => FileNode::ObjectDataEncryptionKey,
+            0x090 => FileNode::FileDataStoreListReference {
+                list: parse_reference(header, reader)?,
+            },
+            0x094 => FileNode::FileDataStoreObjectReference {
+                data: parse_reference(header, reader)?,
+                id: Guid::parse(reader)?,
+            },
+            0x0A4 | 0x0A5 | 0x0C4 | 0x0C5 => FileNode::ObjectDeclaration {
+                data: parse_reference(header, reader)?,
+                id: CompactId::parse(reader)?,
+                jc_id: JcId::parse(reader)?,
+                encoding: 0,
+            },
+            0x0B0 => FileNode::ObjectGroupListReference {
+                list: parse_reference(header, reader)?,
+            },
+            0x0FF => FileNode::ChunkTerminator,
+            _ => FileNode::Other,
+        };
+
+        Ok(node)
+    }
+}
+
+/// Parse the data reference at the start of a file node.
+fn parse_reference(header: u32, reader: Reader) -> Result<ChunkReference> {
+    let stp_format = ((header >> 23) & 0x3) as u8;
+    let cb_format = ((header >> 25) & 0x3) as u8;
+    let base_type = (header >> 27) & 0xF;
+
+    if base_type != 1 && base_type != 2 {
+        return Err(ErrorKind::MalformedOneStoreData(
+            format!("file node 0x{:03X} has no data reference", header & 0x3FF).into(),
+        )
+        .into());
+    }
+
+    ChunkReference::parse_node(reader, stp_format, cb_format)
+}
+
+/// Parse an uncompressed `ExtendedGUID` structure.
+///
+/// See \[MS-ONESTORE\] 2.2.1.
+pub(crate) fn parse_exguid(reader: Reader) -> Result<ExGuid> {
+    let guid = Guid::parse(reader)?;
+    let value = reader.get_u32()?;
+
+    Ok(ExGuid::from_guid(guid, value))
+}
+
+/// Parse a `StringInStorageBuffer` structure.
+///
+/// See \[MS-ONESTORE\] 2.2.3.
+fn parse_string(reader: Reader) -> Result<String> {
+    let len = reader.get_u32()?;
+    let data = reader.read(len as usize * 2)?;
+
+    data.utf16_to_string()
+}
@@ -0,0 +1,103 @@
+use crate::errors::{ErrorKind, Result};
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::onestore::file::file_node::FileNode;
+use crate::onestore::file::transaction_log::TransactionLog;
+use crate::reader::Reader;
+
+const FRAGMENT_HEADER_MAGIC: u64 = 0xA4567AB1F5F7F4C4;
+const FRAGMENT_FOOTER_MAGIC: u64 = 0x8BC215C38233BA4B;
+
+// Size of the fragment header (magic, list ID and sequence number)
+const FRAGMENT_HEADER_SIZE: usize = 16;
+
+// Size of the fragment trailer (next fragment reference and footer)
+const FRAGMENT_TRAILER_SIZE: usize = 20;
+
+/// Parse a file node list.
+///
+/// A file node list is stored as a chain of fragments. The list ends when either the chain
+/// ends or the number of committed file nodes recorded in the transaction log is reached. File
+/// nodes written by uncommitted transactions are ignored.
+///
+/// See \[MS-ONESTORE\] 2.4.
+pub(crate) fn parse_file_node_list(
+    file: &[u8],
+    reference: ChunkReference,
+    log: &TransactionLog,
+) -> Result<Vec<FileNode>> {
+    let mut nodes = vec![];
+    let mut limit = None;
+    let mut next = Some(reference);
+    let mut expected_sequence = 0;
+
+    while let Some(reference) = next.take() {
+        let fragment = reference.data(file)?;
+
+        if fragment.len() < FRAGMENT_HEADER_SIZE + FRAGMENT_TRAILER_SIZE {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "file node list fragment is too small".into(),
+            )
+            .into());
+        }
+
+        let mut reader = Reader::new(fragment);
+        if reader.get_u64()? != FRAGMENT_HEADER_MAGIC {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "invalid file node list fragment header".into(),
+            )
+            .into());
+        }
+
+        let list_id = reader.get_u32()?;
+        let sequence = reader.get_u32()?;
+
+        if sequence != expected_sequence {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "unexpected file node list fragment sequence number".into(),
+            )
+            .into());
+        }
+
+        expected_sequence += 1;
+
+        let limit = *limit.get_or_insert_with(|| log.committed_node_count(list_id));
+
+        let trailer_offset = fragment.len() - FRAGMENT_TRAILER_SIZE;
+        let mut nodes_reader = Reader::new(&fragment[FRAGMENT_HEADER_SIZE..trailer_offset]);
+
+        while nodes_reader.remaining() >= 4 && limit.is_none_or(|limit| nodes.len() < limit) {
+            let header = nodes_reader.get_u32()?;
+            let size = ((header >> 10) & 0x1FFF) as usize;
+
+            if header == 0 || size < 4 {
+                break;
+            }
+
+            let data = nodes_reader.read(size - 4)?;
+            let node = FileNode::parse(header, &mut Reader::new(data))?;
+
+            if let FileNode::ChunkTerminator = node {
+                break;
+            }
+
+            nodes.push(node);
+        }
+
+        let mut trailer = Reader::new(&fragment[trailer_offset..]);
+        let next_fragment = ChunkReference::parse_64x32(&mut trailer)?;
+
+        if trailer.get_u64()? != FRAGMENT_FOOTER_MAGIC {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "invalid file node list fragment footer".into(),
+            )
+            .into());
+        }
+
+        let complete = limit.is_some_and(|limit| nodes.len() >= limit);
+        if !complete && !next_fragment.is_nil() && !next_fragment.is_zero() {
+            next = Some(next_fragment);
+        }
+    }
+
+    Ok(nodes)
+}
@@ -0,0 +1,74 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::onestore::types::compact_id::CompactId;
+use crate::shared::guid::Guid;
+use std::collections::HashMap;
+
+/// A global identification table.
+///
+/// The table maps the GUID indexes used by `CompactId`s to GUIDs. Entries can either be
+/// declared directly or be copied over from the table of the revision that the current
+/// revision depends on.
+///
+/// See \[MS-ONESTORE\] 2.4.3 and 2.1.3.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GlobalIdTable {
+    entries: HashMap<u32, Guid>,
+}
+
+impl GlobalIdTable {
+    pub(crate) fn insert(&mut self, index: u32, guid: Guid) {
+        self.entries.insert(index, guid);
+    }
+
+    pub(crate) fn copy_from(&mut self, previous: &GlobalIdTable, from: u32, to: u32) -> Result<()> {
+        let guid = previous.entries.get(&from).copied().ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData("global id table entry to copy not found".into())
+        })?;
+
+        self.entries.insert(to, guid);
+
+        Ok(())
+    }
+
+    /// Copy a range of `count` consecutive entries from the previous table.
+    pub(crate) fn copy_range_from(
+        &mut self,
+        previous: &GlobalIdTable,
+        from: u32,
+        count: u32,
+        to: u32,
+    ) -> Result<()> {
+        let is_valid = count as usize <= previous.entries.len()
+            && from.checked_add(count).is_some()
+            && to.checked_add(count).is_some();
+
+        if !is_valid {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "global id table copy range is out of bounds".into(),
+            )
+            .into());
+        }
+
+        for i in 0..count {
+            self.copy_from(previous, from + i, to + i)?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolve a `CompactId` to the `ExGuid` it represents.
+    pub(crate) fn resolve(&self, id: CompactId) -> Result<ExGuid> {
+        if id.n() == 0 && id.guid_index() == 0 {
+            return Ok(ExGuid::nil());
+        }
+
+        let guid = self.entries.get(&id.guid_index()).copied().ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData(
+                format!("global id table index {} not found", id.guid_index()).into(),
+            )
+        })?;
+
+        Ok(ExGuid::from_guid(guid, id.n() as u32))
+    }
+}
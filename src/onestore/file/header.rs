@@ -0,0 +1,83 @@
+use crate::errors::{ErrorKind, Result};
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::shared::guid::Guid;
+use crate::Reader;
+
+/// The header of a revision store file.
+///
+/// Only the fields required to locate the file's contents are kept.
+///
+/// See \[MS-ONESTORE\] 2.3.1.
+#[derive(Debug)]
+pub(crate) struct FileHeader {
+    pub(crate) file_type: Guid,
+    pub(crate) file: Guid,
+    pub(crate) last_code_that_wrote_to_this_file: u32,
+    pub(crate) transactions_in_log: u32,
+    pub(crate) ancestor: Guid,
+    pub(crate) name_crc: u32,
+    pub(crate) transaction_log: ChunkReference,
+    pub(crate) file_node_list_root: ChunkReference,
+}
+
+impl FileHeader {
+    pub(crate) fn parse(reader: Reader) -> Result<FileHeader> {
+        if reader.remaining() < 1024 {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+
+        let file_type = Guid::parse(reader)?;
+        let file = Guid::parse(reader)?;
+        let _legacy_file_version = Guid::parse(reader)?;
+        let file_format = Guid::parse(reader)?;
+
+        if file_format != guid!("109ADD3F-911B-49F5-A5D0-1791EDC8AED8") {
+            return Err(
+                ErrorKind::MalformedOneStoreData("not a revision store file".into()).into(),
+            );
+        }
+
+        let last_code_that_wrote_to_this_file = reader.get_u32()?;
+        let _oldest_code_that_has_written_to_this_file = reader.get_u32()?;
+        let _newest_code_that_has_written_to_this_file = reader.get_u32()?;
+        let _oldest_code_that_may_read_this_file = reader.get_u32()?;
+        let _legacy_free_chunk_list = ChunkReference::parse_32(reader)?;
+        let _legacy_transaction_log = ChunkReference::parse_32(reader)?;
+        let transactions_in_log = reader.get_u32()?;
+        let _legacy_expected_file_length = reader.get_u32()?;
+        let _placeholder = reader.get_u64()?;
+        let _legacy_file_node_list_root = ChunkReference::parse_32(reader)?;
+        let _legacy_free_space_in_free_chunk_list = reader.get_u32()?;
+        let _flags = reader.read(4)?;
+        let ancestor = Guid::parse(reader)?;
+        let name_crc = reader.get_u32()?;
+        let _hashed_chunk_list = ChunkReference::parse_64x32(reader)?;
+        let transaction_log = ChunkReference::parse_64x32(reader)?;
+        let file_node_list_root = ChunkReference::parse_64x32(reader)?;
+
+        if transaction_log.is_nil() || transaction_log.is_zero() {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "transaction log reference is missing".into(),
+            )
+            .into());
+        }
+
+        if file_node_list_root.is_nil() || file_node_list_root.is_zero() {
+            return Err(ErrorKind::MalformedOneStoreData(
+                "root file node list reference is missing".into(),
+            )
+            .into());
+        }
+
+        Ok(FileHeader {
+            file_type,
+            file,
+            last_code_that_wrote_to_this_file,
+            transactions_in_log,
+            ancestor,
+            name_crc,
+            transaction_log,
+            file_node_list_root,
+        })
+    }
+}
@@ -0,0 +1,329 @@
+//! The revision store file format.
+//!
+//! This is the file format used by the OneNote 2010+ desktop applications when storing
+//! notebooks on a local disk. Unlike FSSHTTPB packaged files (as downloaded from OneDrive),
+//! these files store the OneStore object spaces as lists of file nodes.
+//!
+//! See \[MS-ONESTORE\] 2.2 to 2.6.
+
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::onestore::file::file_data_store::FileDataStore;
+use crate::onestore::file::file_node::FileNode;
+use crate::onestore::file::file_node_list::parse_file_node_list;
+use crate::onestore::file::header::FileHeader;
+use crate::onestore::file::object_space::parse_object_space;
+use crate::onestore::file::transaction_log::TransactionLog;
+use crate::onestore::header::StoreHeader;
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::{OneStore, StoreOptions, StoreSource};
+use crate::reader::Reader;
+use crate::shared::guid::Guid;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+mod chunk_reference;
+mod file_data_store;
+mod file_node;
+mod file_node_list;
+mod global_id_table;
+mod header;
+mod object_space;
+mod transaction_log;
+#[cfg(test)]
+mod writer;
+
+/// Check whether the file is a revision store file (as opposed to a FSSHTTPB packaged file).
+pub(crate) fn is_revision_store(data: &[u8]) -> bool {
+    data.get(48..64)
+        .and_then(|data| Guid::parse(&mut Reader::new(data)).ok())
+        .map(|file_format| file_format == guid!("109ADD3F-911B-49F5-A5D0-1791EDC8AED8"))
+        .unwrap_or_default()
+}
+
+/// Parse a revision store file.
+///
+/// See \[MS-ONESTORE\] 2.1.14 for the structure of the root file node list.
+pub(crate) fn parse_store(data: &[u8], options: StoreOptions) -> Result<OneStore<'_>> {
+    let header = FileHeader::parse(&mut Reader::new(data))?;
+    let log = TransactionLog::parse(data, header.transaction_log, header.transactions_in_log)?;
+
+    let mut root = None;
+    let mut manifest_lists = vec![];
+    let mut file_data = FileDataStore::default();
+
+    for node in parse_file_node_list(data, header.file_node_list_root, &log)? {
+        match node {
+            FileNode::ObjectSpaceManifestRoot { root: id } => root = Some(id),
+            FileNode::ObjectSpaceManifestListReference { list, id } => {
+                manifest_lists.push((id, list))
+            }
+            FileNode::FileDataStoreListReference { list } => {
+                file_data = FileDataStore::parse(data, list, &log)?
+            }
+            _ => {}
+        }
+    }
+
+    let root =
+        root.ok_or_else(|| ErrorKind::MalformedOneStoreData("object space root missing".into()))?;
+
+    let file = RevisionStoreFile {
+        data,
+        log,
+        file_data,
+        manifest_lists: manifest_lists.into_iter().collect(),
+    };
+
+    let data_root_id = CellId(ExGuid::nil(), root);
+    let data_root = file.parse_object_space(data_root_id, &options)?;
+
+    // The other object spaces are parsed on demand
+    let object_spaces = file
+        .manifest_lists
+        .keys()
+        .map(|id| CellId(ExGuid::nil(), *id))
+        .filter(|id| *id != data_root_id)
+        .map(|id| (id, OnceLock::new()))
+        .collect();
+
+    Ok(OneStore {
+        schema: schema_guid(header.file_type)?,
+        header: StoreHeader::new(
+            header.file,
+            header.ancestor,
+            Some(header.last_code_that_wrote_to_this_file),
+            header.name_crc,
+        ),
+        data_root_id,
+        data_root,
+        object_spaces,
+        source: StoreSource::File(file),
+        options,
+    })
+}
+
+/// The parts of a revision store file needed to parse its object spaces.
+#[derive(Debug)]
+pub(crate) struct RevisionStoreFile<'a> {
+    data: &'a [u8],
+    log: TransactionLog,
+    file_data: FileDataStore<'a>,
+    manifest_lists: HashMap<ExGuid, ChunkReference>,
+}
+
+impl<'a> RevisionStoreFile<'a> {
+    pub(crate) fn parse_object_space(
+        &self,
+        space_id: CellId,
+        options: &StoreOptions,
+    ) -> Result<ObjectSpace<'a>> {
+        let list = self.manifest_lists.get(&space_id.1).ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData("object space manifest missing".into())
+        })?;
+
+        parse_object_space(
+            self.data,
+            space_id.1,
+            *list,
+            &self.log,
+            &self.file_data,
+            options,
+        )
+    }
+}
+
+/// Map the file type to the OneStore schema GUID used by FSSHTTPB packaged files.
+fn schema_guid(file_type: Guid) -> Result<Guid> {
+    if file_type == guid!({7B5C52E4-D88C-4DA7-AEB1-5378D02996D3}) {
+        Ok(guid!({1F937CB4-B26F-445F-B9F8-17E20160E461}))
+    } else if file_type == guid!("43FF2FA1-EFD9-4C76-9EE2-10EA5722765F") {
+        Ok(guid!({E4DBFD38-E5C7-408B-A8A1-0E7B421E1F5F}))
+    } else {
+        Err(ErrorKind::MalformedOneStoreData(
+            format!("unknown revision store file type {}", file_type).into(),
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::onestore::file::parse_store;
+    use crate::onestore::types::property::{PropertyId, PropertyValue};
+    use crate::onestore::StoreOptions;
+    use crate::shared::guid::Guid;
+
+    fn guid(guid: Guid) -> Vec<u8> {
+        guid.0.to_bytes_le().to_vec()
+    }
+
+    fn exguid(id: ExGuid) -> Vec<u8> {
+        [guid(id.guid), id.value.to_le_bytes().to_vec()].concat()
+    }
+
+    fn reference(stp: usize, cb: usize) -> Vec<u8> {
+        [
+            (stp as u64).to_le_bytes().to_vec(),
+            (cb as u32).to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    fn node(id: u32, base_type: u32, data: Vec<u8>) -> Vec<u8> {
+        let header = id | ((data.len() as u32 + 4) << 10) | (base_type << 27);
+
+        [header.to_le_bytes().to_vec(), data].concat()
+    }
+
+    struct File(Vec<u8>);
+
+    impl File {
+        fn append(&mut self, data: Vec<u8>) -> (usize, usize) {
+            let offset = self.0.len();
+            self.0.extend_from_slice(&data);
+
+            (offset, data.len())
+        }
+
+        fn append_list(&mut self, list_id: u32, nodes: Vec<Vec<u8>>) -> (usize, usize) {
+            let mut data = vec![];
+            data.extend_from_slice(&0xA4567AB1F5F7F4C4u64.to_le_bytes());
+            data.extend_from_slice(&list_id.to_le_bytes());
+            data.extend_from_slice(&0u32.to_le_bytes());
+            data.extend(nodes.into_iter().flatten());
+            data.extend(reference(u64::MAX as usize, 0));
+            data.extend_from_slice(&0x8BC215C38233BA4Bu64.to_le_bytes());
+
+            self.append(data)
+        }
+    }
+
+    #[test]
+    fn test_parse_minimal_section() {
+        let space_id = exguid!({{2B2F5D1A-0B7C-4B33-9C6E-0D5F0E4A1C11}, 1});
+        let revision_id = exguid!({{7C1D2E3F-4A5B-4C6D-8E7F-901A2B3C4D5E}, 1});
+        let object_guid = guid!({5A6B7C8D-9E0F-4A1B-8C2D-3E4F5A6B7C8D});
+        let object_id = ExGuid::from_guid(object_guid, 1);
+
+        let mut file = File(vec![0; 1024]);
+
+        // An object with a single U32 property and no references
+        let (props, props_size) = file.append(
+            [
+                0x80000000u32.to_le_bytes().to_vec(),
+                1u16.to_le_bytes().to_vec(),
+                0x14001234u32.to_le_bytes().to_vec(),
+                42u32.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        );
+
+        let group = file.append_list(
+            3,
+            vec![
+                node(0x022, 0, vec![]),
+                node(0x024, 0, [vec![0; 4], guid(object_guid)].concat()),
+                node(0x028, 0, vec![]),
+                node(
+                    0x0A4,
+                    1,
+                    [
+                        reference(props, props_size),
+                        0x00000001u32.to_le_bytes().to_vec(),
+                        0x00020001u32.to_le_bytes().to_vec(),
+                        vec![0, 1],
+                    ]
+                    .concat(),
+                ),
+            ],
+        );
+
+        let revisions = file.append_list(
+            2,
+            vec![
+                node(0x014, 0, [exguid(space_id), vec![0; 4]].concat()),
+                node(
+                    0x01E,
+                    0,
+                    [
+                        exguid(revision_id),
+                        vec![0; 20],
+                        1u32.to_le_bytes().to_vec(),
+                        vec![0; 2],
+                    ]
+                    .concat(),
+                ),
+                node(
+                    0x0B0,
+                    2,
+                    [reference(group.0, group.1), exguid(object_id)].concat(),
+                ),
+                node(
+                    0x05A,
+                    0,
+                    [exguid(object_id), 1u32.to_le_bytes().to_vec()].concat(),
+                ),
+                node(0x01C, 0, vec![]),
+            ],
+        );
+
+        let manifests = file.append_list(
+            1,
+            vec![
+                node(0x00C, 0, exguid(space_id)),
+                node(0x010, 2, reference(revisions.0, revisions.1)),
+            ],
+        );
+
+        let root = file.append_list(
+            0x10,
+            vec![
+                node(0x004, 0, exguid(space_id)),
+                node(
+                    0x008,
+                    2,
+                    [reference(manifests.0, manifests.1), exguid(space_id)].concat(),
+                ),
+            ],
+        );
+
+        let log = file.append(
+            [
+                [1u32, 2, 2, 5, 3, 4, 0x10, 2, 1, 0]
+                    .iter()
+                    .flat_map(|v| v.to_le_bytes())
+                    .collect(),
+                reference(u64::MAX as usize, 0),
+            ]
+            .concat(),
+        );
+
+        let mut data = file.0;
+        data[0..16].copy_from_slice(&guid(guid!("7B5C52E4-D88C-4DA7-AEB1-5378D02996D3")));
+        data[48..64].copy_from_slice(&guid(guid!("109ADD3F-911B-49F5-A5D0-1791EDC8AED8")));
+        data[96..100].copy_from_slice(&1u32.to_le_bytes());
+        data[160..172].copy_from_slice(&reference(log.0, log.1));
+        data[172..184].copy_from_slice(&reference(root.0, root.1));
+
+        let store = parse_store(&data, StoreOptions::default()).unwrap();
+
+        assert_eq!(
+            store.schema_guid(),
+            guid!({1F937CB4-B26F-445F-B9F8-17E20160E461})
+        );
+
+        let root_id = store.data_root().content_root().unwrap();
+        assert_eq!(root_id, object_id);
+
+        let object = store.data_root().get_object(root_id).unwrap();
+        assert_eq!(object.id().0, 0x00020001);
+        assert!(matches!(
+            object.props().properties().get(PropertyId::new(0x14001234)),
+            Some(PropertyValue::U32(42))
+        ));
+    }
+}
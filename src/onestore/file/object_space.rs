@@ -0,0 +1,399 @@
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::PropertyType;
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::onestore::file::file_data_store::FileDataStore;
+use crate::onestore::file::file_node::FileNode;
+use crate::onestore::file::file_node_list::parse_file_node_list;
+use crate::onestore::file::global_id_table::GlobalIdTable;
+use crate::onestore::file::transaction_log::TransactionLog;
+use crate::onestore::mapping_table::MappingTable;
+use crate::onestore::object::Object;
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::revision::Revision;
+use crate::onestore::revision_role::RevisionRole;
+use crate::onestore::types::compact_id::CompactId;
+use crate::onestore::types::jcid::JcId;
+use crate::onestore::types::object_prop_set::ObjectPropSet;
+use crate::onestore::types::prop_set::PropertySet;
+use crate::onestore::types::property::{PropertyId, PropertyValue};
+use crate::onestore::StoreOptions;
+use crate::reader::Reader;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// The revision role of a revision containing an object space's default content.
+const DEFAULT_CONTENT_REVISION_ROLE: u32 = 1;
+
+/// A revision as declared by a revision manifest.
+///
+/// See \[MS-ONESTORE\] 2.1.8 and 2.1.9.
+struct RevisionManifest<'a> {
+    id: ExGuid,
+    dependent: Option<ExGuid>,
+    role: u32,
+    context: ExGuid,
+    roots: HashMap<RevisionRole, ExGuid>,
+    objects: HashMap<ExGuid, Object<'a>>,
+}
+
+/// Context shared while parsing all revision manifests of an object space.
+struct ParseContext<'a, 'b> {
+    file: &'a [u8],
+    log: &'b TransactionLog,
+    file_data: &'b FileDataStore<'a>,
+    options: &'b StoreOptions,
+
+    /// The types of all declared objects, used to resolve the types of object revisions.
+    jc_ids: HashMap<ExGuid, JcId>,
+}
+
+/// Parse an object space from its object space manifest list.
+///
+/// Like for FSSHTTPB packaged files, the object space is made up from the objects of the
+/// revision holding the default content and all the revisions it depends on.
+///
+/// See \[MS-ONESTORE\] 2.1.6 and 2.1.7.
+pub(crate) fn parse_object_space<'a>(
+    file: &'a [u8],
+    id: ExGuid,
+    manifest_list: ChunkReference,
+    log: &TransactionLog,
+    file_data: &FileDataStore<'a>,
+    options: &StoreOptions,
+) -> Result<ObjectSpace<'a>> {
+    // The last revision manifest list reference is the one that's in use
+    let revision_manifest_list = parse_file_node_list(file, manifest_list, log)?
+        .into_iter()
+        .rev()
+        .find_map(|node| match node {
+            FileNode::RevisionManifestListReference { list } => Some(list),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData("revision manifest list reference missing".into())
+        })?;
+
+    let mut context = ParseContext {
+        file,
+        log,
+        file_data,
+        options,
+        jc_ids: HashMap::new(),
+    };
+
+    let (revisions, role_declarations) =
+        parse_revision_manifest_list(revision_manifest_list, &mut context)?;
+
+    let (content_revision, context_id) = find_content_revision(&revisions, &role_declarations)?;
+
+    let mut revisions: HashMap<_, _> = revisions
+        .into_iter()
+        .map(|revision| (revision.id, revision))
+        .collect();
+
+    let mut chain = vec![];
+    let mut seen = HashSet::new();
+    let mut rev_id = Some(content_revision);
+
+    while let Some(id) = rev_id {
+        if !seen.insert(id) {
+            return Err(
+                ErrorKind::MalformedOneStoreData("cyclic revision dependency".into()).into(),
+            );
+        }
+
+        let revision = revisions.remove(&id).ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData("dependent revision not found".into())
+        })?;
+
+        rev_id = revision.dependent;
+        chain.push(Revision::new(revision.id, revision.roots, revision.objects));
+    }
+
+    Ok(ObjectSpace::new(id, context_id, chain))
+}
+
+/// Find the latest revision with the default content role and its context.
+fn find_content_revision(
+    revisions: &[RevisionManifest],
+    role_declarations: &[(ExGuid, u32, ExGuid)],
+) -> Result<(ExGuid, ExGuid)> {
+    let is_content_role =
+        |role: u32, context: ExGuid| role == DEFAULT_CONTENT_REVISION_ROLE && context.is_nil();
+
+    let declared = role_declarations
+        .iter()
+        .rev()
+        .filter(|(_, role, context)| is_content_role(*role, *context))
+        .find_map(|(id, _, _)| revisions.iter().find(|revision| revision.id == *id));
+
+    declared
+        .or_else(|| {
+            revisions
+                .iter()
+                .rev()
+                .find(|revision| is_content_role(revision.role, revision.context))
+        })
+        .or_else(|| revisions.last())
+        .map(|revision| (revision.id, revision.context))
+        .ok_or_else(|| {
+            ErrorKind::MalformedOneStoreData("object space has no revisions".into()).into()
+        })
+}
+
+#[allow(clippy::type_complexity)]
+fn parse_revision_manifest_list<'a>(
+    list: ChunkReference,
+    context: &mut ParseContext<'a, '_>,
+) -> Result<(Vec<RevisionManifest<'a>>, Vec<(ExGuid, u32, ExGuid)>)> {
+    let mut revisions = vec![];
+    let mut role_declarations = vec![];
+
+    let mut current: Option<RevisionManifest> = None;
+    let mut table = GlobalIdTable::default();
+    let mut previous_table = GlobalIdTable::default();
+
+    for node in parse_file_node_list(context.file, list, context.log)? {
+        match node {
+            FileNode::RevisionManifestStart {
+                id,
+                dependent,
+                role,
+                context: revision_context,
+                encoding,
+            } => {
+                if encoding != 0 {
+                    return Err(ErrorKind::MalformedOneStoreData(
+                        "encrypted revisions are not supported".into(),
+                    )
+                    .into());
+                }
+
+                table = GlobalIdTable::default();
+                current = Some(RevisionManifest {
+                    id,
+                    dependent: dependent.as_option(),
+                    role,
+                    context: revision_context,
+                    roots: HashMap::new(),
+                    objects: HashMap::new(),
+                });
+            }
+            FileNode::RevisionManifestEnd => {
+                let revision = current.take().ok_or_else(|| {
+                    ErrorKind::MalformedOneStoreData("unexpected revision manifest end".into())
+                })?;
+
+                revisions.push(revision);
+                previous_table = std::mem::take(&mut table);
+            }
+            FileNode::RevisionRoleDeclaration {
+                id,
+                role,
+                context: role_context,
+            } => {
+                role_declarations.push((id, role, role_context));
+            }
+            FileNode::ObjectGroupListReference { list } => {
+                let revision = current_revision(&mut current)?;
+                parse_object_group_list(list, revision, context)?;
+            }
+            FileNode::RootObjectReference { id, role } => {
+                let root = table.resolve(id)?;
+                let revision = current_revision(&mut current)?;
+                revision.roots.insert(RevisionRole::from_value(role)?, root);
+            }
+            FileNode::RootObjectReferenceExGuid { id, role } => {
+                let revision = current_revision(&mut current)?;
+                revision.roots.insert(RevisionRole::from_value(role)?, id);
+            }
+            FileNode::ObjectDataEncryptionKey => {
+                return Err(ErrorKind::MalformedOneStoreData(
+                    "encrypted sections are not supported".into(),
+                )
+                .into());
+            }
+            node => {
+                let revision = match current.as_mut() {
+                    Some(revision) => revision,
+                    None => continue,
+                };
+
+                parse_table_or_object(node, &mut table, &previous_table, revision, context)?;
+            }
+        }
+    }
+
+    Ok((revisions, role_declarations))
+}
+
+fn parse_object_group_list<'a>(
+    list: ChunkReference,
+    revision: &mut RevisionManifest<'a>,
+    context: &mut ParseContext<'a, '_>,
+) -> Result<()> {
+    let mut table = GlobalIdTable::default();
+    let previous_table = GlobalIdTable::default();
+
+    for node in parse_file_node_list(context.file, list, context.log)? {
+        parse_table_or_object(node, &mut table, &previous_table, revision, context)?;
+    }
+
+    Ok(())
+}
+
+/// Apply a global ID table or object declaration file node.
+///
+/// Other file nodes are ignored.
+fn parse_table_or_object<'a>(
+    node: FileNode,
+    table: &mut GlobalIdTable,
+    previous_table: &GlobalIdTable,
+    revision: &mut RevisionManifest<'a>,
+    context: &mut ParseContext<'a, '_>,
+) -> Result<()> {
+    match node {
+        FileNode::GlobalIdTableStart => *table = GlobalIdTable::default(),
+        FileNode::GlobalIdTableEntry { index, guid } => table.insert(index, guid),
+        FileNode::GlobalIdTableEntryMap { from, to } => {
+            table.copy_from(previous_table, from, to)?
+        }
+        FileNode::GlobalIdTableEntryCopy { from, count, to } => {
+            table.copy_range_from(previous_table, from, count, to)?
+        }
+        FileNode::ObjectDeclaration {
+            data,
+            id,
+            jc_id,
+            encoding,
+        } => {
+            if encoding != 0 {
+                return Err(ErrorKind::MalformedOneStoreData(
+                    "encrypted objects are not supported".into(),
+                )
+                .into());
+            }
+
+            let id = table.resolve(id)?;
+            context.options.count_object()?;
+            let object = parse_object(data, jc_id, revision.context, table, context)?;
+
+            context.jc_ids.insert(id, jc_id);
+            revision.objects.insert(id, object);
+        }
+        FileNode::ObjectRevision { data, id } => {
+            let id = table.resolve(id)?;
+            let jc_id = context.jc_ids.get(&id).copied().ok_or_else(|| {
+                ErrorKind::MalformedOneStoreData("object revision without declaration".into())
+            })?;
+
+            context.options.count_object()?;
+            let object = parse_object(data, jc_id, revision.context, table, context)?;
+            revision.objects.insert(id, object);
+        }
+        FileNode::ObjectDeclarationFileData {
+            id,
+            jc_id,
+            reference,
+            extension,
+        } => {
+            let id = table.resolve(id)?;
+            context.options.count_object()?;
+            let object =
+                parse_file_data_object(&reference, &extension, jc_id, revision.context, context);
+
+            context.jc_ids.insert(id, jc_id);
+            revision.objects.insert(id, object);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn parse_object<'a>(
+    data: ChunkReference,
+    jc_id: JcId,
+    context_id: ExGuid,
+    table: &GlobalIdTable,
+    context: &ParseContext<'a, '_>,
+) -> Result<Object<'a>> {
+    let props = ObjectPropSet::parse(&mut Reader::new(data.data(context.file)?))?;
+
+    let resolve = |ids: &[CompactId]| -> Result<Vec<(CompactId, ExGuid)>> {
+        ids.iter()
+            .map(|id| Ok((*id, table.resolve(*id)?)))
+            .collect()
+    };
+
+    let mapping_objects = resolve(props.object_ids())?;
+    let mapping_contexts = resolve(props.context_ids())?;
+    let mapping_object_spaces = resolve(props.object_space_ids())?
+        .into_iter()
+        .map(|(cid, id)| (cid, CellId(ExGuid::nil(), id)));
+
+    let mapping = MappingTable::from_entries(
+        mapping_objects.into_iter().chain(mapping_contexts),
+        mapping_object_spaces,
+    );
+
+    Ok(Object {
+        context_id,
+        jc_id,
+        props,
+        file_data: None,
+        mapping,
+    })
+}
+
+/// Create an object for a file data object declaration.
+///
+/// Unlike FSSHTTPB packaged files, the revision store file format stores the file
+/// extension as part of the declaration. We add it as a `PictureFileExtension` property so
+/// that the object looks the same for both formats.
+fn parse_file_data_object<'a>(
+    reference: &str,
+    extension: &str,
+    jc_id: JcId,
+    context_id: ExGuid,
+    context: &ParseContext<'a, '_>,
+) -> Object<'a> {
+    let extension = extension
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+
+    let properties = PropertySet::from_values(vec![(
+        PropertyId::new(PropertyType::PictureFileExtension as u32),
+        PropertyValue::Vec(extension),
+    )]);
+
+    let props = ObjectPropSet {
+        object_ids: vec![],
+        object_space_ids: vec![],
+        context_ids: vec![],
+        properties,
+    };
+
+    Object {
+        context_id,
+        jc_id,
+        props,
+        file_data: if context.options.load_payloads() {
+            context.file_data.get(reference).map(Cow::Borrowed)
+        } else {
+            None
+        },
+        mapping: MappingTable::from_entries(std::iter::empty(), std::iter::empty()),
+    }
+}
+
+fn current_revision<'a, 'b>(
+    current: &'b mut Option<RevisionManifest<'a>>,
+) -> Result<&'b mut RevisionManifest<'a>> {
+    current.as_mut().ok_or_else(|| {
+        ErrorKind::MalformedOneStoreData("file node outside of revision manifest".into()).into()
+    })
+}
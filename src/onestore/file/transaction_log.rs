@@ -0,0 +1,112 @@
+use crate::errors::{ErrorKind, Result};
+use crate::onestore::file::chunk_reference::ChunkReference;
+use crate::reader::Reader;
+use std::collections::{HashMap, HashSet};
+
+// The source ID marking the end of a transaction
+const SENTINEL_SOURCE_ID: u32 = 0x00000001;
+
+/// A revision store file's transaction log.
+///
+/// The transaction log records how many file nodes of each file node list have been
+/// committed. We only keep the final node count for each list.
+///
+/// See \[MS-ONESTORE\] 2.3.3.
+#[derive(Debug)]
+pub(crate) struct TransactionLog {
+    node_counts: HashMap<u32, usize>,
+}
+
+impl TransactionLog {
+    pub(crate) fn parse(
+        file: &[u8],
+        reference: ChunkReference,
+        transaction_count: u32,
+    ) -> Result<TransactionLog> {
+        let mut node_counts = HashMap::new();
+        let mut transactions = 0;
+        let mut next = Some(reference);
+        let mut visited = HashSet::new();
+
+        // Node counts are only applied once their transaction has been committed
+        let mut pending = vec![];
+
+        while let Some(reference) = next.take() {
+            if transactions >= transaction_count {
+                break;
+            }
+
+            if !visited.insert(reference.stp) {
+                return Err(ErrorKind::MalformedOneStoreData(
+                    "transaction log fragments form a cycle".into(),
+                )
+                .into());
+            }
+
+            let fragment = reference.data(file)?;
+            if fragment.len() < 12 {
+                return Err(ErrorKind::MalformedOneStoreData(
+                    "transaction log fragment is too small".into(),
+                )
+                .into());
+            }
+
+            let entry_count = (fragment.len() - 12) / 8;
+            let mut reader = Reader::new(fragment);
+
+            for _ in 0..entry_count {
+                let source_id = reader.get_u32()?;
+                let value = reader.get_u32()?;
+
+                if source_id == SENTINEL_SOURCE_ID {
+                    node_counts.extend(pending.drain(..));
+                    transactions += 1;
+
+                    if transactions >= transaction_count {
+                        break;
+                    }
+                } else if source_id != 0 {
+                    pending.push((source_id, value as usize));
+                }
+            }
+
+            let next_fragment =
+                ChunkReference::parse_64x32(&mut Reader::new(&fragment[fragment.len() - 12..]))?;
+
+            if !next_fragment.is_nil() && !next_fragment.is_zero() {
+                next = Some(next_fragment);
+            }
+        }
+
+        Ok(TransactionLog { node_counts })
+    }
+
+    /// The number of committed file nodes in a file node list.
+    ///
+    /// Returns `None` if the transaction log doesn't have any information about the list.
+    pub(crate) fn committed_node_count(&self, list_id: u32) -> Option<usize> {
+        self.node_counts.get(&list_id).copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::errors::ErrorKind;
+    use crate::onestore::file::chunk_reference::ChunkReference;
+    use crate::onestore::file::transaction_log::TransactionLog;
+
+    #[test]
+    fn test_reject_cyclic_log() {
+        // A fragment with a single entry that points back to itself
+        let mut file = vec![];
+        file.extend_from_slice(&5u32.to_le_bytes());
+        file.extend_from_slice(&1u32.to_le_bytes());
+        file.extend_from_slice(&0u64.to_le_bytes());
+        file.extend_from_slice(&20u32.to_le_bytes());
+
+        let reference = ChunkReference { stp: 0, cb: 20 };
+        let err = TransactionLog::parse(&file, reference, 1).unwrap_err();
+
+        assert!(matches!(err.kind(), ErrorKind::MalformedOneStoreData(_)));
+    }
+}
@@ -0,0 +1,463 @@
+//! Convert FSSHTTPB packaged stores to revision store files.
+//!
+//! The test samples are FSSHTTPB packaged files. To check that both file formats result in
+//! the same notebook, the tests convert the samples to the revision store format and
+//! compare the parsed notebooks. Only the parts of the file format used by the parser are
+//! written: one object group per revision, a single transaction and no free chunks.
+//!
+//! No files saved by the OneNote desktop apps are checked in yet. The integration tests
+//! parse and snapshot any placed in `tests/samples/native`.
+
+use crate::errors::{ErrorKind, Result};
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::PropertyType;
+use crate::onestore::object::Object;
+use crate::onestore::object_space::ObjectSpace;
+use crate::onestore::types::compact_id::CompactId;
+use crate::onestore::types::object_prop_set::ObjectPropSet;
+use crate::onestore::types::property::PropertyValue;
+use crate::onestore::OneStore;
+use crate::shared::guid::Guid;
+use itertools::Itertools;
+use std::convert::TryFrom;
+
+const FILE_NODE_LIST_HEADER_MAGIC: u64 = 0xA4567AB1F5F7F4C4;
+const FILE_NODE_LIST_FOOTER_MAGIC: u64 = 0x8BC215C38233BA4B;
+
+/// Write a packaged store as a revision store file.
+pub(crate) fn write_revision_store(store: &OneStore) -> Result<Vec<u8>> {
+    store.load_object_spaces()?;
+
+    let mut file = FileWriter::new();
+
+    // Packaged files store an object space once per context while revision store files
+    // store the revisions of all contexts in the same object space. The data root's
+    // context is the default context.
+    let default_context = store.data_root_id().0;
+
+    let mut spaces: Vec<_> = store.object_spaces().collect();
+    spaces.push((store.data_root_id(), store.data_root()));
+    spaces.sort_by_key(|(id, _)| {
+        (
+            id.1 != store.data_root_id().1,
+            id.1.guid.to_string(),
+            id.1.value,
+            id.0 == default_context,
+            id.0.guid.to_string(),
+        )
+    });
+
+    let mut root_nodes = vec![node(0x004, 0, exguid(store.data_root_id().1))];
+    let mut file_data_nodes = vec![];
+
+    for (id, contexts) in &spaces.into_iter().group_by(|(id, _)| id.1) {
+        let contexts: Vec<_> = contexts
+            .map(|(CellId(context, _), space)| {
+                let context = context.as_option().filter(|c| *c != default_context);
+                (context, space)
+            })
+            .collect();
+
+        let list = file.write_object_space(id, &contexts, &mut file_data_nodes)?;
+        root_nodes.push(node(0x008, 2, [list, exguid(id)].concat()));
+    }
+
+    if !file_data_nodes.is_empty() {
+        let list = file.append_list(file_data_nodes);
+        root_nodes.push(node(0x090, 2, list));
+    }
+
+    let root = file.append_list(root_nodes);
+
+    // A single transaction that adds all file nodes
+    let log: Vec<u8> = file
+        .transactions
+        .iter()
+        .chain([(1, 0)].iter())
+        .flat_map(|(list_id, count)| [list_id.to_le_bytes(), count.to_le_bytes()].concat())
+        .chain(reference(u64::MAX, 0))
+        .collect();
+    let log = file.append(&log);
+
+    let file_type =
+        if store.schema_guid() == guid!({1F937CB4-B26F-445F-B9F8-17E20160E461}) {
+            guid!({7B5C52E4-D88C-4DA7-AEB1-5378D02996D3})
+        } else {
+            guid!("43FF2FA1-EFD9-4C76-9EE2-10EA5722765F")
+        };
+
+    let mut data = file.data;
+    data[0..16].copy_from_slice(&guid(file_type));
+    data[16..32].copy_from_slice(&guid(store.file_identity()));
+    data[48..64].copy_from_slice(&guid(guid!("109ADD3F-911B-49F5-A5D0-1791EDC8AED8")));
+    data[96..100].copy_from_slice(&1u32.to_le_bytes());
+    data[160..172].copy_from_slice(&log);
+    data[172..184].copy_from_slice(&root);
+
+    Ok(data)
+}
+
+struct FileWriter {
+    data: Vec<u8>,
+
+    /// The list ID and the number of file nodes of each file node list.
+    transactions: Vec<(u32, u32)>,
+}
+
+impl FileWriter {
+    fn new() -> FileWriter {
+        FileWriter {
+            data: vec![0; 1024],
+            transactions: vec![],
+        }
+    }
+
+    /// Append a chunk to the file and return a reference to it.
+    fn append(&mut self, data: &[u8]) -> Vec<u8> {
+        let offset = self.data.len();
+        self.data.extend_from_slice(data);
+
+        reference(offset as u64, data.len() as u32)
+    }
+
+    /// Append a file node list with a single fragment and return a reference to it.
+    fn append_list(&mut self, nodes: Vec<Vec<u8>>) -> Vec<u8> {
+        // List ID 1 is reserved for the transaction log's sentinel entry
+        let list_id = self.transactions.len() as u32 + 0x10;
+        self.transactions.push((list_id, nodes.len() as u32));
+
+        let mut data = vec![];
+        data.extend_from_slice(&FILE_NODE_LIST_HEADER_MAGIC.to_le_bytes());
+        data.extend_from_slice(&list_id.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend(nodes.into_iter().flatten());
+        data.extend(reference(u64::MAX, 0));
+        data.extend_from_slice(&FILE_NODE_LIST_FOOTER_MAGIC.to_le_bytes());
+
+        self.append(&data)
+    }
+
+    /// Write an object space and return a reference to its object space manifest list.
+    ///
+    /// The revisions of each context are written from the base revision up, each
+    /// depending on the previous one. Revisions of the default context are written last.
+    fn write_object_space(
+        &mut self,
+        id: ExGuid,
+        contexts: &[(Option<ExGuid>, &ObjectSpace)],
+        file_data_nodes: &mut Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>> {
+        let mut revision_nodes = vec![];
+
+        for (context, space) in contexts {
+            let mut dependent = ExGuid::nil();
+
+            for revision in space.revisions().iter().rev() {
+                let mut table = IdTable::default();
+                let mut declarations = vec![];
+
+                let mut objects: Vec<_> = revision.objects().iter().collect();
+                objects.sort_by_key(|(id, _)| (id.guid.to_string(), id.value));
+
+                for (id, object) in objects {
+                    let declaration =
+                        self.write_object(*id, object, &mut table, file_data_nodes)?;
+                    declarations.push(declaration);
+                }
+
+                let group = [
+                    vec![node(0x022, 0, vec![])],
+                    table.nodes(),
+                    vec![node(0x028, 0, vec![])],
+                    declarations,
+                ]
+                .concat();
+                let group = self.append_list(group);
+
+                let start = [
+                    exguid(revision.id()),
+                    exguid(dependent),
+                    1u32.to_le_bytes().to_vec(),
+                    vec![0; 2],
+                ]
+                .concat();
+
+                revision_nodes.push(match context {
+                    Some(context) => node(0x01F, 0, [start, exguid(*context)].concat()),
+                    None => node(0x01E, 0, start),
+                });
+                revision_nodes.push(node(0x0B0, 2, group));
+
+                let mut roots: Vec<_> = revision.roots().iter().collect();
+                roots.sort_by_key(|(role, _)| role.as_exguid().value);
+
+                for (role, id) in roots {
+                    revision_nodes.push(node(
+                        0x05A,
+                        0,
+                        [exguid(*id), role.as_exguid().value.to_le_bytes().to_vec()].concat(),
+                    ));
+                }
+
+                revision_nodes.push(node(0x01C, 0, vec![]));
+                dependent = revision.id();
+            }
+        }
+
+        let revisions = self.append_list(revision_nodes);
+
+        Ok(self.append_list(vec![node(0x00C, 0, exguid(id)), node(0x010, 2, revisions)]))
+    }
+
+    /// Write an object's data and return its object declaration file node.
+    fn write_object(
+        &mut self,
+        id: ExGuid,
+        object: &Object,
+        table: &mut IdTable,
+        file_data_nodes: &mut Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>> {
+        let mut compact_id = vec![];
+        table.compact_id(id)?.write(&mut compact_id)?;
+
+        let jc_id = object.id().0.to_le_bytes().to_vec();
+
+        if let Some(data) = object.file_data() {
+            let data_id = Guid(uuid::Uuid::from_u128(
+                0x1000 + file_data_nodes.len() as u128,
+            ));
+
+            let chunk = [
+                guid(guid!({BDE316E7-2665-4511-A4C4-8D4D0B7A9EAC})),
+                (data.len() as u64).to_le_bytes().to_vec(),
+                vec![0; 12],
+                data.to_vec(),
+            ]
+            .concat();
+            let chunk = self.append(&chunk);
+            file_data_nodes.push(node(0x094, 2, [chunk, guid(data_id)].concat()));
+
+            let extension = match object.props().get(PropertyType::PictureFileExtension) {
+                Some(PropertyValue::Vec(extension)) => extension.clone(),
+                _ => vec![],
+            };
+
+            return Ok(node(
+                0x072,
+                0,
+                [
+                    compact_id,
+                    jc_id,
+                    vec![0],
+                    string(&format!("<ifndf>{{{}}}", data_id)),
+                    ((extension.len() / 2) as u32).to_le_bytes().to_vec(),
+                    extension,
+                ]
+                .concat(),
+            ));
+        }
+
+        let props = rewrite_props(object, table)?;
+        let mut data = vec![];
+        props.write(&mut data)?;
+        let data = self.append(&data);
+
+        Ok(node(
+            0x0A4,
+            1,
+            [data, compact_id, jc_id, vec![0, 1]].concat(),
+        ))
+    }
+}
+
+/// Replace the object's `CompactId`s by references to the object group's global ID table.
+fn rewrite_props(object: &Object, table: &mut IdTable) -> Result<ObjectPropSet> {
+    let props = object.props();
+    let object_count = props.object_ids().len();
+
+    let mut resolve = |ids: &[CompactId], offset: usize| -> Result<Vec<CompactId>> {
+        ids.iter()
+            .enumerate()
+            .map(|(i, cid)| {
+                let id = object
+                    .mapping()
+                    .get_object(offset + i, *cid)
+                    .ok_or_else(|| ErrorKind::MalformedOneStoreData("object not found".into()))?;
+
+                table.compact_id(id)
+            })
+            .collect()
+    };
+
+    let object_ids = resolve(props.object_ids(), 0)?;
+    let context_ids = resolve(props.context_ids(), object_count)?;
+
+    let object_space_ids = props
+        .object_space_ids()
+        .iter()
+        .enumerate()
+        .map(|(i, cid)| {
+            let CellId(_, id) = object
+                .mapping()
+                .get_object_space(i, *cid)
+                .ok_or_else(|| ErrorKind::MalformedOneStoreData("object space not found".into()))?;
+
+            table.compact_id(id)
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(ObjectPropSet {
+        object_ids,
+        object_space_ids,
+        context_ids,
+        properties: props.properties().clone(),
+    })
+}
+
+/// The global ID table of an object group.
+#[derive(Default)]
+struct IdTable {
+    guids: Vec<Guid>,
+}
+
+impl IdTable {
+    fn compact_id(&mut self, id: ExGuid) -> Result<CompactId> {
+        if id.is_nil() {
+            return Ok(CompactId::new(0, 0));
+        }
+
+        let n = u8::try_from(id.value).map_err(|_| {
+            ErrorKind::MalformedOneStoreData("ExGuid value doesn't fit a CompactId".into())
+        })?;
+
+        // Index 0 is left unused so that `CompactId(0, 0)` always means nil
+        let index = match self.guids.iter().position(|guid| *guid == id.guid) {
+            Some(index) => index,
+            None => {
+                self.guids.push(id.guid);
+                self.guids.len() - 1
+            }
+        };
+
+        Ok(CompactId::new(n, index as u32 + 1))
+    }
+
+    fn nodes(&self) -> Vec<Vec<u8>> {
+        self.guids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let index = i as u32 + 1;
+                node(0x024, 0, [index.to_le_bytes().to_vec(), guid(*id)].concat())
+            })
+            .collect()
+    }
+}
+
+fn node(id: u32, base_type: u32, data: Vec<u8>) -> Vec<u8> {
+    let header = id | ((data.len() as u32 + 4) << 10) | (base_type << 27);
+
+    [header.to_le_bytes().to_vec(), data].concat()
+}
+
+fn guid(guid: Guid) -> Vec<u8> {
+    guid.0.to_bytes_le().to_vec()
+}
+
+fn exguid(id: ExGuid) -> Vec<u8> {
+    [guid(id.guid), id.value.to_le_bytes().to_vec()].concat()
+}
+
+fn reference(stp: u64, cb: u32) -> Vec<u8> {
+    [stp.to_le_bytes().to_vec(), cb.to_le_bytes().to_vec()].concat()
+}
+
+fn string(value: &str) -> Vec<u8> {
+    let data: Vec<u8> = value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+
+    [((data.len() / 2) as u32).to_le_bytes().to_vec(), data].concat()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::errors::Result;
+    use crate::onenote::resolver::{NotebookResolver, ResolvedEntry};
+    use crate::onenote::Parser;
+    use crate::onestore::file::is_revision_store;
+    use crate::onestore::file::writer::write_revision_store;
+    use crate::onestore::{parse_store, StoreOptions};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn convert(path: &Path) -> Vec<u8> {
+        let data = fs::read(path).unwrap();
+        let store = parse_store(&data, StoreOptions::default()).unwrap();
+
+        let data = write_revision_store(&store).unwrap();
+        assert!(is_revision_store(&data));
+
+        data
+    }
+
+    /// Resolves the notebook's entries to the converted sample files.
+    struct ConvertingResolver {
+        dir: PathBuf,
+    }
+
+    impl NotebookResolver for ConvertingResolver {
+        fn resolve(&mut self, path: &[String]) -> Result<Option<ResolvedEntry>> {
+            let mut file = self.dir.clone();
+            file.extend(path);
+
+            if !file.is_file() {
+                return Ok(None);
+            }
+
+            Ok(Some(ResolvedEntry::Section(convert(&file))))
+        }
+    }
+
+    #[test]
+    fn test_parse_converted_section() {
+        for file in [
+            "tests/samples/New Section 1.one",
+            "tests/samples/New Section Group/New Section 1.one",
+            "tests/samples/New Section Group/New Section 2.one",
+            "tests/samples/OneNote_RecycleBin/OneNote_DeletedPages.one",
+        ] {
+            let path = PathBuf::from(file);
+            let name = path.file_name().unwrap().to_string_lossy();
+
+            let mut parser = Parser::new();
+            let packaged = parser.parse_section(&path).unwrap();
+            let native = parser
+                .parse_section_from_bytes(&convert(&path), &name)
+                .unwrap();
+
+            assert!(!packaged.page_series().is_empty());
+            assert_eq!(format!("{:?}", native), format!("{:?}", packaged));
+        }
+    }
+
+    #[test]
+    fn test_parse_converted_notebook() {
+        for dir in ["tests/samples", "tests/samples/New Section Group"] {
+            let dir = PathBuf::from(dir);
+            let path = dir.join("Open Notebook.onetoc2");
+
+            let mut parser = Parser::new();
+            let packaged = parser.parse_notebook(&path).unwrap();
+            let native = parser
+                .parse_notebook_from_bytes(
+                    &convert(&path),
+                    &path.to_string_lossy(),
+                    &mut ConvertingResolver { dir },
+                )
+                .unwrap();
+
+            assert!(!packaged.entries().is_empty());
+            assert_eq!(format!("{:?}", native), format!("{:?}", packaged));
+        }
+    }
+}
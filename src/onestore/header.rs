@@ -1,6 +1,8 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data_element::object_group::{ObjectGroup, ObjectGroupData};
 use crate::one::property::PropertyType;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::types::jcid::JcId;
 use crate::onestore::types::object_prop_set::ObjectPropSet;
 use crate::onestore::types::property::PropertyValue;
 use crate::reader::Reader;
@@ -12,6 +14,7 @@ use crate::shared::guid::Guid;
 ///
 /// [\[MS-ONESTORE\] 2.7.2]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/07a5dc4d-0d97-4a4c-ab69-aa7957d7115c
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct StoreHeader {
     file_identity: Guid,
     ancestor_identity: Guid,
@@ -20,6 +23,24 @@ pub(crate) struct StoreHeader {
 }
 
 impl StoreHeader {
+    pub(crate) fn new(
+        file_identity: Guid,
+        ancestor_identity: Guid,
+        last_code_version_that_wrote_to_it: Option<u32>,
+        file_name_crc: u32,
+    ) -> StoreHeader {
+        StoreHeader {
+            file_identity,
+            ancestor_identity,
+            last_code_version_that_wrote_to_it,
+            file_name_crc,
+        }
+    }
+
+    pub(crate) fn file_identity(&self) -> Guid {
+        self.file_identity
+    }
+
     pub(crate) fn parse(data: &ObjectGroup) -> Result<StoreHeader> {
         let (_, object_data) = data
             .declarations
@@ -41,7 +62,7 @@ impl StoreHeader {
 
         let file_identity = prop_set
             .get(PropertyType::FileIdentityGuid)
-            .map(|value| StoreHeader::parse_guid(value))
+            .map(StoreHeader::parse_guid)
             .transpose()?
             .ok_or_else(|| {
                 ErrorKind::MalformedOneStoreData("FileIdentityGuid prop missing".into())
@@ -49,7 +70,7 @@ impl StoreHeader {
 
         let ancestor_identity = prop_set
             .get(PropertyType::FileAncestorIdentityGuid)
-            .map(|value| StoreHeader::parse_guid(value))
+            .map(StoreHeader::parse_guid)
             .transpose()?
             .ok_or_else(|| {
                 ErrorKind::MalformedOneStoreData("FileAncestorIdentityGuid prop missing".into())
@@ -57,12 +78,12 @@ impl StoreHeader {
 
         let last_code_version_that_wrote_to_it = prop_set
             .get(PropertyType::FileLastCodeVersionThatWroteToIt)
-            .map(|value| StoreHeader::parse_u32(value))
+            .map(StoreHeader::parse_u32)
             .transpose()?;
 
         let file_name_crc = prop_set
             .get(PropertyType::FileNameCrc)
-            .map(|value| StoreHeader::parse_u32(value))
+            .map(StoreHeader::parse_u32)
             .transpose()?
             .ok_or_else(|| ErrorKind::MalformedOneStoreData("FileNameCRC prop missing".into()))?;
 
@@ -74,6 +95,33 @@ impl StoreHeader {
         })
     }
 
+    /// Build the header cell's object.
+    pub(crate) fn build(&self) -> ObjectBuilder {
+        let mut object = ObjectBuilder::new(JcId(0x00020001));
+
+        object.set(
+            PropertyType::FileNameCrc,
+            PropertyValue::U32(self.file_name_crc),
+        );
+        object.set(
+            PropertyType::FileIdentityGuid,
+            PropertyValue::Vec(self.file_identity.0.to_bytes_le().to_vec()),
+        );
+        object.set(
+            PropertyType::FileAncestorIdentityGuid,
+            PropertyValue::Vec(self.ancestor_identity.0.to_bytes_le().to_vec()),
+        );
+
+        if let Some(version) = self.last_code_version_that_wrote_to_it {
+            object.set(
+                PropertyType::FileLastCodeVersionThatWroteToIt,
+                PropertyValue::U32(version),
+            );
+        }
+
+        object
+    }
+
     fn parse_guid(value: &PropertyValue) -> Result<Guid> {
         if let PropertyValue::Vec(data) = &value {
             Ok(Guid::parse(&mut Reader::new(data.as_slice()))?)
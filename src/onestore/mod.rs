@@ -1,28 +1,109 @@
 use crate::errors::{ErrorKind, Result};
 use crate::fsshttpb::data::cell_id::CellId;
-use crate::fsshttpb::data_element::storage_index::StorageIndex;
 use crate::fsshttpb::data_element::storage_manifest::StorageManifest;
 use crate::fsshttpb::packaging::OneStorePackaging;
 use crate::onestore::header::StoreHeader;
 use crate::onestore::object_space::ObjectSpace;
-use crate::onestore::revision::Revision;
+use crate::reader::Reader;
 use crate::shared::guid::Guid;
+use crate::Writer;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
 
+pub(crate) mod file;
 pub(crate) mod header;
 pub(crate) mod mapping_table;
 pub(crate) mod object;
+pub(crate) mod object_builder;
 pub(crate) mod object_space;
 pub(crate) mod revision;
 mod revision_role;
+pub(crate) mod store_builder;
 pub(crate) mod types;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 pub(crate) struct OneStore<'a> {
     schema: Guid,
     header: StoreHeader,
+    data_root_id: CellId,
     data_root: ObjectSpace<'a>,
-    object_spaces: HashMap<CellId, ObjectSpace<'a>>,
+
+    /// All object spaces except the data root, parsed when they are first accessed.
+    object_spaces: HashMap<CellId, OnceLock<ObjectSpace<'a>>>,
+    source: StoreSource<'a>,
+    options: StoreOptions,
+}
+
+/// Options and limits applied while parsing the objects of a store.
+#[derive(Debug)]
+pub(crate) struct StoreOptions {
+    /// The file name used in error messages.
+    file: String,
+    load_payloads: bool,
+    max_object_count: Option<usize>,
+
+    /// The number of objects parsed so far, including the lazily parsed object spaces.
+    object_count: AtomicUsize,
+}
+
+impl StoreOptions {
+    pub(crate) fn new(file: &str) -> StoreOptions {
+        StoreOptions {
+            file: file.to_string(),
+            load_payloads: true,
+            max_object_count: None,
+            object_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set whether the file data of objects is loaded.
+    ///
+    /// If disabled, objects don't have any file data and the file's blobs are never looked
+    /// up.
+    pub(crate) fn with_payloads(mut self, load_payloads: bool) -> StoreOptions {
+        self.load_payloads = load_payloads;
+        self
+    }
+
+    /// Set the maximum number of objects parsed from the store.
+    pub(crate) fn with_max_object_count(mut self, max_object_count: Option<usize>) -> StoreOptions {
+        self.max_object_count = max_object_count;
+        self
+    }
+
+    pub(crate) fn load_payloads(&self) -> bool {
+        self.load_payloads
+    }
+
+    /// Count an object before parsing it and fail if the store exceeds the object limit.
+    pub(crate) fn count_object(&self) -> Result<()> {
+        let count = self.object_count.fetch_add(1, Ordering::Relaxed) + 1;
+
+        match self.max_object_count {
+            Some(max) if count > max => Err(ErrorKind::TooManyObjects {
+                file: self.file.clone(),
+                count,
+                max,
+            }
+            .into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Default for StoreOptions {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+/// The file a store's object spaces are parsed from.
+#[derive(Debug)]
+enum StoreSource<'a> {
+    Packaging(Box<OneStorePackaging<'a>>),
+    File(file::RevisionStoreFile<'a>),
 }
 
 impl<'a> OneStore<'a> {
@@ -30,16 +111,97 @@ impl<'a> OneStore<'a> {
         self.schema
     }
 
-    pub(crate) fn data_root(&'a self) -> &'a ObjectSpace {
+    /// The file name the store was parsed from.
+    pub(crate) fn file(&self) -> &str {
+        &self.options.file
+    }
+
+    pub(crate) fn file_identity(&self) -> Guid {
+        self.header.file_identity()
+    }
+
+    pub(crate) fn data_root(&self) -> &ObjectSpace<'a> {
         &self.data_root
     }
 
-    pub(crate) fn object_space(&'a self, space_id: CellId) -> Option<&'a ObjectSpace<'a>> {
-        self.object_spaces.get(&space_id)
+    pub(crate) fn data_root_id(&self) -> CellId {
+        self.data_root_id
+    }
+
+    /// All object spaces except the data root that have been parsed so far.
+    ///
+    /// Use [`OneStore::load_object_spaces()`] to parse all object spaces first.
+    pub(crate) fn object_spaces(&self) -> impl Iterator<Item = (CellId, &ObjectSpace<'a>)> {
+        self.object_spaces
+            .iter()
+            .filter_map(|(id, space)| Some((*id, space.get()?)))
+    }
+
+    /// Look up an object space, parsing it if it hasn't been parsed yet.
+    ///
+    /// Returns `None` if the store doesn't contain the object space.
+    pub(crate) fn object_space(&self, space_id: CellId) -> Result<Option<&ObjectSpace<'a>>> {
+        let cell = match self.object_spaces.get(&space_id) {
+            Some(cell) => cell,
+            None => return Ok(None),
+        };
+
+        if let Some(space) = cell.get() {
+            return Ok(Some(space));
+        }
+
+        let space = match &self.source {
+            StoreSource::Packaging(packaging) => {
+                parse_object_space(space_id, packaging, &self.options)?
+            }
+            StoreSource::File(file) => file.parse_object_space(space_id, &self.options)?,
+        };
+
+        Ok(Some(cell.get_or_init(|| space)))
+    }
+
+    /// Parse all object spaces that haven't been parsed yet.
+    pub(crate) fn load_object_spaces(&self) -> Result<()> {
+        for id in self.object_spaces.keys() {
+            self.object_space(*id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the store as a FSSHTTPB packaged file.
+    ///
+    /// This writes the store's data elements as they were parsed so the resulting file is
+    /// identical to the file the store was parsed from.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        match &self.source {
+            StoreSource::Packaging(packaging) => packaging.write(writer),
+            StoreSource::File(_) => Err(ErrorKind::Unsupported(
+                "writing revision store files is not supported".into(),
+            )
+            .into()),
+        }
+    }
+}
+
+/// Parse a OneStore file.
+///
+/// Both FSSHTTPB packaged files and revision store files (as stored by the OneNote desktop
+/// applications) are supported. Only the data root object space is parsed right away, the
+/// other object spaces are parsed when they are first accessed. The object limit applies
+/// to the objects of all object spaces, so it's checked again whenever an object space is
+/// parsed on demand.
+pub(crate) fn parse_store(data: &[u8], options: StoreOptions) -> Result<OneStore<'_>> {
+    if file::is_revision_store(data) {
+        return file::parse_store(data, options);
     }
+
+    let packaging = OneStorePackaging::parse(&mut Reader::new(data))?;
+
+    parse_packaged_store(packaging, options)
 }
 
-pub(crate) fn parse_store(package: &OneStorePackaging) -> Result<OneStore> {
+fn parse_packaged_store(package: OneStorePackaging, options: StoreOptions) -> Result<OneStore> {
     let mut parsed_object_spaces = HashSet::new();
 
     // [ONESTORE] 2.7.1: Parse storage manifest
@@ -63,7 +225,7 @@ pub(crate) fn parse_store(package: &OneStorePackaging) -> Result<OneStore> {
     // [ONESTORE] 2.7.2: Parse header cell
     let header_cell = package
         .data_element_package
-        .find_objects(header_cell_mapping_id, &storage_index)?
+        .find_objects(header_cell_mapping_id, storage_index)?
         .into_iter()
         .next()
         .ok_or_else(|| {
@@ -74,77 +236,69 @@ pub(crate) fn parse_store(package: &OneStorePackaging) -> Result<OneStore> {
 
     parsed_object_spaces.insert(header_cell_id);
 
-    // FIXME: document revision cache
-    let mut revision_cache = HashMap::new();
-
     // Parse data root
 
     let data_root_cell_id = find_data_root_cell_id(storage_manifest)?;
-    let (_, data_root) = parse_object_space(
-        data_root_cell_id,
-        storage_index,
-        &package,
-        &mut revision_cache,
-    )?;
+    let data_root = parse_object_space(data_root_cell_id, &package, &options)?;
 
     parsed_object_spaces.insert(data_root_cell_id);
 
-    // Parse other object spaces
-
-    let mut object_spaces = HashMap::new();
+    // Collect the other object spaces to parse them on demand
 
-    for mapping in storage_index.cell_mappings.values() {
-        if mapping.id.is_nil() {
-            continue;
-        }
-
-        if parsed_object_spaces.contains(&mapping.cell_id) {
-            continue;
-        }
-
-        let (id, group) = parse_object_space(
-            mapping.cell_id,
-            storage_index,
-            &package,
-            &mut revision_cache,
-        )?;
-        object_spaces.insert(id, group);
-    }
+    let object_spaces = storage_index
+        .cell_mappings
+        .values()
+        .filter(|mapping| !mapping.id.is_nil())
+        .filter(|mapping| !parsed_object_spaces.contains(&mapping.cell_id))
+        .map(|mapping| (mapping.cell_id, OnceLock::new()))
+        .collect();
 
     Ok(OneStore {
         schema: storage_manifest.id,
         header,
+        data_root_id: data_root_cell_id,
         data_root,
         object_spaces,
+        source: StoreSource::Packaging(Box::new(package)),
+        options,
     })
 }
 
-fn parse_object_space<'a, 'b>(
+fn parse_object_space<'a>(
     cell_id: CellId,
-    storage_index: &'a StorageIndex,
-    package: &'a OneStorePackaging,
-    revision_cache: &'b mut HashMap<CellId, Revision<'a>>,
-) -> Result<(CellId, ObjectSpace<'a>)> {
+    package: &OneStorePackaging<'a>,
+    options: &StoreOptions,
+) -> Result<ObjectSpace<'a>> {
+    let storage_index = package
+        .data_element_package
+        .find_storage_index()
+        .ok_or_else(|| ErrorKind::MalformedOneStoreData("storage index is missing".into()))?;
     let mapping = storage_index
         .cell_mappings
         .get(&cell_id)
         .ok_or_else(|| ErrorKind::MalformedOneStoreData("cell mapping not found".into()))?;
 
-    ObjectSpace::parse(mapping, storage_index, package, revision_cache)
+    // Revisions are cached by their ID while following the revision chain
+    let mut revision_cache = HashMap::new();
+    let (_, space) = ObjectSpace::parse(
+        mapping,
+        storage_index,
+        package,
+        &mut revision_cache,
+        options,
+    )?;
+
+    Ok(space)
 }
 
 fn find_header_cell_id(manifest: &StorageManifest) -> Result<CellId> {
     manifest
-        .roots
-        .get(&exguid!({{1A5A319C-C26B-41AA-B9C5-9BD8C44E07D4}, 1}))
-        .copied()
+        .find_root(exguid!({{1A5A319C-C26B-41AA-B9C5-9BD8C44E07D4}, 1}))
         .ok_or_else(|| ErrorKind::MalformedOneStoreData("no header cell root".into()).into())
 }
 
 fn find_data_root_cell_id(manifest: &StorageManifest) -> Result<CellId> {
     manifest
-        .roots
-        .get(&exguid!({{84DEFAB9-AAA3-4A0D-A3A8-520C77AC7073}, 2}))
-        .copied()
+        .find_root(exguid!({{84DEFAB9-AAA3-4A0D-A3A8-520C77AC7073}, 2}))
         .ok_or_else(|| ErrorKind::MalformedOneStoreData("no header cell root".into()).into())
 }
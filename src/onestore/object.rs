@@ -7,6 +7,7 @@ use crate::onestore::object_space::GroupData;
 use crate::onestore::types::jcid::JcId;
 use crate::onestore::types::object_prop_set::ObjectPropSet;
 use crate::reader::Reader;
+use std::borrow::Cow;
 
 /// A OneNote data object.
 ///
@@ -20,7 +21,7 @@ pub(crate) struct Object<'a> {
 
     pub(crate) jc_id: JcId,
     pub(crate) props: ObjectPropSet,
-    pub(crate) file_data: Option<&'a [u8]>,
+    pub(crate) file_data: Option<Cow<'a, [u8]>>,
     pub(crate) mapping: MappingTable,
 }
 
@@ -59,7 +60,8 @@ impl<'a> Object<'a> {
         context_id: ExGuid,
         object_space_id: ExGuid,
         objects: &'b GroupData,
-        packaging: &'a OneStorePackaging,
+        packaging: &OneStorePackaging<'a>,
+        load_payloads: bool,
     ) -> Result<Object<'a>> {
         let metadata_object = Object::find_object(object_id, Partition::Metadata, objects)
             .ok_or_else(|| ErrorKind::MalformedOneStoreData("object metadata is missing".into()))?;
@@ -93,16 +95,20 @@ impl<'a> Object<'a> {
 
         let props = ObjectPropSet::parse(&mut Reader::new(data.as_slice()))?;
 
-        // Parse file data
-
-        let file_data = Object::find_blob_id(object_id, objects)?
-            .map(|blob_id| {
-                packaging
-                    .data_element_package
-                    .find_blob(blob_id)
-                    .ok_or_else(|| ErrorKind::MalformedOneStoreData("blob not found".into()))
-            })
-            .transpose()?;
+        // Parse file data (the blob isn't looked up if payloads are skipped)
+
+        let file_data = if load_payloads {
+            Object::find_blob_id(object_id, objects)?
+                .map(|blob_id| {
+                    packaging
+                        .data_element_package
+                        .find_blob(blob_id)
+                        .ok_or_else(|| ErrorKind::MalformedOneStoreData("blob not found".into()))
+                })
+                .transpose()?
+        } else {
+            None
+        };
 
         let context_refs: Vec<_> = referenced_cells
             .iter()
@@ -166,7 +172,7 @@ impl<'a> Object<'a> {
         objects.get(&(id, partition_id as u64)).cloned()
     }
 
-    fn find_blob_id(id: ExGuid, objects: &'a GroupData) -> Result<Option<ExGuid>> {
+    fn find_blob_id(id: ExGuid, objects: &GroupData) -> Result<Option<ExGuid>> {
         Self::find_object(id, Partition::FileData, objects)
             .map(|object| match object {
                 ObjectGroupData::BlobReference { blob, .. } => Ok(*blob),
@@ -0,0 +1,113 @@
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::one::property::PropertyType;
+use crate::onestore::types::compact_id::CompactId;
+use crate::onestore::types::jcid::JcId;
+use crate::onestore::types::object_prop_set::ObjectPropSet;
+use crate::onestore::types::prop_set::PropertySet;
+use crate::onestore::types::property::{PropertyId, PropertyValue};
+use crate::shared::guid::Guid;
+
+/// A OneNote data object that is to be written to a OneStore file.
+///
+/// The object's references are stored in the order they are added. As the parser resolves
+/// references by counting the references of all preceding properties, references have to
+/// be added together with the property that refers to them.
+#[derive(Debug, Clone)]
+pub(crate) struct ObjectBuilder {
+    jc_id: JcId,
+    properties: Vec<(PropertyId, PropertyValue)>,
+    object_refs: Vec<ExGuid>,
+    object_space_refs: Vec<CellId>,
+    file_data: Option<Vec<u8>>,
+}
+
+impl ObjectBuilder {
+    pub(crate) fn new(jc_id: JcId) -> ObjectBuilder {
+        ObjectBuilder {
+            jc_id,
+            properties: vec![],
+            object_refs: vec![],
+            object_space_refs: vec![],
+            file_data: None,
+        }
+    }
+
+    pub(crate) fn jc_id(&self) -> JcId {
+        self.jc_id
+    }
+
+    pub(crate) fn object_refs(&self) -> &[ExGuid] {
+        &self.object_refs
+    }
+
+    pub(crate) fn object_space_refs(&self) -> &[CellId] {
+        &self.object_space_refs
+    }
+
+    pub(crate) fn file_data(&self) -> Option<&[u8]> {
+        self.file_data.as_deref()
+    }
+
+    /// Set a property's value.
+    pub(crate) fn set(&mut self, prop_type: PropertyType, value: PropertyValue) {
+        let id = PropertyId::for_value(prop_type as u32, &value);
+
+        self.properties.push((id, value));
+    }
+
+    /// Add the objects referenced by the last property.
+    pub(crate) fn add_object_refs(&mut self, ids: &[ExGuid]) {
+        self.object_refs.extend_from_slice(ids);
+    }
+
+    /// Add the object spaces referenced by the last property.
+    pub(crate) fn add_object_space_refs(&mut self, ids: &[CellId]) {
+        self.object_space_refs.extend_from_slice(ids);
+    }
+
+    /// Set the object's file data.
+    pub(crate) fn set_file_data(&mut self, data: Vec<u8>) {
+        self.file_data = Some(data);
+    }
+
+    /// Build the object's property set.
+    ///
+    /// The references' compact IDs are made up from the referenced ID's value and the
+    /// index of its GUID in the object's references. This requires ID values to be
+    /// less than 256 to be unique.
+    pub(crate) fn prop_set(&self) -> ObjectPropSet {
+        let mut guids = vec![];
+
+        let object_ids = self
+            .object_refs
+            .iter()
+            .map(|id| Self::compact_id(*id, &mut guids))
+            .collect();
+
+        let object_space_ids = self
+            .object_space_refs
+            .iter()
+            .map(|id| Self::compact_id(id.1, &mut guids))
+            .collect();
+
+        ObjectPropSet {
+            object_ids,
+            object_space_ids,
+            context_ids: vec![],
+            properties: PropertySet::from_values(self.properties.clone()),
+        }
+    }
+
+    fn compact_id(id: ExGuid, guids: &mut Vec<Guid>) -> CompactId {
+        let index = match guids.iter().position(|guid| *guid == id.guid) {
+            Some(index) => index,
+            None => {
+                guids.push(id.guid);
+                guids.len() - 1
+            }
+        };
+
+        CompactId::new(id.value as u8, index as u32 + 1)
+    }
+}
@@ -7,7 +7,9 @@ use crate::fsshttpb::packaging::OneStorePackaging;
 use crate::onestore::object::Object;
 use crate::onestore::revision::Revision;
 use crate::onestore::revision_role::RevisionRole;
+use crate::onestore::StoreOptions;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub(crate) type GroupData<'a> = HashMap<(ExGuid, u64), &'a ObjectGroupData>;
 
@@ -18,17 +20,96 @@ pub(crate) type GroupData<'a> = HashMap<(ExGuid, u64), &'a ObjectGroupData>;
 /// See [\[MS-ONESTOR\] 2.1.4]
 ///
 /// [\[MS-ONESTOR\] 2.1.4]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/1329433f-02a5-4e83-ab41-80d57ade38d9
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub(crate) struct ObjectSpace<'a> {
     id: ExGuid,
     context: ExGuid,
     roots: HashMap<RevisionRole, ExGuid>,
-    objects: HashMap<ExGuid, Object<'a>>,
+
+    /// The index of the newest revision containing each object.
+    object_revisions: HashMap<ExGuid, usize>,
+
+    /// The revisions the object space is made up from, newest first.
+    ///
+    /// The revisions are shared with the object spaces returned by
+    /// [`ObjectSpace::at_revision()`].
+    revisions: Arc<[Revision<'a>]>,
+
+    /// The index of the object space's newest revision.
+    start: usize,
 }
 
 impl<'a, 'b> ObjectSpace<'a> {
-    pub(crate) fn get_object(&self, id: ExGuid) -> Option<&Object> {
-        self.objects.get(&id)
+    /// Create an object space from a revision chain.
+    ///
+    /// The revisions are expected to be ordered from the newest revision to the base revision.
+    pub(crate) fn new(
+        id: ExGuid,
+        context: ExGuid,
+        revisions: Vec<Revision<'a>>,
+    ) -> ObjectSpace<'a> {
+        Self::with_start(id, context, revisions.into(), 0)
+    }
+
+    fn with_start(
+        id: ExGuid,
+        context: ExGuid,
+        revisions: Arc<[Revision<'a>]>,
+        start: usize,
+    ) -> ObjectSpace<'a> {
+        let mut roots = HashMap::new();
+        let mut object_revisions = HashMap::new();
+
+        // Apply the revisions from the base revision up so newer revisions win
+        for (index, revision) in revisions.iter().enumerate().skip(start).rev() {
+            roots.extend(revision.roots().iter().map(|(role, id)| (*role, *id)));
+            object_revisions.extend(revision.objects().keys().map(|id| (*id, index)));
+        }
+
+        ObjectSpace {
+            id,
+            context,
+            roots,
+            object_revisions,
+            revisions,
+            start,
+        }
+    }
+
+    /// The revisions the object space is made up from, newest first.
+    pub(crate) fn revisions(&self) -> &[Revision<'a>] {
+        &self.revisions[self.start..]
+    }
+
+    /// Get the object space as of the revision at the given index in the revision chain.
+    ///
+    /// The returned object space shares the revisions with this object space.
+    pub(crate) fn at_revision(&self, index: usize) -> Option<ObjectSpace<'a>> {
+        let start = self.start.checked_add(index)?;
+        if start >= self.revisions.len() {
+            return None;
+        }
+
+        Some(Self::with_start(
+            self.id,
+            self.context,
+            self.revisions.clone(),
+            start,
+        ))
+    }
+
+    /// All objects visible in the object space.
+    pub(crate) fn objects(&self) -> impl Iterator<Item = (ExGuid, &Object<'a>)> {
+        self.object_revisions
+            .iter()
+            .filter_map(move |(id, index)| Some((*id, self.revisions[*index].objects().get(id)?)))
+    }
+
+    pub(crate) fn get_object(&self, id: ExGuid) -> Option<&Object<'a>> {
+        let index = *self.object_revisions.get(&id)?;
+
+        self.revisions[index].objects().get(&id)
     }
 
     pub(crate) fn content_root(&self) -> Option<ExGuid> {
@@ -39,11 +120,16 @@ impl<'a, 'b> ObjectSpace<'a> {
         self.roots.get(&RevisionRole::Metadata).copied()
     }
 
+    pub(crate) fn version_metadata_root(&self) -> Option<ExGuid> {
+        self.roots.get(&RevisionRole::VersionMetadata).copied()
+    }
+
     pub(crate) fn parse(
-        mapping: &'a StorageIndexCellMapping,
-        storage_index: &'a StorageIndex,
-        packaging: &'a OneStorePackaging,
+        mapping: &StorageIndexCellMapping,
+        storage_index: &StorageIndex,
+        packaging: &OneStorePackaging<'a>,
         revision_cache: &'b mut HashMap<CellId, Revision<'a>>,
+        options: &StoreOptions,
     ) -> Result<(CellId, ObjectSpace<'a>)> {
         let cell_id = mapping.cell_id;
 
@@ -58,39 +144,32 @@ impl<'a, 'b> ObjectSpace<'a> {
                 ErrorKind::MalformedOneStoreData("no revision manifest id found".into())
             })?;
 
-        let mut objects = HashMap::new();
-        let mut roots = HashMap::new();
-
+        let mut revisions = vec![];
         let mut rev_id = Some(revision_manifest_id);
 
         while let Some(revision_manifest_id) = rev_id {
-            let base_rev_id = Revision::parse(
+            let (revision, base_rev_id) = Revision::parse(
                 revision_manifest_id,
                 context_id,
                 object_space_id,
                 storage_index,
                 packaging,
                 revision_cache,
-                &mut objects,
-                &mut roots,
+                options,
             )?;
 
+            revisions.push(revision);
             rev_id = base_rev_id;
         }
 
-        let space = ObjectSpace {
-            id: object_space_id,
-            context: context_id,
-            roots,
-            objects,
-        };
+        let space = ObjectSpace::new(object_space_id, context_id, revisions);
 
         Ok((cell_id, space))
     }
 
     fn find_cell_manifest_id(
         cell_manifest_id: ExGuid,
-        packaging: &'a OneStorePackaging,
+        packaging: &OneStorePackaging<'a>,
     ) -> Option<ExGuid> {
         packaging
             .data_element_package
@@ -99,3 +178,85 @@ impl<'a, 'b> ObjectSpace<'a> {
             .copied()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::fsshttpb::data::exguid::ExGuid;
+    use crate::onestore::mapping_table::MappingTable;
+    use crate::onestore::object::Object;
+    use crate::onestore::object_space::ObjectSpace;
+    use crate::onestore::revision::Revision;
+    use crate::onestore::revision_role::RevisionRole;
+    use crate::onestore::types::jcid::JcId;
+    use crate::onestore::types::object_prop_set::ObjectPropSet;
+    use crate::onestore::types::prop_set::PropertySet;
+    use crate::shared::guid::Guid;
+    use std::collections::HashMap;
+
+    fn object(jc_id: u32) -> Object<'static> {
+        Object {
+            context_id: ExGuid::nil(),
+            jc_id: JcId(jc_id),
+            props: ObjectPropSet {
+                object_ids: vec![],
+                object_space_ids: vec![],
+                context_ids: vec![],
+                properties: PropertySet::from_values(vec![]),
+            },
+            file_data: None,
+            mapping: MappingTable::from_entries(std::iter::empty(), std::iter::empty()),
+        }
+    }
+
+    fn revision(
+        id: ExGuid,
+        roots: &[(RevisionRole, ExGuid)],
+        objects: &[(ExGuid, u32)],
+    ) -> Revision<'static> {
+        Revision::new(
+            id,
+            roots.iter().copied().collect(),
+            objects
+                .iter()
+                .map(|(id, jc_id)| (*id, object(*jc_id)))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    #[test]
+    fn test_merge_revision_chain() {
+        let guid = Guid::new_v4();
+        let [a, b, c] = [1, 2, 3].map(|value| ExGuid::from_guid(guid, value));
+
+        let base = revision(
+            ExGuid::from_guid(guid, 10),
+            &[
+                (RevisionRole::DefaultContent, a),
+                (RevisionRole::Metadata, b),
+            ],
+            &[(a, 1), (b, 2)],
+        );
+        let newest = revision(
+            ExGuid::from_guid(guid, 11),
+            &[(RevisionRole::DefaultContent, c)],
+            &[(a, 3), (c, 4)],
+        );
+
+        let space = ObjectSpace::new(ExGuid::nil(), ExGuid::nil(), vec![newest, base]);
+
+        // The newest revision wins
+        assert_eq!(space.get_object(a).map(|object| object.id()), Some(JcId(3)));
+        assert_eq!(space.get_object(b).map(|object| object.id()), Some(JcId(2)));
+        assert_eq!(space.content_root(), Some(c));
+        assert_eq!(space.metadata_root(), Some(b));
+        assert_eq!(space.objects().count(), 3);
+
+        let base = space.at_revision(1).unwrap();
+
+        assert_eq!(base.get_object(a).map(|object| object.id()), Some(JcId(1)));
+        assert!(base.get_object(c).is_none());
+        assert_eq!(base.content_root(), Some(a));
+        assert_eq!(base.revisions().len(), 1);
+        assert!(space.at_revision(2).is_none());
+    }
+}
@@ -6,31 +6,57 @@ use crate::fsshttpb::packaging::OneStorePackaging;
 use crate::onestore::object::Object;
 use crate::onestore::object_space::GroupData;
 use crate::onestore::revision_role::RevisionRole;
+use crate::onestore::StoreOptions;
 use std::collections::HashMap;
 
 /// A OneNote file revision.
 ///
+/// A revision only contains the objects and roots that have been added or changed by it.
+/// The full state of the object space as of a revision is made up from the revision itself
+/// and all the revisions it depends on.
+///
 /// See [\[MS-ONESTOR\] 2.1.8]
 ///
 /// [\[MS-ONESTOR\] 2.1.8]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/a8ca2a90-d92a-4cf7-bf68-ed18ae476a11
 #[derive(Debug, Clone)]
 pub(crate) struct Revision<'a> {
+    id: ExGuid,
     objects: HashMap<ExGuid, Object<'a>>,
     roots: HashMap<RevisionRole, ExGuid>,
 }
 
 impl<'a, 'b> Revision<'a> {
-    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        id: ExGuid,
+        roots: HashMap<RevisionRole, ExGuid>,
+        objects: HashMap<ExGuid, Object<'a>>,
+    ) -> Revision<'a> {
+        Revision { id, objects, roots }
+    }
+
+    pub(crate) fn id(&self) -> ExGuid {
+        self.id
+    }
+
+    pub(crate) fn objects(&self) -> &HashMap<ExGuid, Object<'a>> {
+        &self.objects
+    }
+
+    pub(crate) fn roots(&self) -> &HashMap<RevisionRole, ExGuid> {
+        &self.roots
+    }
+
+    /// Parse a revision and return it together with the mapping ID of the revision it
+    /// depends on (if any).
     pub(crate) fn parse(
         revision_manifest_id: ExGuid,
         context_id: ExGuid,
         object_space_id: ExGuid,
-        storage_index: &'a StorageIndex,
-        packaging: &'a OneStorePackaging,
+        storage_index: &StorageIndex,
+        packaging: &OneStorePackaging<'a>,
         revision_cache: &'b mut HashMap<CellId, Revision<'a>>,
-        objects: &'b mut HashMap<ExGuid, Object<'a>>,
-        roots: &'b mut HashMap<RevisionRole, ExGuid>,
-    ) -> Result<Option<ExGuid>> {
+        options: &StoreOptions,
+    ) -> Result<(Revision<'a>, Option<ExGuid>)> {
         let revision_manifest = packaging
             .data_element_package
             .find_revision_manifest(revision_manifest_id)
@@ -50,35 +76,47 @@ impl<'a, 'b> Revision<'a> {
             })
             .transpose()?;
 
-        if let Some(rev) = revision_cache.get(&CellId(context_id, revision_manifest.rev_id)) {
-            roots.extend(rev.roots.iter());
-            objects.extend(rev.objects.clone().into_iter());
-
-            return Ok(base_rev);
+        let cache_id = CellId(context_id, revision_manifest.rev_id);
+        if let Some(rev) = revision_cache.get(&cache_id) {
+            return Ok((rev.clone(), base_rev));
         }
 
-        roots.extend(
-            revision_manifest
-                .root_declare
-                .iter()
-                .map(|root| Ok((RevisionRole::parse(root.root_id)?, root.object_id)))
-                .collect::<Result<Vec<_>>>()?
-                .into_iter(),
-        );
+        let roots = revision_manifest
+            .root_declare
+            .iter()
+            .map(|root| Ok((RevisionRole::parse(root.root_id)?, root.object_id)))
+            .collect::<Result<_>>()?;
 
+        let mut objects = HashMap::new();
         for group_id in revision_manifest.group_references.iter() {
-            Self::parse_group(context_id, *group_id, object_space_id, packaging, objects)?
+            Self::parse_group(
+                context_id,
+                *group_id,
+                object_space_id,
+                packaging,
+                &mut objects,
+                options,
+            )?
         }
 
-        Ok(base_rev)
+        let revision = Revision {
+            id: revision_manifest.rev_id,
+            objects,
+            roots,
+        };
+
+        revision_cache.insert(cache_id, revision.clone());
+
+        Ok((revision, base_rev))
     }
 
     fn parse_group(
         context_id: ExGuid,
         group_id: ExGuid,
         object_space_id: ExGuid,
-        packaging: &'a OneStorePackaging,
+        packaging: &OneStorePackaging<'a>,
         objects: &'b mut HashMap<ExGuid, Object<'a>>,
+        options: &StoreOptions,
     ) -> Result<()> {
         let group = packaging
             .data_element_package
@@ -106,12 +144,15 @@ impl<'a, 'b> Revision<'a> {
                 .into());
             }
 
+            options.count_object()?;
+
             let object = Object::parse(
                 object_id,
                 context_id,
                 object_space_id,
                 &group_objects,
                 packaging,
+                options.load_payloads(),
             )?;
 
             objects.insert(object_id, object);
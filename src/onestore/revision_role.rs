@@ -24,12 +24,35 @@ impl RevisionRole {
             );
         }
 
-        match id {
-            ExGuid { value: 1, .. } => Ok(RevisionRole::DefaultContent),
-            ExGuid { value: 2, .. } => Ok(RevisionRole::Metadata),
-            ExGuid { value: 3, .. } => Ok(RevisionRole::EncryptionKey),
-            ExGuid { value: 4, .. } => Ok(RevisionRole::VersionMetadata),
-            ExGuid { value, .. } => Err(ErrorKind::MalformedOneStoreData(
+        RevisionRole::from_value(id.value)
+    }
+
+    /// The root declare ID for the role.
+    pub(crate) fn as_exguid(&self) -> ExGuid {
+        let value = match self {
+            RevisionRole::DefaultContent => 1,
+            RevisionRole::Metadata => 2,
+            RevisionRole::EncryptionKey => 3,
+            RevisionRole::VersionMetadata => 4,
+        };
+
+        ExGuid::from_guid(
+            guid!({4A3717F8-1C14-49E7-9526-81D942DE1741}),
+            value,
+        )
+    }
+
+    /// Look up the role for a root role value.
+    ///
+    /// The FSSHTTPB root declare IDs use the same values as the `RootRole` field of the
+    /// revision store file format (see \[MS-ONESTORE\] 2.1.8).
+    pub(crate) fn from_value(value: u32) -> Result<RevisionRole> {
+        match value {
+            1 => Ok(RevisionRole::DefaultContent),
+            2 => Ok(RevisionRole::Metadata),
+            3 => Ok(RevisionRole::EncryptionKey),
+            4 => Ok(RevisionRole::VersionMetadata),
+            value => Err(ErrorKind::MalformedOneStoreData(
                 format!("invalid root declare id exguid value {}", value).into(),
             )
             .into()),
@@ -0,0 +1,367 @@
+use crate::errors::Result;
+use crate::fsshttpb::data::cell_id::CellId;
+use crate::fsshttpb::data::exguid::ExGuid;
+use crate::fsshttpb::data::serial_number::SerialNumber;
+use crate::fsshttpb::data_element::object_data_blob::ObjectDataBlob;
+use crate::fsshttpb::data_element::object_group::{
+    ObjectGroup, ObjectGroupData, ObjectGroupDeclaration,
+};
+use crate::fsshttpb::data_element::revision_manifest::{
+    RevisionManifest, RevisionManifestRootDeclare,
+};
+use crate::fsshttpb::data_element::storage_index::{
+    StorageIndex, StorageIndexCellMapping, StorageIndexEntry, StorageIndexManifestMapping,
+    StorageIndexRevisionMapping,
+};
+use crate::fsshttpb::data_element::storage_manifest::StorageManifest;
+use crate::fsshttpb::data_element::{DataElementHeader, DataElementPackage};
+use crate::fsshttpb::packaging::OneStorePackaging;
+use crate::onestore::header::StoreHeader;
+use crate::onestore::object_builder::ObjectBuilder;
+use crate::onestore::revision_role::RevisionRole;
+use crate::shared::guid::Guid;
+use crate::utils::{crc32, utf16_bytes};
+use crate::Writer;
+use std::collections::HashMap;
+
+/// A OneStore file that is to be written in the FSSHTTPB packaging format.
+///
+/// Each object space is written as a single revision containing all of its objects.
+///
+/// See [\[MS-ONESTORE\] 2.7] and [\[MS-ONESTORE\] 2.8].
+///
+/// [\[MS-ONESTORE\] 2.7]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/07a5dc4d-0d97-4a4c-ab69-aa7957d7115c
+/// [\[MS-ONESTORE\] 2.8]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/a2f046ea-109a-49c4-912d-dc2888cf0565
+#[derive(Debug)]
+pub(crate) struct StoreBuilder {
+    header: StoreHeader,
+    data_root: ObjectSpaceBuilder,
+    object_spaces: Vec<ObjectSpaceBuilder>,
+}
+
+impl StoreBuilder {
+    /// Create a store for a section file with the given file name.
+    pub(crate) fn new(file_identity: Guid, file_name: &str) -> StoreBuilder {
+        let file_name_crc = crc32(&utf16_bytes(&format!("{}\0", file_name)));
+
+        StoreBuilder {
+            header: StoreHeader::new(file_identity, Guid::nil(), None, file_name_crc),
+            data_root: ObjectSpaceBuilder::new(),
+            object_spaces: vec![],
+        }
+    }
+
+    pub(crate) fn data_root(&mut self) -> &mut ObjectSpaceBuilder {
+        &mut self.data_root
+    }
+
+    /// Add an object space to the store and return its ID.
+    pub(crate) fn add_object_space(&mut self, space: ObjectSpaceBuilder) -> CellId {
+        let id = space.cell_id();
+        self.object_spaces.push(space);
+
+        id
+    }
+
+    /// Write the store as a FSSHTTPB packaged file.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let file = self.header.file_identity();
+
+        let mut header_space = ObjectSpaceBuilder::with_id(ExGuid::from_guid(
+            Guid::from_str("111E4CF3-7FEF-4087-AF6A-B9544ACD334D")?,
+            1,
+        ));
+        let header_object_id =
+            ExGuid::from_guid(Guid::from_str("B4760B1A-FBDF-4AE3-9D08-53219D8A8D21")?, 1);
+        header_space.insert_object(header_object_id, self.header.build());
+        header_space.set_content_root(header_object_id);
+
+        let mut package = PackageBuilder {
+            package: DataElementPackage::new(),
+            element_guid: Guid::new_v4(),
+            element_count: 0,
+            file,
+        };
+
+        let storage_manifest_id = package.add_element(0x02);
+        package.package.storage_manifests.insert(
+            storage_manifest_id,
+            StorageManifest {
+                id: Guid::from_str("1F937CB4-B26F-445F-B9F8-17E20160E461")?,
+                roots: vec![
+                    (
+                        exguid!({{1A5A319C-C26B-41AA-B9C5-9BD8C44E07D4}, 1}),
+                        header_space.cell_id(),
+                    ),
+                    (
+                        exguid!({{84DEFAB9-AAA3-4A0D-A3A8-520C77AC7073}, 2}),
+                        self.data_root.cell_id(),
+                    ),
+                ],
+            },
+        );
+
+        let mut storage_index = StorageIndex {
+            entries: vec![StorageIndexEntry::Manifest(0)],
+            manifest_mappings: vec![StorageIndexManifestMapping {
+                mapping_id: storage_manifest_id,
+                serial: package.serial(),
+            }],
+            cell_mappings: HashMap::new(),
+            revision_mappings: HashMap::new(),
+        };
+
+        let spaces = std::iter::once(&header_space)
+            .chain(std::iter::once(&self.data_root))
+            .chain(self.object_spaces.iter());
+
+        for space in spaces {
+            let (cell_manifest_id, rev_id, revision_manifest_id) = package.add_space(space);
+
+            storage_index
+                .entries
+                .push(StorageIndexEntry::Cell(space.cell_id()));
+            storage_index.cell_mappings.insert(
+                space.cell_id(),
+                StorageIndexCellMapping {
+                    cell_id: space.cell_id(),
+                    id: cell_manifest_id,
+                    serial: package.serial(),
+                },
+            );
+
+            storage_index
+                .entries
+                .push(StorageIndexEntry::Revision(rev_id));
+            storage_index.revision_mappings.insert(
+                rev_id,
+                StorageIndexRevisionMapping {
+                    revision_mapping: revision_manifest_id,
+                    serial: package.serial(),
+                },
+            );
+        }
+
+        let storage_index_id = ExGuid::from_guid(file, 1);
+        package.add_element_with_id(storage_index_id, 0x01);
+        package
+            .package
+            .storage_indexes
+            .insert(storage_index_id, storage_index);
+
+        let packaging = OneStorePackaging {
+            file_type: Guid::from_str("7B5C52E4-D88C-4DA7-AEB1-5378D02996D3")?,
+            file,
+            legacy_file_version: file,
+            file_format: Guid::from_str("638DE92F-A6D4-4BC1-9A36-B3FC2511A5B7")?,
+            storage_index: storage_index_id,
+            cell_schema: Guid::from_str("1F937CB4-B26F-445F-B9F8-17E20160E461")?,
+            data_element_package: package.package,
+            trailing_data: &[],
+        };
+
+        packaging.write(writer)
+    }
+}
+
+/// An object space that is to be written to a OneStore file.
+#[derive(Debug)]
+pub(crate) struct ObjectSpaceBuilder {
+    id: ExGuid,
+    roots: Vec<(RevisionRole, ExGuid)>,
+    objects: Vec<(ExGuid, ObjectBuilder)>,
+    object_guid: Guid,
+    object_count: u32,
+}
+
+impl ObjectSpaceBuilder {
+    /// Create an empty object space with a new ID.
+    pub(crate) fn new() -> ObjectSpaceBuilder {
+        Self::with_id(ExGuid::from_guid(Guid::new_v4(), 1))
+    }
+
+    fn with_id(id: ExGuid) -> ObjectSpaceBuilder {
+        ObjectSpaceBuilder {
+            id,
+            roots: vec![],
+            objects: vec![],
+            object_guid: id.guid,
+            object_count: 0,
+        }
+    }
+
+    /// The object space's cell ID.
+    ///
+    /// All object spaces use the default context.
+    pub(crate) fn cell_id(&self) -> CellId {
+        CellId(
+            exguid!({{84DEFAB9-AAA3-4A0D-A3A8-520C77AC7073}, 1}),
+            self.id,
+        )
+    }
+
+    /// Add an object to the object space and return its ID.
+    pub(crate) fn add_object(&mut self, object: ObjectBuilder) -> ExGuid {
+        // Keep the ID values small enough to be used in compact IDs
+        if self.object_count == 255 {
+            self.object_guid = Guid::new_v4();
+            self.object_count = 0;
+        }
+
+        self.object_count += 1;
+
+        let id = ExGuid::from_guid(self.object_guid, self.object_count);
+        self.insert_object(id, object);
+
+        id
+    }
+
+    pub(crate) fn set_content_root(&mut self, id: ExGuid) {
+        self.roots.push((RevisionRole::DefaultContent, id));
+    }
+
+    pub(crate) fn set_metadata_root(&mut self, id: ExGuid) {
+        self.roots.push((RevisionRole::Metadata, id));
+    }
+
+    fn insert_object(&mut self, id: ExGuid, object: ObjectBuilder) {
+        self.objects.push((id, object));
+    }
+}
+
+/// Collects the data elements of a store.
+struct PackageBuilder<'a> {
+    package: DataElementPackage<'a>,
+    element_guid: Guid,
+    element_count: u32,
+    file: Guid,
+}
+
+impl<'a> PackageBuilder<'a> {
+    /// Add the data elements of an object space.
+    ///
+    /// Returns the IDs of the space's cell manifest, revision and revision manifest.
+    fn add_space(&mut self, space: &'a ObjectSpaceBuilder) -> (ExGuid, ExGuid, ExGuid) {
+        let mut declarations = vec![];
+        let mut objects = vec![];
+
+        for (id, object) in &space.objects {
+            let mut jc_id = vec![];
+            object.jc_id().write(&mut jc_id).unwrap();
+
+            declarations.push(ObjectGroupDeclaration::Object {
+                object_id: *id,
+                partition_id: 4,
+                data_size: jc_id.len() as u64,
+                object_reference_count: 0,
+                cell_reference_count: 0,
+            });
+            objects.push(ObjectGroupData::Object {
+                group: vec![],
+                cells: vec![],
+                data: jc_id,
+            });
+
+            let mut data = vec![];
+            object.prop_set().write(&mut data).unwrap();
+
+            declarations.push(ObjectGroupDeclaration::Object {
+                object_id: *id,
+                partition_id: 1,
+                data_size: data.len() as u64,
+                object_reference_count: object.object_refs().len() as u64,
+                cell_reference_count: object.object_space_refs().len() as u64,
+            });
+            objects.push(ObjectGroupData::Object {
+                group: object.object_refs().to_vec(),
+                cells: object.object_space_refs().to_vec(),
+                data,
+            });
+
+            if let Some(file_data) = object.file_data() {
+                let blob_id = self.add_element(0x0A);
+                self.package
+                    .object_data_blobs
+                    .insert(blob_id, ObjectDataBlob::new(file_data));
+
+                declarations.push(ObjectGroupDeclaration::Blob {
+                    object_id: *id,
+                    blob_id,
+                    partition_id: 2,
+                    object_reference_count: 0,
+                    cell_reference_count: 0,
+                });
+                objects.push(ObjectGroupData::BlobReference {
+                    objects: vec![],
+                    cells: vec![],
+                    blob: blob_id,
+                });
+            }
+        }
+
+        let group_id = self.add_element(0x05);
+        self.package.object_groups.insert(
+            group_id,
+            ObjectGroup {
+                declarations,
+                metadata: vec![],
+                objects,
+            },
+        );
+
+        let rev_id = ExGuid::from_guid(Guid::new_v4(), 1);
+        let revision_manifest_id = self.add_element(0x04);
+        self.package.revision_manifests.insert(
+            revision_manifest_id,
+            RevisionManifest {
+                rev_id,
+                base_rev_id: ExGuid::nil(),
+                root_declare: space
+                    .roots
+                    .iter()
+                    .map(|(role, object_id)| RevisionManifestRootDeclare {
+                        root_id: role.as_exguid(),
+                        object_id: *object_id,
+                    })
+                    .collect(),
+                group_references: vec![group_id],
+            },
+        );
+
+        let cell_manifest_id = self.add_element(0x03);
+        self.package.cell_manifests.insert(cell_manifest_id, rev_id);
+
+        (cell_manifest_id, rev_id, revision_manifest_id)
+    }
+
+    fn add_element(&mut self, element_type: u64) -> ExGuid {
+        self.element_count += 1;
+
+        let id = ExGuid::from_guid(self.element_guid, self.element_count);
+        self.add_element_with_id(id, element_type);
+
+        id
+    }
+
+    fn add_element_with_id(&mut self, id: ExGuid, element_type: u64) {
+        let serial = self.serial();
+
+        self.package.elements.push(DataElementHeader {
+            id,
+            serial,
+            element_type,
+        });
+    }
+
+    /// Get the next serial number.
+    ///
+    /// The serial numbers are shared between data elements and storage index entries.
+    fn serial(&mut self) -> SerialNumber {
+        self.element_count += 1;
+
+        SerialNumber {
+            guid: self.file,
+            serial: self.element_count as u64,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::errors::Result;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 
 /// A compact reference to an ID in the mapping table.
 ///
@@ -13,6 +14,18 @@ pub(crate) struct CompactId {
 }
 
 impl CompactId {
+    pub(crate) fn new(n: u8, guid_index: u32) -> CompactId {
+        CompactId { n, guid_index }
+    }
+
+    pub(crate) fn n(&self) -> u8 {
+        self.n
+    }
+
+    pub(crate) fn guid_index(&self) -> u32 {
+        self.guid_index
+    }
+
     pub(crate) fn parse(reader: Reader) -> Result<CompactId> {
         let data = reader.get_u32()?;
 
@@ -21,4 +34,10 @@ impl CompactId {
 
         Ok(CompactId { n, guid_index })
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        writer.put_u32_le(self.n as u32 | self.guid_index << 8);
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::errors::Result;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 use std::fmt;
 
 /// An object type.
@@ -14,6 +15,12 @@ impl JcId {
     pub(crate) fn parse(reader: Reader) -> Result<JcId> {
         reader.get_u32().map(JcId)
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        writer.put_u32_le(self.0);
+
+        Ok(())
+    }
 }
 
 impl fmt::Debug for JcId {
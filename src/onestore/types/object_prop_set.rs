@@ -4,7 +4,7 @@ use crate::onestore::types::compact_id::CompactId;
 use crate::onestore::types::object_stream_header::ObjectStreamHeader;
 use crate::onestore::types::prop_set::PropertySet;
 use crate::onestore::types::property::{PropertyId, PropertyValue};
-use crate::Reader;
+use crate::{Reader, Writer};
 
 /// An object's properties.
 ///
@@ -72,6 +72,52 @@ impl ObjectPropSet {
         })
     }
 
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let osid_stream_not_present =
+            self.object_space_ids.is_empty() && self.context_ids.is_empty();
+
+        ObjectStreamHeader {
+            count: self.object_ids.len() as u32,
+            extended_streams_present: !self.context_ids.is_empty(),
+            osid_stream_not_present,
+        }
+        .write(writer)?;
+
+        for id in &self.object_ids {
+            id.write(writer)?;
+        }
+
+        if !osid_stream_not_present {
+            let extended_streams_present = !self.context_ids.is_empty();
+
+            ObjectStreamHeader {
+                count: self.object_space_ids.len() as u32,
+                extended_streams_present,
+                osid_stream_not_present: false,
+            }
+            .write(writer)?;
+
+            for id in &self.object_space_ids {
+                id.write(writer)?;
+            }
+
+            if extended_streams_present {
+                ObjectStreamHeader {
+                    count: self.context_ids.len() as u32,
+                    extended_streams_present: false,
+                    osid_stream_not_present: false,
+                }
+                .write(writer)?;
+
+                for id in &self.context_ids {
+                    id.write(writer)?;
+                }
+            }
+        }
+
+        self.properties.write(writer)
+    }
+
     pub(crate) fn get(&self, prop_type: PropertyType) -> Option<&PropertyValue> {
         self.properties.get(PropertyId::new(prop_type as u32))
     }
@@ -1,5 +1,6 @@
 use crate::errors::Result;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 
 /// An object stream header.
 ///
@@ -27,4 +28,14 @@ impl ObjectStreamHeader {
             osid_stream_not_present,
         })
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let data = (self.count & 0xFFFFFF)
+            | (self.extended_streams_present as u32) << 30
+            | (self.osid_stream_not_present as u32) << 31;
+
+        writer.put_u32_le(data);
+
+        Ok(())
+    }
 }
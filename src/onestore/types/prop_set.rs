@@ -1,6 +1,8 @@
 use crate::errors::Result;
 use crate::onestore::types::property::{PropertyId, PropertyValue};
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
+use itertools::Itertools;
 use std::collections::HashMap;
 
 /// A property set.
@@ -14,6 +16,16 @@ pub(crate) struct PropertySet {
 }
 
 impl PropertySet {
+    pub(crate) fn from_values(values: Vec<(PropertyId, PropertyValue)>) -> PropertySet {
+        let values = values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (id, value))| (id.id(), (idx, value)))
+            .collect();
+
+        PropertySet { values }
+    }
+
     pub(crate) fn parse(reader: Reader) -> Result<PropertySet> {
         let count = reader.get_u16()?;
 
@@ -30,6 +42,26 @@ impl PropertySet {
         Ok(PropertySet { values })
     }
 
+    /// Write the property set with its properties in their original order.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        let values = self
+            .iter()
+            .map(|(id, value)| (PropertyId::for_value(id, value), value))
+            .collect_vec();
+
+        writer.put_u16_le(values.len() as u16);
+
+        for (id, _) in &values {
+            id.write(writer)?;
+        }
+
+        for (_, value) in values {
+            value.write(writer)?;
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get(&self, id: PropertyId) -> Option<&PropertyValue> {
         self.values.get(&id.id()).map(|(_, value)| value)
     }
@@ -42,6 +74,14 @@ impl PropertySet {
         self.values.values().map(|(_, value)| value)
     }
 
+    /// The property IDs and values in their original order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (u32, &PropertyValue)> {
+        self.values
+            .iter()
+            .sorted_by_key(|(_, (index, _))| *index)
+            .map(|(id, (_, value))| (*id, value))
+    }
+
     pub(crate) fn values_with_index(&self) -> impl Iterator<Item = &(usize, PropertyValue)> {
         self.values.values()
     }
@@ -1,10 +1,12 @@
 use crate::errors::{ErrorKind, Result};
 use crate::onestore::types::prop_set::PropertySet;
-use crate::Reader;
+use crate::{Reader, Writer};
+use bytes::BufMut;
 use std::fmt;
 
 /// A property value.
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub(crate) enum PropertyValue {
     Empty,
     Bool(bool),
@@ -147,6 +149,67 @@ impl PropertyValue {
         Ok(value)
     }
 
+    /// The property type used to store the value.
+    ///
+    /// See [\[MS-ONESTORE\] 2.6.6].
+    ///
+    /// [\[MS-ONESTORE\] 2.6.6]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-onestore/17d8c39e-6cc2-4fcd-8d10-aee950fd0ab2
+    pub(crate) fn prop_type(&self) -> u32 {
+        match self {
+            PropertyValue::Empty => 0x1,
+            PropertyValue::Bool(_) => 0x2,
+            PropertyValue::U8(_) => 0x3,
+            PropertyValue::U16(_) => 0x4,
+            PropertyValue::U32(_) => 0x5,
+            PropertyValue::U64(_) => 0x6,
+            PropertyValue::Vec(_) => 0x7,
+            PropertyValue::ObjectId => 0x8,
+            PropertyValue::ObjectIds(_) => 0x9,
+            PropertyValue::ObjectSpaceId => 0xA,
+            PropertyValue::ObjectSpaceIds(_) => 0xB,
+            PropertyValue::ContextId => 0xC,
+            PropertyValue::ContextIds(_) => 0xD,
+            PropertyValue::PropertyValues(_, _) => 0x10,
+            PropertyValue::PropertySet(_) => 0x11,
+        }
+    }
+
+    /// Write the value's data.
+    ///
+    /// Boolean values and single references don't have any data as they are stored in the
+    /// property ID and the object's reference streams respectively.
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        match self {
+            PropertyValue::Empty
+            | PropertyValue::Bool(_)
+            | PropertyValue::ObjectId
+            | PropertyValue::ObjectSpaceId
+            | PropertyValue::ContextId => {}
+            PropertyValue::U8(value) => writer.put_u8(*value),
+            PropertyValue::U16(value) => writer.put_u16_le(*value),
+            PropertyValue::U32(value) => writer.put_u32_le(*value),
+            PropertyValue::U64(value) => writer.put_u64_le(*value),
+            PropertyValue::Vec(data) => {
+                writer.put_u32_le(data.len() as u32);
+                writer.extend_from_slice(data);
+            }
+            PropertyValue::ObjectIds(count)
+            | PropertyValue::ObjectSpaceIds(count)
+            | PropertyValue::ContextIds(count) => writer.put_u32_le(*count),
+            PropertyValue::PropertyValues(id, values) => {
+                writer.put_u32_le(values.len() as u32);
+                id.write(writer)?;
+
+                for value in values {
+                    value.write(writer)?;
+                }
+            }
+            PropertyValue::PropertySet(props) => props.write(writer)?,
+        }
+
+        Ok(())
+    }
+
     fn parse_vec(reader: Reader) -> Result<PropertyValue> {
         let size = reader.get_u32()?;
         let data = reader.read(size as usize)?.to_vec();
@@ -184,6 +247,13 @@ impl PropertyId {
         PropertyId(value)
     }
 
+    /// Create the property ID for storing a value with the given ID.
+    pub(crate) fn for_value(id: u32, value: &PropertyValue) -> PropertyId {
+        let bool = matches!(value, PropertyValue::Bool(true)) as u32;
+
+        PropertyId(id & 0x3ffffff | value.prop_type() << 26 | bool << 31)
+    }
+
     pub(crate) fn value(&self) -> u32 {
         self.0
     }
@@ -203,6 +273,12 @@ impl PropertyId {
     pub(crate) fn parse(reader: Reader) -> Result<PropertyId> {
         reader.get_u32().map(PropertyId::new)
     }
+
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        writer.put_u32_le(self.0);
+
+        Ok(())
+    }
 }
 
 impl fmt::Debug for PropertyId {
@@ -213,13 +289,25 @@ impl fmt::Debug for PropertyId {
 
 #[cfg(test)]
 mod test {
-    use crate::onestore::types::property::PropertyId;
+    use crate::onestore::types::property::{PropertyId, PropertyValue};
 
     #[test]
     fn test_property_bool() {
-        assert_eq!(PropertyId::new(0x08001C04).bool(), false);
-        assert_eq!(PropertyId::new(0x88001C04).bool(), true);
+        assert!(!PropertyId::new(0x08001C04).bool());
+        assert!(PropertyId::new(0x88001C04).bool());
         assert_eq!(PropertyId::new(0x88001C04).id(), 0x1C04);
         assert_eq!(PropertyId::new(0x88001C04).prop_type(), 0x2);
     }
+
+    #[test]
+    fn test_property_for_value() {
+        let id = PropertyId::for_value(0x1C04, &PropertyValue::Bool(true));
+        assert_eq!(id.value(), 0x88001C04);
+
+        let id = PropertyId::for_value(0x1C04, &PropertyValue::Bool(false));
+        assert_eq!(id.value(), 0x08001C04);
+
+        let id = PropertyId::for_value(0x1C20, &PropertyValue::ObjectIds(2));
+        assert_eq!(id.value(), 0x24001C20);
+    }
 }
@@ -28,12 +28,12 @@ impl<'a> Reader<'a> {
         Reader(data)
     }
 
-    pub(crate) fn read(&mut self, cnt: usize) -> Result<&[u8]> {
+    pub(crate) fn read(&mut self, cnt: usize) -> Result<&'a [u8]> {
         if self.remaining() < cnt {
             return Err(ErrorKind::UnexpectedEof.into());
         }
 
-        let data = &self.0[0..cnt];
+        let data: &'a [u8] = &self.0[0..cnt];
         self.0.advance(cnt);
 
         Ok(data)
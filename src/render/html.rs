@@ -0,0 +1,813 @@
+use crate::one::property::color::Color;
+use crate::one::property::color_ref::ColorRef;
+use crate::one::property::layout_alignment::{HorizontalAlignment, LayoutAlignment};
+use crate::one::property::paragraph_alignment::ParagraphAlignment;
+use crate::onenote::content::Content;
+use crate::onenote::embedded_file::EmbeddedFile;
+use crate::onenote::image::Image;
+use crate::onenote::ink::Ink;
+use crate::onenote::list::List;
+use crate::onenote::outline::{Outline, OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onenote::rich_text::{EmbeddedObject, ParagraphStyling, RichText};
+use crate::onenote::section::Section;
+use crate::onenote::table::Table;
+use crate::render::svg::ink_bounds;
+use crate::render::{is_safe_url, text_runs, LIST_FORMAT_NUMBER};
+use std::fmt::Write;
+
+/// The indentation of nested outline elements in half-inch increments.
+const NESTED_INDENT: f32 = 0.75;
+
+/// The default distance between a list bullet and the list content in half-inch increments.
+const DEFAULT_LIST_SPACING: f32 = 0.5;
+
+const STYLESHEET: &str = "\
+body { font-family: Calibri, sans-serif; font-size: 11pt; margin: 0; }
+.page { position: relative; margin: 0.5in; }
+.page + .page { border-top: 1px solid #d0d0d0; padding-top: 0.5in; }
+p { margin: 0; white-space: pre-wrap; }
+p:empty { min-height: 1.2em; }
+.list-item { display: flex; }
+.list-marker { flex: none; }
+.list-content { flex: auto; min-width: 0; }
+table { border-collapse: collapse; }
+td { vertical-align: top; padding: 0.02in 0.05in; }
+table.borders td { border: 1px solid #a3a3a3; }
+img { display: block; }
+svg { overflow: visible; }
+.ink svg { display: block; }
+";
+
+/// A renderer converting pages and sections to standalone HTML documents.
+///
+/// Page contents are positioned absolutely using their offsets from the page origin so that
+/// a rendered page looks like the page as displayed by OneNote. Images and embedded files
+/// are embedded into the document using `data:` URLs and ink is drawn using inline SVG images.
+/// Links using schemes other than `http`, `https`, `mailto` and `onenote` are left out.
+#[derive(Debug, Default)]
+pub struct HtmlRenderer;
+
+impl HtmlRenderer {
+    /// Create a new HTML renderer.
+    pub fn new() -> HtmlRenderer {
+        HtmlRenderer
+    }
+
+    /// Render a page as a HTML document.
+    pub fn render_page(&self, page: &Page) -> String {
+        let mut body = String::new();
+        render_page(&mut body, page);
+
+        document(page.title_text().unwrap_or_default(), &body)
+    }
+
+    /// Render all pages of a section as a single HTML document.
+    ///
+    /// The pages are rendered below each other.
+    pub fn render_section(&self, section: &Section) -> String {
+        let mut body = String::new();
+
+        for page in section
+            .page_series()
+            .iter()
+            .flat_map(|series| series.pages())
+        {
+            render_page(&mut body, page);
+        }
+
+        document(section.display_name(), &body)
+    }
+}
+
+fn document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>\n{}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLESHEET,
+        body
+    )
+}
+
+fn render_page(out: &mut String, page: &Page) {
+    // Absolutely positioned contents don't take up space, so make room for them
+    let height = page
+        .contents()
+        .iter()
+        .filter_map(|content| match content {
+            PageContent::Outline(outline) => outline
+                .offset_vertical()
+                .map(|offset| offset + outline.layout_max_height().unwrap_or_default()),
+            PageContent::Image(image) => image_bottom(image),
+            PageContent::EmbeddedFile(file) => file
+                .offset_vertical()
+                .map(|offset| offset + file.layout_max_height().unwrap_or_default()),
+            PageContent::Ink(ink) => ink_bounds(ink).map(|bounds| {
+                ink.offset_vertical().unwrap_or_default() + (bounds.y() + bounds.height())
+            }),
+            PageContent::Printout(printout) => printout
+                .pages()
+                .iter()
+                .filter_map(image_bottom)
+                .reduce(f32::max),
+            PageContent::Unknown(_) => None,
+        })
+        .chain(page.height())
+        .fold(0f32, f32::max);
+
+    let _ = writeln!(
+        out,
+        "<div class=\"page\" style=\"min-height: {}\">",
+        length(height)
+    );
+
+    if let Some(title) = page.title() {
+        let _ = writeln!(
+            out,
+            "<div class=\"title\" style=\"{}\">",
+            position(
+                Some(title.offset_horizontal()),
+                Some(title.offset_vertical())
+            )
+        );
+
+        for outline in title.contents() {
+            render_outline_items(out, outline);
+        }
+
+        out.push_str("</div>\n");
+    }
+
+    for content in page.contents() {
+        match content {
+            PageContent::Outline(outline) => render_outline(out, outline),
+            PageContent::Image(image) => render_page_image(out, image),
+            PageContent::EmbeddedFile(file) => {
+                let _ = writeln!(
+                    out,
+                    "<div class=\"embedded-file\" style=\"{}\">",
+                    position(file.offset_horizontal(), file.offset_vertical())
+                );
+                render_embedded_file(out, file);
+                out.push_str("</div>\n");
+            }
+            PageContent::Ink(ink) => render_page_ink(out, ink),
+            PageContent::Printout(printout) => {
+                for image in printout.pages() {
+                    render_page_image(out, image);
+                }
+            }
+            PageContent::Unknown(_) => {}
+        }
+    }
+
+    out.push_str("</div>\n");
+}
+
+fn render_page_image(out: &mut String, image: &Image) {
+    let _ = writeln!(
+        out,
+        "<div class=\"image\" style=\"{}\">",
+        position(image.offset_horizontal(), image.offset_vertical())
+    );
+    render_image(out, image);
+    out.push_str("</div>\n");
+}
+
+/// The vertical position of an image's bottom edge on the page.
+fn image_bottom(image: &Image) -> Option<f32> {
+    image
+        .offset_vertical()
+        .map(|offset| offset + image_size(image).1.unwrap_or_default())
+}
+
+fn render_page_ink(out: &mut String, ink: &Ink) {
+    let bounds = match ink_bounds(ink) {
+        Some(bounds) => bounds,
+        None => return,
+    };
+
+    // The SVG image only covers the ink's bounding box
+    let left = ink.offset_horizontal().unwrap_or_default() + bounds.x();
+    let top = ink.offset_vertical().unwrap_or_default() + bounds.y();
+
+    let _ = write!(
+        out,
+        "<div class=\"ink\" style=\"{}\">\n{}</div>\n",
+        position(Some(left), Some(top)),
+        ink.to_svg()
+    );
+}
+
+fn render_outline(out: &mut String, outline: &Outline) {
+    let mut style = position(outline.offset_horizontal(), outline.offset_vertical());
+
+    if let Some(width) = outline.layout_max_width() {
+        let _ = write!(style, " width: {};", length(width));
+    }
+
+    let _ = writeln!(out, "<div class=\"outline\" style=\"{}\">", style);
+    render_outline_items(out, outline);
+    out.push_str("</div>\n");
+}
+
+fn render_outline_items(out: &mut String, outline: &Outline) {
+    let list_spacing = outline.list_spacing().unwrap_or(DEFAULT_LIST_SPACING);
+
+    render_items(out, outline.items(), list_spacing);
+}
+
+fn render_items(out: &mut String, items: &[OutlineItem], list_spacing: f32) {
+    let mut counter = ListCounter::default();
+
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => {
+                let _ = writeln!(
+                    out,
+                    "<div class=\"outline-group\" style=\"margin-left: {}\">",
+                    length(NESTED_INDENT * group.child_level() as f32)
+                );
+                render_items(out, group.outlines(), list_spacing);
+                out.push_str("</div>\n");
+
+                counter = ListCounter::default();
+            }
+            OutlineItem::Element(element) => {
+                render_list_element(out, element, &mut counter, list_spacing)
+            }
+        }
+    }
+}
+
+/// Render an outline element that may be part of a numbered list.
+fn render_list_element(
+    out: &mut String,
+    element: &OutlineElement,
+    counter: &mut ListCounter,
+    list_spacing: f32,
+) {
+    let marker = element
+        .list_contents()
+        .first()
+        .map(|list| (list, counter.next(list)));
+
+    if marker.is_none() {
+        *counter = ListCounter::default();
+    }
+
+    render_element(out, element, marker, list_spacing);
+}
+
+fn render_element(
+    out: &mut String,
+    element: &OutlineElement,
+    marker: Option<(&List, String)>,
+    list_spacing: f32,
+) {
+    out.push_str("<div class=\"outline-element\">\n");
+
+    if let Some((list, marker)) = &marker {
+        let spacing = element.list_spacing().unwrap_or(list_spacing);
+
+        let _ = writeln!(
+            out,
+            "<div class=\"list-item\"><span class=\"list-marker\" style=\"{}\">{}</span>\
+             <div class=\"list-content\">",
+            list_marker_style(list, spacing),
+            escape(marker)
+        );
+    }
+
+    for content in element.contents() {
+        render_content(out, content);
+    }
+
+    if marker.is_some() {
+        out.push_str("</div></div>\n");
+    }
+
+    if !element.children().is_empty() {
+        let _ = writeln!(
+            out,
+            "<div class=\"outline-children\" style=\"margin-left: {}\">",
+            length(NESTED_INDENT * element.child_level() as f32)
+        );
+        render_items(out, element.children(), list_spacing);
+        out.push_str("</div>\n");
+    }
+
+    out.push_str("</div>\n");
+}
+
+fn render_content(out: &mut String, content: &Content) {
+    match content {
+        Content::RichText(text) => render_rich_text(out, text),
+        Content::Table(table) => render_table(out, table),
+        Content::Image(image) => render_image(out, image),
+        Content::EmbeddedFile(file) => render_embedded_file(out, file),
+        Content::Printout(printout) => {
+            for image in printout.pages() {
+                render_image(out, image);
+            }
+        }
+        Content::Ink(ink) => {
+            let _ = write!(out, "<div class=\"ink\">\n{}</div>\n", ink.to_svg());
+        }
+        Content::Unknown(_) => {}
+    }
+}
+
+fn render_rich_text(out: &mut String, text: &RichText) {
+    let mut style = text_style(text.paragraph_style());
+
+    match text.paragraph_alignment() {
+        ParagraphAlignment::Center => style.push_str(" text-align: center;"),
+        ParagraphAlignment::Right => style.push_str(" text-align: right;"),
+        ParagraphAlignment::Left | ParagraphAlignment::Unknown => {}
+    }
+
+    if text.paragraph_space_before() > 0.0 {
+        let _ = write!(
+            style,
+            " margin-top: {};",
+            length(text.paragraph_space_before())
+        );
+    }
+
+    if text.paragraph_space_after() > 0.0 {
+        let _ = write!(
+            style,
+            " margin-bottom: {};",
+            length(text.paragraph_space_after())
+        );
+    }
+
+    if let Some(spacing) = text.paragraph_line_spacing_exact() {
+        let _ = write!(style, " line-height: {};", length(spacing));
+    }
+
+    let _ = write!(out, "<p style=\"{}\">", style.trim());
+
+    let checkable = text.note_tags().iter().find(|tag| {
+        tag.definition()
+            .map(|definition| definition.shape().is_checkable())
+            .unwrap_or_default()
+    });
+
+    if let Some(tag) = checkable {
+        let checked = if tag.item_status().completed() {
+            " checked"
+        } else {
+            ""
+        };
+
+        let _ = write!(out, "<input type=\"checkbox\" disabled{}> ", checked);
+    }
+
+    for object in text.embedded_objects() {
+        match object {
+            EmbeddedObject::Ink(container) => out.push_str(container.to_svg().trim_end()),
+            EmbeddedObject::InkSpace(_) => out.push(' '),
+            EmbeddedObject::InkLineBreak => out.push_str("<br>"),
+        }
+    }
+
+    let mut open_link = None;
+
+    for (run, style, link) in text_runs(text) {
+        if run.is_empty() {
+            continue;
+        }
+
+        if link != open_link {
+            if open_link.is_some() {
+                out.push_str("</a>");
+            }
+
+            if let Some(url) = link {
+                let _ = write!(out, "<a href=\"{}\">", escape(url));
+            }
+
+            open_link = link;
+        }
+
+        let run = escape(&run)
+            .replace("\r\n", "<br>")
+            .replace(['\r', '\n', '\u{b}'], "<br>");
+
+        let style = text_style(style);
+        if style.is_empty() {
+            out.push_str(&run);
+        } else {
+            let _ = write!(out, "<span style=\"{}\">{}</span>", style.trim(), run);
+        }
+    }
+
+    if open_link.is_some() {
+        out.push_str("</a>");
+    }
+
+    out.push_str("</p>\n");
+}
+
+fn render_table(out: &mut String, table: &Table) {
+    let class = if table.borders_visible() {
+        " class=\"borders\""
+    } else {
+        ""
+    };
+
+    let _ = writeln!(
+        out,
+        "<table{} style=\"{}\">",
+        class,
+        alignment(table.layout_alignment_in_parent())
+    );
+
+    if !table.col_widths().is_empty() {
+        out.push_str("<colgroup>");
+
+        for width in table.col_widths() {
+            let _ = write!(out, "<col style=\"width: {}\">", length(*width));
+        }
+
+        out.push_str("</colgroup>\n");
+    }
+
+    for row in table.contents() {
+        out.push_str("<tr>\n");
+
+        for cell in row.contents() {
+            match cell.background_color() {
+                Some(color) => {
+                    let _ = writeln!(out, "<td style=\"background-color: {}\">", rgba(color));
+                }
+                None => out.push_str("<td>\n"),
+            }
+
+            let mut counter = ListCounter::default();
+            for element in cell.contents() {
+                render_list_element(out, element, &mut counter, DEFAULT_LIST_SPACING);
+            }
+
+            out.push_str("</td>\n");
+        }
+
+        out.push_str("</tr>\n");
+    }
+
+    out.push_str("</table>\n");
+}
+
+fn render_image(out: &mut String, image: &Image) {
+    let data = match image.data() {
+        Some(data) => data,
+        None => return,
+    };
+
+    let mut style = alignment(image.layout_alignment_in_parent());
+    let (width, height) = image_size(image);
+
+    if let Some(width) = width {
+        let _ = write!(style, " width: {};", length(width));
+    }
+
+    if let Some(height) = height {
+        let _ = write!(style, " height: {};", length(height));
+    }
+
+    let img = format!(
+        "<img src=\"data:{};base64,{}\" alt=\"{}\" style=\"{}\">",
+        image_mime_type(image.extension().unwrap_or_default()),
+        base64(data),
+        escape(image.alt_text().unwrap_or_default()),
+        style.trim()
+    );
+
+    match image.hyperlink_url().filter(|url| is_safe_url(url)) {
+        Some(url) => {
+            let _ = writeln!(out, "<a href=\"{}\">{}</a>", escape(url), img);
+        }
+        None => {
+            let _ = writeln!(out, "{}", img);
+        }
+    }
+}
+
+fn render_embedded_file(out: &mut String, file: &EmbeddedFile) {
+    let _ = writeln!(
+        out,
+        "<p><a download=\"{name}\" href=\"data:application/octet-stream;base64,{}\">{name}</a></p>",
+        base64(file.data()),
+        name = escape(file.filename())
+    );
+}
+
+/// The CSS properties for the text formatting of a paragraph or text run.
+fn text_style(style: &ParagraphStyling) -> String {
+    let mut css = String::new();
+
+    if let Some(font) = style.font() {
+        let _ = write!(css, " font-family: '{}';", escape(&font.replace('\'', "")));
+    }
+
+    if let Some(size) = style.font_size() {
+        let _ = write!(css, " font-size: {}pt;", size as f32 / 2.0);
+    }
+
+    if let Some(ColorRef::Manual { r, g, b }) = style.font_color() {
+        let _ = write!(css, " color: #{:02x}{:02x}{:02x};", r, g, b);
+    }
+
+    if let Some(ColorRef::Manual { r, g, b }) = style.highlight() {
+        let _ = write!(css, " background-color: #{:02x}{:02x}{:02x};", r, g, b);
+    }
+
+    if style.bold() {
+        css.push_str(" font-weight: bold;");
+    }
+
+    if style.italic() {
+        css.push_str(" font-style: italic;");
+    }
+
+    match (style.underline(), style.strikethrough()) {
+        (true, true) => css.push_str(" text-decoration: underline line-through;"),
+        (true, false) => css.push_str(" text-decoration: underline;"),
+        (false, true) => css.push_str(" text-decoration: line-through;"),
+        (false, false) => {}
+    }
+
+    if style.superscript() {
+        css.push_str(" vertical-align: super; font-size: smaller;");
+    } else if style.subscript() {
+        css.push_str(" vertical-align: sub; font-size: smaller;");
+    }
+
+    css
+}
+
+fn list_marker_style(list: &List, spacing: f32) -> String {
+    let mut css = format!("padding-right: {};", length(spacing));
+
+    if let Some(font) = list.font().or_else(|| list.list_font()) {
+        let _ = write!(css, " font-family: '{}';", escape(&font.replace('\'', "")));
+    }
+
+    if let Some(size) = list.font_size() {
+        let _ = write!(css, " font-size: {}pt;", size as f32 / 2.0);
+    }
+
+    if let Some(ColorRef::Manual { r, g, b }) = list.font_color() {
+        let _ = write!(css, " color: #{:02x}{:02x}{:02x};", r, g, b);
+    }
+
+    if list.bold() {
+        css.push_str(" font-weight: bold;");
+    }
+
+    if list.italic() {
+        css.push_str(" font-style: italic;");
+    }
+
+    css
+}
+
+/// Counts the items of a numbered list.
+#[derive(Default)]
+struct ListCounter {
+    index: i32,
+}
+
+impl ListCounter {
+    /// Determine the list marker for the next list item.
+    fn next(&mut self, list: &List) -> String {
+        let format = list.list_format();
+
+        let number_format = match format.iter().position(|c| *c == LIST_FORMAT_NUMBER) {
+            Some(index) => format.get(index + 1).copied().unwrap_or('\0'),
+            None => return format.iter().filter(|c| **c != '\0').collect(),
+        };
+
+        self.index = list.list_restart().unwrap_or(self.index + 1);
+
+        // The list format is made up of the text before the number, the number placeholder
+        // (including the number format) and the text after the number
+        format
+            .iter()
+            .scan(false, |skip, c| {
+                let c = if *skip {
+                    *skip = false;
+                    None
+                } else if *c == LIST_FORMAT_NUMBER {
+                    *skip = true;
+                    Some(format_number(self.index, number_format))
+                } else {
+                    Some(c.to_string())
+                };
+
+                Some(c)
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+/// Format a list number.
+///
+/// See [\[MS-ONE\] 2.3.20] for the number formats.
+///
+/// [\[MS-ONE\] 2.3.20]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/587f8d1c-e0c3-434f-8e02-c9b4e710c0b3
+fn format_number(number: i32, format: char) -> String {
+    match format {
+        '\u{1}' => roman(number),
+        '\u{2}' => roman(number).to_lowercase(),
+        '\u{3}' => letters(number),
+        '\u{4}' => letters(number).to_lowercase(),
+        _ => number.to_string(),
+    }
+}
+
+fn roman(number: i32) -> String {
+    const NUMERALS: [(i32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if number <= 0 {
+        return number.to_string();
+    }
+
+    let mut number = number;
+    let mut output = String::new();
+
+    for (value, numeral) in NUMERALS.iter() {
+        while number >= *value {
+            output.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    output
+}
+
+fn letters(number: i32) -> String {
+    if number <= 0 {
+        return number.to_string();
+    }
+
+    let mut number = number;
+    let mut output = vec![];
+
+    while number > 0 {
+        number -= 1;
+        output.push((b'A' + (number % 26) as u8) as char);
+        number /= 26;
+    }
+
+    output.into_iter().rev().collect()
+}
+
+/// The CSS properties to position an element relative to the page origin.
+///
+/// Elements without offsets are part of the regular document flow.
+fn position(horizontal: Option<f32>, vertical: Option<f32>) -> String {
+    if horizontal.is_none() && vertical.is_none() {
+        return String::new();
+    }
+
+    format!(
+        "position: absolute; left: {}; top: {};",
+        length(horizontal.unwrap_or_default()),
+        length(vertical.unwrap_or_default())
+    )
+}
+
+/// The CSS properties to align a block element within its parent.
+fn alignment(alignment: Option<LayoutAlignment>) -> String {
+    match alignment.map(|alignment| alignment.alignment_horizontal()) {
+        Some(HorizontalAlignment::Center) => "margin-left: auto; margin-right: auto;".to_string(),
+        Some(HorizontalAlignment::Right) => "margin-left: auto;".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// The display size of an image in half-inch increments.
+fn image_size(image: &Image) -> (Option<f32>, Option<f32>) {
+    (
+        image.layout_max_width().or_else(|| image.picture_width()),
+        image.layout_max_height().or_else(|| image.picture_height()),
+    )
+}
+
+/// Convert a length in half-inch increments to CSS.
+fn length(half_inches: f32) -> String {
+    format!("{}in", half_inches / 2.0)
+}
+
+fn rgba(color: Color) -> String {
+    format!(
+        "rgba({}, {}, {}, {})",
+        color.r(),
+        color.g(),
+        color.b(),
+        color.alpha() as f32 / 255.0
+    )
+}
+
+fn image_mime_type(extension: &str) -> &'static str {
+    match extension.trim_start_matches('.').to_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+        let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(value >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::{base64, format_number, length};
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(3, '\0'), "3");
+        assert_eq!(format_number(14, '\u{1}'), "XIV");
+        assert_eq!(format_number(4, '\u{2}'), "iv");
+        assert_eq!(format_number(28, '\u{3}'), "AB");
+        assert_eq!(format_number(2, '\u{4}'), "b");
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(length(3.0), "1.5in");
+    }
+}
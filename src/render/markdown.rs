@@ -0,0 +1,745 @@
+use crate::onenote::content::Content;
+use crate::onenote::embedded_file::EmbeddedFile;
+use crate::onenote::image::Image;
+use crate::onenote::list::List;
+use crate::onenote::notebook::Notebook;
+use crate::onenote::outline::{Outline, OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onenote::printout::Printout;
+use crate::onenote::rich_text::{ParagraphStyling, RichText};
+use crate::onenote::section::{Section, SectionEntry};
+use crate::onenote::table::Table;
+use crate::render::{is_safe_url, text_runs, LIST_FORMAT_NUMBER};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+/// A file linked from a Markdown document (e.g. an image or an attachment).
+#[derive(Clone, Debug)]
+pub struct LinkedFile {
+    path: String,
+    data: Vec<u8>,
+}
+
+impl LinkedFile {
+    /// The file's path relative to the Markdown document, using `/` as the separator.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The file contents.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A rendered Markdown document.
+#[derive(Clone, Debug)]
+pub struct Markdown {
+    text: String,
+    files: Vec<LinkedFile>,
+}
+
+impl Markdown {
+    /// The document's Markdown text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The files linked from the document.
+    ///
+    /// These have to be stored next to the document for the links to resolve.
+    pub fn files(&self) -> &[LinkedFile] {
+        &self.files
+    }
+}
+
+/// A renderer converting pages and sections to CommonMark.
+///
+/// Tables and task lists use the GitHub Flavored Markdown extensions. Images and embedded
+/// files are linked from the document and returned as [`LinkedFile`]s that are stored in
+/// the attachment directory (`attachments` by default). File names are unique across all
+/// documents rendered by the same renderer. Ink isn't rendered.
+#[derive(Debug)]
+pub struct MarkdownRenderer {
+    attachment_dir: String,
+    file_names: HashSet<String>,
+}
+
+impl MarkdownRenderer {
+    /// Create a new Markdown renderer.
+    pub fn new() -> MarkdownRenderer {
+        MarkdownRenderer {
+            attachment_dir: "attachments".to_string(),
+            file_names: HashSet::new(),
+        }
+    }
+
+    /// Set the directory (relative to the rendered documents) that linked files are stored in.
+    pub fn with_attachment_dir(mut self, dir: &str) -> MarkdownRenderer {
+        self.attachment_dir = dir.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Render a page.
+    ///
+    /// The page's title is rendered as a level 1 heading.
+    pub fn render_page(&mut self, page: &Page) -> Markdown {
+        let mut document = Document::new(self);
+        document.render_page(page, 1);
+        document.finish()
+    }
+
+    /// Render all pages of a section into a single document.
+    ///
+    /// The section's name is rendered as a level 1 heading, followed by the section's pages
+    /// with their titles as level 2 headings (or lower for sub-pages).
+    pub fn render_section(&mut self, section: &Section) -> Markdown {
+        let mut document = Document::new(self);
+        document.heading(1, section.display_name());
+
+        for page in section
+            .page_series()
+            .iter()
+            .flat_map(|series| series.pages())
+        {
+            let level = (page.level().max(1) as usize + 1).min(6);
+            document.render_page(page, level);
+        }
+
+        document.finish()
+    }
+
+    /// Render all sections of a notebook.
+    ///
+    /// This returns a document for each section along with the document's path relative to
+    /// the notebook root. Sections contained in section groups are stored in a directory
+    /// named after the section group.
+    pub fn render_notebook(&mut self, notebook: &Notebook) -> Vec<(String, Markdown)> {
+        let mut documents = vec![];
+        self.render_entries(notebook.entries(), "", &mut documents);
+
+        documents
+    }
+
+    fn render_entries(
+        &mut self,
+        entries: &[SectionEntry],
+        dir: &str,
+        documents: &mut Vec<(String, Markdown)>,
+    ) {
+        for entry in entries {
+            match entry {
+                SectionEntry::Section(section) => {
+                    let path = format!("{}{}.md", dir, file_name(section.display_name()));
+                    documents.push((path, self.render_section(section)));
+                }
+                SectionEntry::SectionGroup(group) => {
+                    let dir = format!("{}{}/", dir, file_name(group.display_name()));
+                    self.render_entries(group.entries(), &dir, documents);
+                }
+            }
+        }
+    }
+
+    /// Reserve a unique path in the attachment directory for a file.
+    fn reserve_path(&mut self, name: &str) -> String {
+        let name = file_name(name);
+        let (stem, extension) = match name.rfind('.') {
+            Some(index) if index > 0 => name.split_at(index),
+            _ => (name.as_str(), ""),
+        };
+
+        let mut candidate = name.clone();
+        let mut counter = 1;
+
+        while !self.file_names.insert(candidate.clone()) {
+            candidate = format!("{}-{}{}", stem, counter, extension);
+            counter += 1;
+        }
+
+        if self.attachment_dir.is_empty() {
+            candidate
+        } else {
+            format!("{}/{}", self.attachment_dir, candidate)
+        }
+    }
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A Markdown document being rendered.
+struct Document<'r> {
+    renderer: &'r mut MarkdownRenderer,
+    text: String,
+    files: Vec<LinkedFile>,
+
+    /// Whether the last block was a list item (so that lists are rendered as tight lists).
+    after_list_item: bool,
+}
+
+impl<'r> Document<'r> {
+    fn new(renderer: &'r mut MarkdownRenderer) -> Document<'r> {
+        Document {
+            renderer,
+            text: String::new(),
+            files: vec![],
+            after_list_item: false,
+        }
+    }
+
+    fn finish(mut self) -> Markdown {
+        if !self.text.is_empty() {
+            self.text.push('\n');
+        }
+
+        Markdown {
+            text: self.text,
+            files: self.files,
+        }
+    }
+
+    /// Append a block.
+    ///
+    /// The first line of the block is prefixed with `first_prefix`, all following lines
+    /// are prefixed with `prefix`.
+    fn block(&mut self, first_prefix: &str, prefix: &str, content: &str, list_item: bool) {
+        // Skip empty paragraphs (but keep empty list items)
+        if content.trim().is_empty() && first_prefix.trim().is_empty() {
+            return;
+        }
+
+        if !self.text.is_empty() {
+            if list_item && self.after_list_item {
+                self.text.push('\n');
+            } else {
+                self.text.push_str("\n\n");
+            }
+        }
+
+        for (i, line) in content.split('\n').enumerate() {
+            if i == 0 {
+                self.text.push_str(first_prefix);
+            } else {
+                self.text.push('\n');
+                self.text.push_str(prefix);
+            }
+
+            self.text.push_str(line);
+        }
+
+        self.after_list_item = list_item;
+    }
+
+    fn heading(&mut self, level: usize, text: &str) {
+        let text = escape(&text.replace(['\n', '\r', '\u{b}'], " "));
+        self.block(&"#".repeat(level), "", &format!(" {}", text), false);
+    }
+
+    fn render_page(&mut self, page: &Page, level: usize) {
+        if let Some(title) = page.title_text() {
+            self.heading(level, title);
+        }
+
+        for content in page.contents() {
+            match content {
+                PageContent::Outline(outline) => self.render_outline(outline, level),
+                PageContent::Image(image) => {
+                    let image = self.image(image);
+                    self.block("", "", &image, false);
+                }
+                PageContent::EmbeddedFile(file) => {
+                    let file = self.embedded_file(file);
+                    self.block("", "", &file, false);
+                }
+                PageContent::Printout(printout) => {
+                    for image in printout.pages() {
+                        let image = self.image(image);
+                        self.block("", "", &image, false);
+                    }
+                }
+                PageContent::Ink(_) | PageContent::Unknown(_) => {}
+            }
+        }
+    }
+
+    fn render_outline(&mut self, outline: &Outline, heading_offset: usize) {
+        // Don't continue lists from the previous outline
+        self.after_list_item = false;
+
+        self.render_items(outline.items(), 0, heading_offset);
+    }
+
+    /// Render outline items.
+    ///
+    /// Markdown can only express nesting through lists, so the children of an outline element
+    /// are only indented if the element is a list item.
+    fn render_items(&mut self, items: &[OutlineItem], indent: usize, heading_offset: usize) {
+        for item in items {
+            match item {
+                OutlineItem::Group(group) => {
+                    self.render_items(group.outlines(), indent, heading_offset)
+                }
+                OutlineItem::Element(element) => {
+                    self.render_element(element, indent, heading_offset)
+                }
+            }
+        }
+    }
+
+    fn render_element(&mut self, element: &OutlineElement, indent: usize, heading_offset: usize) {
+        let list_marker = element.list_contents().first().map(list_marker);
+        let task_marker = task_marker(element);
+
+        let is_list_item = list_marker.is_some() || task_marker.is_some();
+        let marker = match (list_marker, task_marker) {
+            (Some(list), Some(task)) => format!("{}{}", list, task),
+            (Some(list), None) => list,
+            (None, Some(task)) => format!("- {}", task),
+            (None, None) => String::new(),
+        };
+
+        // The content of list items starts after the list marker (but before the task box)
+        let marker_width = if is_list_item {
+            marker.find('[').unwrap_or(marker.len())
+        } else {
+            0
+        };
+
+        let first_prefix = format!("{}{}", " ".repeat(indent), marker);
+        let content_indent = indent + marker_width;
+        let prefix = " ".repeat(content_indent);
+
+        let mut contents = element.contents().iter();
+
+        match contents.next() {
+            Some(content) => {
+                let (content, heading) = self.content(content, heading_offset);
+                let first_prefix = match heading {
+                    Some(level) if !is_list_item => {
+                        format!("{}{} ", first_prefix, "#".repeat(level))
+                    }
+                    _ => first_prefix,
+                };
+
+                self.block(&first_prefix, &prefix, &content, is_list_item);
+            }
+            None if is_list_item => self.block(&first_prefix, &prefix, "", true),
+            None => {}
+        }
+
+        for content in contents {
+            let (content, _) = self.content(content, heading_offset);
+            self.block(&prefix, &prefix, &content, false);
+        }
+
+        self.render_items(element.children(), content_indent, heading_offset);
+    }
+
+    /// Render an outline element's content.
+    ///
+    /// Returns the rendered content and the heading level if the content is a heading.
+    fn content(&mut self, content: &Content, heading_offset: usize) -> (String, Option<usize>) {
+        match content {
+            Content::RichText(text) => {
+                let heading = heading_level(text).map(|level| (level + heading_offset).min(6));
+                (rich_text(text, "\\\n"), heading)
+            }
+            Content::Table(table) => (self.table(table), None),
+            Content::Image(image) => (self.image(image), None),
+            Content::EmbeddedFile(file) => (self.embedded_file(file), None),
+            Content::Printout(printout) => (self.printout(printout, "\n\n"), None),
+            Content::Ink(_) | Content::Unknown(_) => (String::new(), None),
+        }
+    }
+
+    /// Render a table as a GFM table.
+    ///
+    /// GFM tables always have a header row so the first table row is used as the header.
+    fn table(&mut self, table: &Table) -> String {
+        let rows: Vec<Vec<String>> = table
+            .contents()
+            .iter()
+            .map(|row| {
+                row.contents()
+                    .iter()
+                    .map(|cell| {
+                        cell.contents()
+                            .iter()
+                            .map(|element| self.inline_element(element))
+                            .filter(|text| !text.is_empty())
+                            .join("<br>")
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let cols = rows
+            .iter()
+            .map(|row| row.len())
+            .chain(std::iter::once(table.cols() as usize))
+            .max()
+            .unwrap_or_default()
+            .max(1);
+
+        let format_row = |row: &[String]| {
+            let cells = (0..cols).map(|i| row.get(i).map(|cell| cell.as_str()).unwrap_or(""));
+
+            format!("| {} |", cells.format(" | "))
+        };
+
+        let mut lines = vec![];
+        let mut rows = rows.iter();
+
+        lines.push(format_row(rows.next().map(|row| &row[..]).unwrap_or(&[])));
+        lines.push(format!("|{}", " --- |".repeat(cols)));
+        lines.extend(rows.map(|row| format_row(row)));
+
+        lines.join("\n")
+    }
+
+    /// Render the pages of a printout as images.
+    fn printout(&mut self, printout: &Printout, separator: &str) -> String {
+        printout
+            .pages()
+            .iter()
+            .map(|image| self.image(image))
+            .join(separator)
+    }
+
+    /// Render an outline element (and its children) on a single line for use in a table.
+    fn inline_element(&mut self, element: &OutlineElement) -> String {
+        let task = task_marker(element).unwrap_or_default();
+
+        let contents = element
+            .contents()
+            .iter()
+            .map(|content| match content {
+                Content::RichText(text) => rich_text(text, "<br>"),
+                Content::Table(table) => self
+                    .table(table)
+                    .lines()
+                    .filter(|line| !line.starts_with("| ---"))
+                    .join("<br>"),
+                Content::Image(image) => self.image(image),
+                Content::EmbeddedFile(file) => self.embedded_file(file),
+                Content::Printout(printout) => self.printout(printout, "<br>"),
+                Content::Ink(_) | Content::Unknown(_) => String::new(),
+            })
+            .filter(|text| !text.is_empty())
+            .collect_vec();
+
+        let children = element
+            .children()
+            .iter()
+            .flat_map(|item| match item {
+                OutlineItem::Element(element) => vec![element],
+                OutlineItem::Group(group) => group
+                    .outlines()
+                    .iter()
+                    .filter_map(|item| item.element())
+                    .collect(),
+            })
+            .map(|child| self.inline_element(child))
+            .collect_vec();
+
+        let text = contents.into_iter().chain(children).join("<br>");
+
+        format!("{}{}", task, text)
+    }
+
+    fn image(&mut self, image: &Image) -> String {
+        let alt_text = escape(image.alt_text().unwrap_or_default());
+
+        let data = match image.data() {
+            Some(data) => data,
+            None => return alt_text,
+        };
+
+        let name = image
+            .image_filename()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("image{}", image.extension().unwrap_or_default()));
+
+        let path = self.add_file(&name, data);
+        let link = format!("![{}]({})", alt_text, encode_path(&path));
+
+        match image.hyperlink_url().filter(|url| is_safe_url(url)) {
+            Some(url) => format!("[{}]({})", link, encode_url(url)),
+            None => link,
+        }
+    }
+
+    fn embedded_file(&mut self, file: &EmbeddedFile) -> String {
+        let path = self.add_file(file.filename(), file.data());
+
+        format!("[{}]({})", escape(file.filename()), encode_path(&path))
+    }
+
+    fn add_file(&mut self, name: &str, data: &[u8]) -> String {
+        let path = self.renderer.reserve_path(name);
+
+        self.files.push(LinkedFile {
+            path: path.clone(),
+            data: data.to_vec(),
+        });
+
+        path
+    }
+}
+
+/// Render a rich text paragraph including its inline formatting.
+///
+/// Line breaks within the paragraph are replaced by `line_break`.
+fn rich_text(text: &RichText, line_break: &str) -> String {
+    let runs = text_runs(text)
+        .into_iter()
+        .map(|(text, style, link)| (text, Formatting::from_style(style), link))
+        .coalesce(|(a, a_style, a_link), (b, b_style, b_link)| {
+            if a_style == b_style && a_link == b_link {
+                Ok((a + &b, a_style, a_link))
+            } else {
+                Err(((a, a_style, a_link), (b, b_style, b_link)))
+            }
+        });
+
+    let mut output = String::new();
+
+    for (text, formatting, link) in runs {
+        let content = text.trim();
+        if content.is_empty() || (formatting == Formatting::default() && link.is_none()) {
+            output.push_str(&escape(&text));
+            continue;
+        }
+
+        let leading = &text[..text.len() - text.trim_start().len()];
+        let trailing = &text[text.trim_end().len()..];
+
+        output.push_str(&escape(leading));
+
+        if link.is_some() {
+            output.push('[');
+        }
+
+        output.push_str(&formatting.markers());
+        output.push_str(&escape(content));
+        output.push_str(&formatting.markers().chars().rev().collect::<String>());
+
+        if let Some(url) = link {
+            output.push_str(&format!("]({})", encode_url(url)));
+        }
+
+        output.push_str(&escape(trailing));
+    }
+
+    output
+        .replace("\r\n", "\n")
+        .replace(['\r', '\u{b}'], "\n")
+        .replace('\n', line_break)
+}
+
+/// The inline formatting supported by Markdown.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+struct Formatting {
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+}
+
+impl Formatting {
+    fn from_style(style: &ParagraphStyling) -> Formatting {
+        Formatting {
+            bold: style.bold(),
+            italic: style.italic(),
+            strikethrough: style.strikethrough(),
+        }
+    }
+
+    fn markers(&self) -> String {
+        let mut markers = String::new();
+
+        if self.strikethrough {
+            markers.push_str("~~");
+        }
+
+        if self.bold {
+            markers.push_str("**");
+        }
+
+        if self.italic {
+            markers.push('*');
+        }
+
+        markers
+    }
+}
+
+/// Determine the heading level of a paragraph from its style (`h1` to `h6`).
+fn heading_level(text: &RichText) -> Option<usize> {
+    let style_id = text.paragraph_style().style_id()?;
+    let level = style_id.strip_prefix('h')?.parse::<usize>().ok()?;
+
+    Some(level).filter(|level| (1..=6).contains(level))
+}
+
+fn list_marker(list: &List) -> String {
+    if list.list_format().contains(&LIST_FORMAT_NUMBER) {
+        format!("{}. ", list.list_restart().unwrap_or(1).max(0))
+    } else {
+        "- ".to_string()
+    }
+}
+
+/// The GFM task list marker of an outline element with a checkable note tag.
+fn task_marker(element: &OutlineElement) -> Option<&'static str> {
+    let tag = element
+        .contents()
+        .iter()
+        .filter_map(|content| content.rich_text())
+        .flat_map(|text| text.note_tags())
+        .find(|tag| {
+            tag.definition()
+                .map(|definition| definition.shape().is_checkable())
+                .unwrap_or_default()
+        })?;
+
+    if tag.item_status().completed() {
+        Some("[x] ")
+    } else {
+        Some("[ ] ")
+    }
+}
+
+/// Escape characters that would otherwise be interpreted as Markdown syntax.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '#' | '&'
+        ) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+    }
+
+    // Prevent the text from being interpreted as a list item
+    let digits = escaped.chars().take_while(|c| c.is_ascii_digit()).count();
+    match escaped[digits..].chars().next() {
+        Some('-' | '+') if digits == 0 => escaped.insert(0, '\\'),
+        Some('.' | ')') if digits > 0 => escaped.insert(digits, '\\'),
+        _ => {}
+    }
+
+    escaped
+}
+
+/// Turn a section or file name into a file name that's safe to use on all platforms.
+fn file_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let name = name.trim().trim_start_matches('.');
+
+    if name.is_empty() {
+        "file".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Percent-encode a relative path for use as a link destination.
+fn encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Encode a URL for use as a link destination.
+fn encode_url(url: &str) -> String {
+    url.replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+        .replace('<', "%3C")
+        .replace('>', "%3E")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{encode_path, escape, file_name};
+    use crate::builder::{
+        OutlineBuilder, OutlineElementBuilder, PageBuilder, RichTextBuilder, SectionBuilder,
+    };
+    use crate::property::rich_text::ParagraphStyling;
+    use crate::render::MarkdownRenderer;
+    use crate::Parser;
+
+    fn render_link(url: &str) -> String {
+        let mut link = ParagraphStyling::new();
+        link.hyperlink = true;
+
+        let text = RichTextBuilder::new("See ")
+            .with_run(
+                &format!("\u{fddf}HYPERLINK \"{}\"", url),
+                ParagraphStyling::new(),
+            )
+            .with_run("this", link);
+        let builder =
+            SectionBuilder::new("Section").with_page(PageBuilder::new("Links").with_outline(
+                OutlineBuilder::new().with_element(OutlineElementBuilder::new().with_text(text)),
+            ));
+        let data = builder.to_bytes().unwrap();
+
+        let section = Parser::new()
+            .open_section(&data, &builder.file_name())
+            .unwrap();
+        let page = section.page(&section.pages()[0]).unwrap();
+
+        MarkdownRenderer::new()
+            .render_page(&page)
+            .text()
+            .to_string()
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a *b* [c]"), "a \\*b\\* \\[c\\]");
+        assert_eq!(escape("- not a list"), "\\- not a list");
+        assert_eq!(escape("2021. A year"), "2021\\. A year");
+        assert_eq!(escape("#hashtag"), "\\#hashtag");
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert_eq!(file_name("a/b: c?.png"), "a_b_ c_.png");
+        assert_eq!(file_name("..."), "file");
+        assert_eq!(
+            encode_path("attachments/my file (1).png"),
+            "attachments/my%20file%20%281%29.png"
+        );
+    }
+
+    #[test]
+    fn test_unsafe_links() {
+        assert!(render_link("https://example.com").contains("See [this](https://example.com)"));
+        assert!(render_link("javascript:alert(1)").contains("See this"));
+        assert!(render_link("data:text/html;base64,PHNjcmlwdD4=").contains("See this"));
+    }
+}
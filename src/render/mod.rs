@@ -0,0 +1,96 @@
+//! Renderers converting parsed OneNote data into other document formats.
+
+use crate::onenote::rich_text::{ParagraphStyling, RichText};
+
+pub use crate::render::html::HtmlRenderer;
+pub use crate::render::markdown::{LinkedFile, Markdown, MarkdownRenderer};
+
+mod html;
+mod markdown;
+pub(crate) mod svg;
+
+/// The list format character marking a numbered list.
+///
+/// See [\[MS-ONE\] 2.3.20].
+///
+/// [\[MS-ONE\] 2.3.20]: https://docs.microsoft.com/en-us/openspecs/office_file_formats/ms-one/587f8d1c-e0c3-434f-8e02-c9b4e710c0b3
+const LIST_FORMAT_NUMBER: char = '\u{fffd}';
+
+/// Split a paragraph's text into its text runs.
+///
+/// Each text run is returned along with its formatting and the URL of the hyperlink it
+/// belongs to. Links with unsafe URLs are left out so they are rendered as plain text.
+/// The text run indices are offsets in UTF-16 code units.
+fn text_runs(text: &RichText) -> Vec<(String, &ParagraphStyling, Option<&str>)> {
+    let styles = text.text_run_formatting();
+    let style = |index: usize| styles.get(index).unwrap_or(text.paragraph_style());
+    let link = |start: usize| {
+        text.links()
+            .iter()
+            .find(|link| link.display_range().contains(&(start as u32)))
+            .map(|link| link.url())
+            .filter(|url| is_safe_url(url))
+    };
+
+    let mut runs = vec![];
+    let mut run = String::new();
+    let mut run_start = 0;
+    let mut offset = 0;
+    let mut boundaries = text.text_run_indices().iter().peekable();
+
+    for c in text.text().chars() {
+        while boundaries
+            .next_if(|index| **index as usize <= offset)
+            .is_some()
+        {
+            runs.push((std::mem::take(&mut run), style(runs.len()), link(run_start)));
+            run_start = offset;
+        }
+
+        run.push(c);
+        offset += c.len_utf16();
+    }
+
+    runs.push((run, style(runs.len()), link(run_start)));
+
+    runs
+}
+
+/// Check whether a URL is safe to use as a link target.
+///
+/// Only web, mail and OneNote links as well as relative links are allowed so the
+/// rendered document can't run scripts through `javascript:` or `data:` links.
+fn is_safe_url(url: &str) -> bool {
+    const SCHEMES: [&str; 4] = ["http", "https", "mailto", "onenote"];
+
+    // Browsers ignore these characters when parsing a URL's scheme
+    let url: String = url
+        .trim_matches(|c: char| c <= ' ')
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+
+    match url.find([':', '/', '?', '#']) {
+        Some(index) if url[index..].starts_with(':') => SCHEMES
+            .iter()
+            .any(|scheme| url[..index].eq_ignore_ascii_case(scheme)),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_safe_url;
+
+    #[test]
+    fn test_is_safe_url() {
+        assert!(is_safe_url("https://example.com/?q=a:b"));
+        assert!(is_safe_url("onenote:#Page&section-id={5EAF76B1}"));
+        assert!(is_safe_url("MAILTO:user@example.com"));
+        assert!(is_safe_url("../Notes/page.html#top"));
+        assert!(!is_safe_url("javascript:alert(1)"));
+        assert!(!is_safe_url(" Java\tScript:alert(1)"));
+        assert!(!is_safe_url("data:text/html;base64,PHNjcmlwdD4="));
+        assert!(!is_safe_url("vbscript:msgbox"));
+    }
+}
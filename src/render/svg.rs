@@ -0,0 +1,254 @@
+use crate::one::property::color_ref::ColorRef;
+use crate::onenote::ink::{Ink, InkBoundingBox, InkStroke};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use std::fmt::Write;
+
+/// The pen tip value of a rectangular pen tip. All other pen tips are round.
+const PEN_TIP_RECTANGLE: u8 = 1;
+
+/// Render an ink object as a standalone SVG image.
+///
+/// The SVG's view box is the ink object's bounding box in half-inch increments.
+pub(crate) fn render_ink(ink: &Ink) -> String {
+    let bounds = ink_bounds(ink).unwrap_or(InkBoundingBox {
+        x: 0.0,
+        y: 0.0,
+        height: 0.0,
+        width: 0.0,
+    });
+
+    let mut body = String::new();
+    render_strokes(&mut body, ink.ink_strokes());
+
+    svg(bounds, &body)
+}
+
+/// Render all ink objects placed on a page as a single SVG image.
+///
+/// The SVG's origin is the page origin so it can be laid over the rendered page. Ink that
+/// is part of an outline is positioned relative to the surrounding text and isn't included.
+pub(crate) fn render_page(page: &Page) -> Option<String> {
+    let inks = page
+        .contents()
+        .iter()
+        .filter_map(|content| match content {
+            PageContent::Ink(ink) => Some(ink),
+            _ => None,
+        })
+        .filter_map(|ink| {
+            let bounds = ink_bounds(ink)?;
+            let dx = ink.offset_horizontal().unwrap_or_default();
+            let dy = ink.offset_vertical().unwrap_or_default();
+
+            Some((ink, bounds, dx, dy))
+        })
+        .collect::<Vec<_>>();
+
+    if inks.is_empty() {
+        return None;
+    }
+
+    let mut width = 0f32;
+    let mut height = 0f32;
+    let mut body = String::new();
+
+    for (ink, bounds, dx, dy) in inks {
+        width = width.max(dx + bounds.x + bounds.width);
+        height = height.max(dy + bounds.y + bounds.height);
+
+        let _ = writeln!(body, "<g transform=\"translate({} {})\">", dx, dy);
+        render_strokes(&mut body, ink.ink_strokes());
+        body.push_str("</g>\n");
+    }
+
+    let bounds = InkBoundingBox {
+        x: 0.0,
+        y: 0.0,
+        height,
+        width,
+    };
+
+    Some(svg(bounds, &body))
+}
+
+/// The area covered by an ink object's strokes in half-inch increments.
+///
+/// The bounding box stored with the ink data is preferred. If it's missing, the bounding
+/// box is calculated from the stroke paths. In both cases it's extended by the pen size so
+/// that strokes along the edges aren't clipped.
+pub(crate) fn ink_bounds(ink: &Ink) -> Option<InkBoundingBox> {
+    let pen_size = ink.ink_strokes().iter().map(pen_size).fold(0f32, f32::max);
+
+    let bounds = ink.bounding_box().or_else(|| {
+        let mut points = ink
+            .ink_strokes()
+            .iter()
+            .flat_map(|stroke| stroke.path())
+            .map(|point| (point.x(), point.y()));
+        let (x, y) = points.next()?;
+
+        let (x_min, y_min, x_max, y_max) =
+            points.fold((x, y, x, y), |(x_min, y_min, x_max, y_max), (x, y)| {
+                (x_min.min(x), y_min.min(y), x_max.max(x), y_max.max(y))
+            });
+
+        Some(InkBoundingBox {
+            x: x_min,
+            y: y_min,
+            height: y_max - y_min,
+            width: x_max - x_min,
+        })
+    })?;
+
+    Some(InkBoundingBox {
+        x: bounds.x - pen_size / 2.0,
+        y: bounds.y - pen_size / 2.0,
+        height: bounds.height + pen_size,
+        width: bounds.width + pen_size,
+    })
+}
+
+fn svg(bounds: InkBoundingBox, body: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}in\" height=\"{}in\" \
+         viewBox=\"{} {} {} {}\">\n{}</svg>\n",
+        bounds.width / 2.0,
+        bounds.height / 2.0,
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+        body
+    )
+}
+
+fn render_strokes(out: &mut String, strokes: &[InkStroke]) {
+    for stroke in strokes {
+        render_stroke(out, stroke);
+    }
+}
+
+fn render_stroke(out: &mut String, stroke: &InkStroke) {
+    let (start, rest) = match stroke.path().split_first() {
+        Some(path) => path,
+        None => return,
+    };
+
+    let mut path = format!("M {} {}", start.x(), start.y());
+
+    if rest.is_empty() {
+        // Draw a dot for single point strokes
+        path.push_str(" l 0 0");
+    }
+
+    for point in rest {
+        let _ = write!(path, " L {} {}", point.x(), point.y());
+    }
+
+    let (cap, join) = if stroke.pen_tip() == Some(PEN_TIP_RECTANGLE) {
+        ("square", "miter")
+    } else {
+        ("round", "round")
+    };
+
+    let _ = write!(
+        out,
+        "<path d=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" \
+         stroke-linecap=\"{}\" stroke-linejoin=\"{}\"",
+        path,
+        color(stroke.color()),
+        pen_size(stroke),
+        cap,
+        join
+    );
+
+    // Highlighters are drawn using a partially transparent pen
+    if let Some(transparency) = stroke.transparency().filter(|t| *t > 0) {
+        let _ = write!(
+            out,
+            " stroke-opacity=\"{}\"",
+            (255 - transparency) as f32 / 255.0
+        );
+    }
+
+    out.push_str("/>\n");
+}
+
+/// The stroke width used to draw a stroke.
+///
+/// SVG doesn't support elliptic or rectangular pens so the larger pen dimension is used.
+fn pen_size(stroke: &InkStroke) -> f32 {
+    stroke.width().max(stroke.height())
+}
+
+/// Convert an ink color to CSS. Strokes without a color are black.
+fn color(color: Option<ColorRef>) -> String {
+    match color {
+        Some(ColorRef::Manual { r, g, b }) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Some(ColorRef::Auto) | None => "#000000".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ink_bounds, render_ink};
+    use crate::one::property::color_ref::ColorRef;
+    use crate::onenote::ink::{Ink, InkPoint, InkStroke};
+
+    fn stroke(path: &[(f32, f32)], pen_tip: Option<u8>, transparency: Option<u8>) -> InkStroke {
+        InkStroke {
+            path: path.iter().map(|&(x, y)| InkPoint { x, y }).collect(),
+            pen_tip,
+            transparency,
+            height: 0.5,
+            width: 0.5,
+            color: Some(ColorRef::Manual {
+                r: 0xFF,
+                g: 0x80,
+                b: 0x00,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_ink_bounds() {
+        let ink = Ink {
+            ink_strokes: vec![stroke(&[(1.0, 2.0), (1.5, 1.0)], None, None)],
+            bounding_box: None,
+            offset_horizontal: None,
+            offset_vertical: None,
+        };
+
+        let bounds = ink_bounds(&ink).unwrap();
+        assert_eq!(bounds.x(), 0.75);
+        assert_eq!(bounds.y(), 0.75);
+        assert_eq!(bounds.width(), 1.0);
+        assert_eq!(bounds.height(), 1.5);
+    }
+
+    #[test]
+    fn test_render_ink() {
+        let ink = Ink {
+            ink_strokes: vec![
+                stroke(&[(0.0, 0.0), (2.0, 1.0)], None, None),
+                stroke(&[(0.0, 0.0)], Some(1), Some(128)),
+            ],
+            bounding_box: None,
+            offset_horizontal: None,
+            offset_vertical: None,
+        };
+
+        assert_eq!(
+            render_ink(&ink),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"1.25in\" \
+             height=\"0.75in\" viewBox=\"-0.25 -0.25 2.5 1.5\">\n\
+             <path d=\"M 0 0 L 2 1\" fill=\"none\" stroke=\"#ff8000\" \
+             stroke-width=\"0.5\" stroke-linecap=\"round\" stroke-linejoin=\"round\"/>\n\
+             <path d=\"M 0 0 l 0 0\" fill=\"none\" stroke=\"#ff8000\" stroke-width=\"0.5\" \
+             stroke-linecap=\"square\" stroke-linejoin=\"miter\" \
+             stroke-opacity=\"0.49803922\"/>\n\
+             </svg>\n"
+        );
+    }
+}
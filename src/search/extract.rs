@@ -0,0 +1,219 @@
+use crate::onenote::content::Content;
+use crate::onenote::embedded_file::EmbeddedFile;
+use crate::onenote::image::Image;
+use crate::onenote::notebook::Notebook;
+use crate::onenote::outline::{OutlineElement, OutlineItem};
+use crate::onenote::page::Page;
+use crate::onenote::page_content::PageContent;
+use crate::onenote::section::{Section, SectionEntry};
+use crate::shared::guid::Guid;
+
+/// The plain text extracted from a page.
+#[derive(Clone, Debug)]
+pub struct PageText {
+    pub(crate) section_path: Vec<String>,
+    pub(crate) page_id: Guid,
+    pub(crate) title: Option<String>,
+    pub(crate) paragraphs: Vec<Paragraph>,
+}
+
+impl PageText {
+    /// The names of the section groups and the section containing the page.
+    ///
+    /// This is empty if the page was extracted on its own.
+    pub fn section_path(&self) -> &[String] {
+        &self.section_path
+    }
+
+    /// The page's unique ID.
+    pub fn page_id(&self) -> Guid {
+        self.page_id
+    }
+
+    /// The page's title text.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// The page's text paragraphs in document order.
+    pub fn paragraphs(&self) -> &[Paragraph] {
+        &self.paragraphs
+    }
+}
+
+/// A text paragraph extracted from a page.
+#[derive(Clone, Debug)]
+pub struct Paragraph {
+    pub(crate) kind: ParagraphKind,
+    pub(crate) text: String,
+}
+
+impl Paragraph {
+    /// Where the text was extracted from.
+    pub fn kind(&self) -> ParagraphKind {
+        self.kind
+    }
+
+    /// The paragraph's plain text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// The source of an extracted paragraph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParagraphKind {
+    /// A paragraph of the page title.
+    Title,
+
+    /// A rich text paragraph.
+    Text,
+
+    /// A paragraph in a table cell.
+    TableCell,
+
+    /// An image's alternative text.
+    ImageAltText,
+
+    /// The text recognized in an image.
+    ImageText,
+
+    /// The file name of an embedded file.
+    EmbeddedFile,
+}
+
+/// Extract the text of all pages in a notebook.
+pub fn extract_notebook(notebook: &Notebook) -> Vec<PageText> {
+    let mut pages = vec![];
+    extract_entries(notebook.entries(), &[], &mut pages);
+
+    pages
+}
+
+/// Extract the text of all pages in a section.
+pub fn extract_section(section: &Section) -> Vec<PageText> {
+    extract_section_pages(section, vec![section.display_name().to_string()])
+}
+
+/// Extract the text of a single page.
+pub fn extract_page(page: &Page) -> PageText {
+    extract_page_text(page, vec![])
+}
+
+fn extract_entries(entries: &[SectionEntry], parents: &[String], pages: &mut Vec<PageText>) {
+    for entry in entries {
+        match entry {
+            SectionEntry::Section(section) => {
+                let mut path = parents.to_vec();
+                path.push(section.display_name().to_string());
+
+                pages.extend(extract_section_pages(section, path));
+            }
+            SectionEntry::SectionGroup(group) => {
+                let mut path = parents.to_vec();
+                path.push(group.display_name().to_string());
+
+                extract_entries(group.entries(), &path, pages);
+            }
+        }
+    }
+}
+
+fn extract_section_pages(section: &Section, path: Vec<String>) -> Vec<PageText> {
+    section
+        .page_series()
+        .iter()
+        .flat_map(|series| series.pages())
+        .map(|page| extract_page_text(page, path.clone()))
+        .collect()
+}
+
+fn extract_page_text(page: &Page, section_path: Vec<String>) -> PageText {
+    let mut paragraphs = vec![];
+
+    for outline in page.title().iter().flat_map(|title| title.contents()) {
+        extract_items(outline.items(), ParagraphKind::Title, &mut paragraphs);
+    }
+
+    for content in page.contents() {
+        match content {
+            PageContent::Outline(outline) => {
+                extract_items(outline.items(), ParagraphKind::Text, &mut paragraphs)
+            }
+            PageContent::Image(image) => extract_image(image, &mut paragraphs),
+            PageContent::EmbeddedFile(file) => extract_embedded_file(file, &mut paragraphs),
+            PageContent::Printout(printout) => {
+                for image in printout.pages() {
+                    extract_image(image, &mut paragraphs);
+                }
+            }
+            PageContent::Ink(_) | PageContent::Unknown(_) => {}
+        }
+    }
+
+    PageText {
+        section_path,
+        page_id: page.id(),
+        title: page.title_text().map(|title| title.to_string()),
+        paragraphs,
+    }
+}
+
+fn extract_items(items: &[OutlineItem], kind: ParagraphKind, paragraphs: &mut Vec<Paragraph>) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => extract_items(group.outlines(), kind, paragraphs),
+            OutlineItem::Element(element) => extract_element(element, kind, paragraphs),
+        }
+    }
+}
+
+fn extract_element(element: &OutlineElement, kind: ParagraphKind, paragraphs: &mut Vec<Paragraph>) {
+    for content in element.contents() {
+        match content {
+            Content::RichText(text) => push(paragraphs, kind, text.text()),
+            Content::Table(table) => {
+                let cells = table.contents().iter().flat_map(|row| row.contents());
+
+                for element in cells.flat_map(|cell| cell.contents()) {
+                    extract_element(element, ParagraphKind::TableCell, paragraphs);
+                }
+            }
+            Content::Image(image) => extract_image(image, paragraphs),
+            Content::EmbeddedFile(file) => extract_embedded_file(file, paragraphs),
+            Content::Printout(printout) => {
+                for image in printout.pages() {
+                    extract_image(image, paragraphs);
+                }
+            }
+            Content::Ink(_) | Content::Unknown(_) => {}
+        }
+    }
+
+    extract_items(element.children(), kind, paragraphs);
+}
+
+fn extract_image(image: &Image, paragraphs: &mut Vec<Paragraph>) {
+    if let Some(alt_text) = image.alt_text() {
+        push(paragraphs, ParagraphKind::ImageAltText, alt_text);
+    }
+
+    if let Some(text) = image.text() {
+        push(paragraphs, ParagraphKind::ImageText, text);
+    }
+}
+
+fn extract_embedded_file(file: &EmbeddedFile, paragraphs: &mut Vec<Paragraph>) {
+    push(paragraphs, ParagraphKind::EmbeddedFile, file.filename());
+}
+
+fn push(paragraphs: &mut Vec<Paragraph>, kind: ParagraphKind, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+
+    paragraphs.push(Paragraph {
+        kind,
+        text: text.to_string(),
+    });
+}
@@ -0,0 +1,224 @@
+use crate::onenote::notebook::Notebook;
+use crate::onenote::section::Section;
+use crate::search::extract::{extract_notebook, extract_section, PageText};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// An in-memory inverted index over the text of pages.
+///
+/// Text is split into words at every character that isn't alphanumeric. Words are
+/// matched case-insensitively.
+#[derive(Clone, Debug, Default)]
+pub struct SearchIndex {
+    pages: Vec<PageText>,
+    terms: HashMap<String, Vec<Posting>>,
+}
+
+/// An occurrence of a term in the indexed text.
+#[derive(Clone, Debug)]
+struct Posting {
+    page: usize,
+    paragraph: usize,
+    range: Range<usize>,
+}
+
+impl SearchIndex {
+    /// Create an empty index.
+    pub fn new() -> SearchIndex {
+        SearchIndex::default()
+    }
+
+    /// Add all pages of a notebook to the index.
+    pub fn add_notebook(&mut self, notebook: &Notebook) {
+        for page in extract_notebook(notebook) {
+            self.add_page(page);
+        }
+    }
+
+    /// Add all pages of a section to the index.
+    pub fn add_section(&mut self, section: &Section) {
+        for page in extract_section(section) {
+            self.add_page(page);
+        }
+    }
+
+    /// Add a page's text to the index.
+    ///
+    /// Returns the page's index in [`SearchIndex::pages()`].
+    pub fn add_page(&mut self, page: PageText) -> usize {
+        let page_index = self.pages.len();
+
+        for (paragraph_index, paragraph) in page.paragraphs().iter().enumerate() {
+            for (term, range) in tokenize(paragraph.text()) {
+                self.terms.entry(term).or_default().push(Posting {
+                    page: page_index,
+                    paragraph: paragraph_index,
+                    range,
+                });
+            }
+        }
+
+        self.pages.push(page);
+
+        page_index
+    }
+
+    /// The indexed pages.
+    pub fn pages(&self) -> &[PageText] {
+        &self.pages
+    }
+
+    /// Find the paragraphs containing all words of the query.
+    ///
+    /// The hits are sorted by page and paragraph.
+    pub fn query(&self, query: &str) -> Vec<SearchHit> {
+        let terms = tokenize(query)
+            .into_iter()
+            .map(|(term, _)| term)
+            .unique()
+            .collect_vec();
+
+        if terms.is_empty() {
+            return vec![];
+        }
+
+        // The number of query terms found in each paragraph and their positions
+        let mut found: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut matches: HashMap<(usize, usize), Vec<Range<usize>>> = HashMap::new();
+
+        for term in &terms {
+            let postings = match self.terms.get(term) {
+                Some(postings) => postings,
+                None => return vec![],
+            };
+
+            for (location, postings) in &postings.iter().group_by(|p| (p.page, p.paragraph)) {
+                *found.entry(location).or_default() += 1;
+                matches
+                    .entry(location)
+                    .or_default()
+                    .extend(postings.map(|posting| posting.range.clone()));
+            }
+        }
+
+        matches
+            .into_iter()
+            .filter(|(location, _)| found[location] == terms.len())
+            .map(|((page, paragraph), mut matches)| {
+                matches.sort_by_key(|range| range.start);
+
+                SearchHit {
+                    page,
+                    paragraph,
+                    matches,
+                }
+            })
+            .sorted_by_key(|hit| (hit.page, hit.paragraph))
+            .collect()
+    }
+}
+
+/// A paragraph matching a search query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchHit {
+    page: usize,
+    paragraph: usize,
+    matches: Vec<Range<usize>>,
+}
+
+impl SearchHit {
+    /// The index of the page in [`SearchIndex::pages()`].
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// The index of the paragraph in [`PageText::paragraphs()`].
+    pub fn paragraph(&self) -> usize {
+        self.paragraph
+    }
+
+    /// The positions of the matched words in the paragraph text.
+    ///
+    /// The positions are offsets in characters (Unicode scalar values).
+    pub fn matches(&self) -> &[Range<usize>] {
+        &self.matches
+    }
+}
+
+/// Split a text into lowercase words along with their character positions.
+fn tokenize(text: &str) -> Vec<(String, Range<usize>)> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut start = 0;
+
+    for (offset, c) in text.chars().enumerate() {
+        if c.is_alphanumeric() {
+            if word.is_empty() {
+                start = offset;
+            }
+
+            word.extend(c.to_lowercase());
+        } else if !word.is_empty() {
+            words.push((std::mem::take(&mut word), start..offset));
+        }
+    }
+
+    if !word.is_empty() {
+        words.push((word, start..text.chars().count()));
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod test {
+    use super::{tokenize, SearchIndex};
+    use crate::search::extract::{PageText, Paragraph, ParagraphKind};
+    use crate::shared::guid::Guid;
+
+    fn page(paragraphs: &[&str]) -> PageText {
+        PageText {
+            section_path: vec![],
+            page_id: Guid::nil(),
+            title: None,
+            paragraphs: paragraphs
+                .iter()
+                .map(|text| Paragraph {
+                    kind: ParagraphKind::Text,
+                    text: text.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("Grüße, Welt_2!"),
+            vec![
+                ("grüße".to_string(), 0..5),
+                ("welt".to_string(), 7..11),
+                ("2".to_string(), 12..13)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query() {
+        let mut index = SearchIndex::new();
+        index.add_page(page(&["Lorem ipsum", "dolor sit amet"]));
+        index.add_page(page(&["Sit dolor, sit"]));
+
+        let hits = index.query("SIT dolor");
+        assert_eq!(hits.len(), 2);
+
+        assert_eq!((hits[0].page(), hits[0].paragraph()), (0, 1));
+        assert_eq!(hits[0].matches(), [0..5, 6..9]);
+        assert_eq!((hits[1].page(), hits[1].paragraph()), (1, 0));
+        assert_eq!(hits[1].matches(), [0..3, 4..9, 11..14]);
+
+        assert!(index.query("lorem amet").is_empty());
+        assert!(index.query("").is_empty());
+    }
+}
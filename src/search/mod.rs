@@ -0,0 +1,16 @@
+//! Full-text search over parsed notebooks.
+//!
+//! The text of a page is extracted as a list of plain text paragraphs. This includes
+//! the page title, rich text paragraphs, table cells, image alternative texts, the text
+//! recognized in images and the names of embedded files. The [`SearchIndex`] indexes
+//! the extracted text and finds the paragraphs matching a query.
+//!
+//! This requires the `search` feature.
+
+pub use crate::search::extract::{
+    extract_notebook, extract_page, extract_section, PageText, Paragraph, ParagraphKind,
+};
+pub use crate::search::index::{SearchHit, SearchIndex};
+
+mod extract;
+mod index;
@@ -1,5 +1,5 @@
 use crate::errors::Result;
-use crate::Reader;
+use crate::{Reader, Writer};
 use std::fmt;
 use uuid::Uuid;
 
@@ -12,7 +12,7 @@ use uuid::Uuid;
 ///
 /// [\[1\]]: https://stackoverflow.com/questions/10190817/guid-byte-order-in-net
 /// [\[2\]]: https://docs.microsoft.com/en-us/dotnet/api/system.guid.tobytearray?view=net-5.0#remarks
-#[derive(Clone, Copy, PartialEq, Hash, Eq)]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, PartialOrd, Ord)]
 pub struct Guid(pub Uuid);
 
 impl Guid {
@@ -50,6 +50,18 @@ impl Guid {
         Ok(guid)
     }
 
+    pub(crate) fn write(&self, writer: Writer) -> Result<()> {
+        // The inverse of the mixed endianness conversion in `Guid::parse()`
+        writer.extend_from_slice(&self.0.to_bytes_le());
+
+        Ok(())
+    }
+
+    /// Generate a random GUID.
+    pub(crate) fn new_v4() -> Guid {
+        Guid(Uuid::new_v4())
+    }
+
     pub(crate) fn nil() -> Guid {
         Guid(Uuid::nil())
     }
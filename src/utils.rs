@@ -16,3 +16,35 @@ impl Utf16ToString for &[u8] {
         Ok(value.to_string().unwrap())
     }
 }
+
+/// Encode a string as UTF-16LE.
+pub(crate) fn utf16_bytes(value: &str) -> Vec<u8> {
+    value.encode_utf16().flat_map(|c| c.to_le_bytes()).collect()
+}
+
+/// Calculate the CRC-32 checksum (as used by zlib) of some data.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB88320 & (!(crc & 1)).wrapping_add(1));
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::{crc32, utf16_bytes};
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+        assert_eq!(crc32(&utf16_bytes("New Section 1.one\0")), 3193438256);
+    }
+}
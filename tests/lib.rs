@@ -1,6 +1,21 @@
-use insta::assert_debug_snapshot;
-use onenote_parser::Parser;
-use std::path::PathBuf;
+use insta::{assert_debug_snapshot, assert_snapshot};
+use onenote_parser::builder::{
+    EmbeddedFileBuilder, ImageBuilder, OutlineBuilder, OutlineElementBuilder, PageBuilder,
+    PageSeriesBuilder, RichTextBuilder, SectionBuilder, TableBuilder, TableCellBuilder,
+    TableRowBuilder,
+};
+use onenote_parser::contents::{ImageUploadState, OutlineItem, RichText};
+use onenote_parser::errors::{ErrorKind, Result};
+use onenote_parser::notebook::{FsSource, NotebookResolver, ResolvedEntry};
+use onenote_parser::property::rich_text::{ParagraphAlignment, ParagraphStyling};
+use onenote_parser::raw::RawValue;
+use onenote_parser::render::{HtmlRenderer, MarkdownRenderer};
+use onenote_parser::section::SectionEntry;
+use onenote_parser::{ParseOptions, Parser};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
 
 #[test]
 fn test_parse_section() {
@@ -17,3 +32,725 @@ fn test_parse_notebook() {
     let mut parser = Parser::new();
     assert_debug_snapshot!(parser.parse_notebook(&path).unwrap());
 }
+
+/// Parse the revision store files saved by the OneNote desktop apps.
+///
+/// The samples above are FSSHTTPB packaged files, the revision store parser is checked
+/// against converted copies of them (see `onestore::file::writer`). Files saved by OneNote
+/// 2016 placed in `tests/samples/native` are parsed and snapshotted as well; none are
+/// checked in yet.
+#[test]
+fn test_parse_native_samples() {
+    let Ok(entries) = fs::read_dir("tests/samples/native") else {
+        return;
+    };
+
+    let mut paths: Vec<_> = entries.map(|entry| entry.unwrap().path()).collect();
+    paths.sort();
+
+    let mut parser = Parser::new();
+
+    for path in paths {
+        let name = format!(
+            "native_{}",
+            path.file_name()
+                .unwrap()
+                .to_string_lossy()
+                .replace(' ', "_")
+        );
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("one") => assert_debug_snapshot!(name, parser.parse_section(&path).unwrap()),
+            Some("onetoc2") => assert_debug_snapshot!(name, parser.parse_notebook(&path).unwrap()),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_page_metadata() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+    let page = &section.page_series()[0].pages()[0];
+
+    assert_eq!(
+        page.id().to_string(),
+        "{5EAF76B1-8DDE-8745-A8A7-1A9865CC478A}"
+    );
+    assert_eq!(page.cached_title(), "Test Page");
+    assert!(!page.is_deleted());
+
+    let created_at = page.created_at().to_system_time();
+    let last_modified = page.last_modified().unwrap().to_system_time();
+    assert!(created_at <= last_modified);
+    assert_eq!(
+        created_at.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+        1_603_795_643
+    );
+}
+
+#[test]
+fn test_image_upload_state() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+    let page = &section.page_series()[0].pages()[0];
+
+    let image = page
+        .contents()
+        .iter()
+        .filter_map(|content| content.outline())
+        .flat_map(|outline| outline.items())
+        .filter_map(|item| item.element())
+        .flat_map(|element| element.contents())
+        .find_map(|content| content.image())
+        .unwrap();
+
+    assert_eq!(image.upload_state(), ImageUploadState::Uploaded);
+    assert!(image.data().is_some());
+    assert!(page.printouts().is_empty());
+}
+
+#[test]
+fn test_notebook_metadata() {
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let mut parser = Parser::new();
+    let notebook = parser.parse_notebook(&path).unwrap();
+
+    assert_eq!(notebook.display_name(), "samples");
+    assert_eq!(
+        notebook.id().to_string(),
+        "{F1DA443F-A65F-4513-B200-78D8A9910B8D}"
+    );
+
+    let color = notebook.color().unwrap();
+    assert_eq!((color.r(), color.g(), color.b()), (155, 187, 210));
+
+    let section = match &notebook.entries()[0] {
+        SectionEntry::Section(section) => section,
+        SectionEntry::SectionGroup(_) => panic!("expected a section"),
+    };
+    let color = section.color().unwrap();
+    assert_eq!((color.r(), color.g(), color.b()), (138, 168, 228));
+    assert_eq!(section.ordering_id(), Some(0));
+}
+
+#[test]
+fn test_parse_recycle_bin() {
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let mut parser = Parser::new();
+    let notebook = parser.parse_notebook(&path).unwrap();
+    assert!(notebook.entries().iter().all(|entry| match entry {
+        SectionEntry::Section(_) => true,
+        SectionEntry::SectionGroup(group) => !group.is_recycle_bin(),
+    }));
+
+    let recycle_bin = parser.parse_recycle_bin(&path).unwrap().unwrap();
+    assert!(recycle_bin.is_recycle_bin());
+    assert_eq!(recycle_bin.ordering_id(), None);
+
+    let section = match &recycle_bin.entries()[0] {
+        SectionEntry::Section(section) => section,
+        SectionEntry::SectionGroup(_) => panic!("expected a section"),
+    };
+    assert_eq!(section.display_name(), "OneNote_DeletedPages");
+
+    let page = &section.page_series()[0].pages()[0];
+    assert_eq!(page.title_text(), Some("Te"));
+    assert!(page.is_deleted());
+
+    let path = PathBuf::from("tests/samples/New Section Group/Open Notebook.onetoc2");
+    assert!(parser.parse_recycle_bin(&path).unwrap().is_none());
+}
+
+#[test]
+fn test_parse_options() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::with_options(ParseOptions::new().with_payloads(false));
+    let section = parser.parse_section(&path).unwrap();
+    let image = section.page_series()[0].pages()[0]
+        .contents()
+        .iter()
+        .filter_map(|content| content.outline())
+        .flat_map(|outline| outline.items())
+        .filter_map(|item| item.element())
+        .flat_map(|element| element.contents())
+        .find_map(|content| content.image())
+        .unwrap();
+    assert!(image.data().is_none());
+
+    // Pages opened on demand don't load payloads either
+    let data = std::fs::read(&path).unwrap();
+    let section = Parser::with_options(ParseOptions::new().with_payloads(false))
+        .open_section(&data, "New Section 1.one")
+        .unwrap();
+    let page = section.page(&section.pages()[0]).unwrap();
+    let image = page
+        .contents()
+        .iter()
+        .filter_map(|content| content.outline())
+        .flat_map(|outline| outline.items())
+        .filter_map(|item| item.element())
+        .flat_map(|element| element.contents())
+        .find_map(|content| content.image())
+        .unwrap();
+    assert!(image.data().is_none());
+
+    let mut parser = Parser::with_options(ParseOptions::new().with_max_file_size(1024));
+    let err = parser.parse_section(&path).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::FileTooLarge { max: 1024, .. }
+    ));
+
+    // Files from notebook sources are only read until they exceed the limit
+    let mut source = FsSource::new("tests/samples");
+    let toc = Path::new("Open Notebook.onetoc2");
+    for max in [1024, 4096] {
+        let mut parser = Parser::with_options(ParseOptions::new().with_max_file_size(max));
+        let err = parser
+            .parse_notebook_from_source(&mut source, toc)
+            .unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorKind::FileTooLarge { size, .. } if *size == max as u64 + 1
+        ));
+    }
+
+    let mut parser = Parser::with_options(ParseOptions::new().with_max_object_count(10));
+    let err = parser.parse_section(&path).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::TooManyObjects { max: 10, .. }
+    ));
+
+    // Pages opened on demand count towards the limit as well
+    let data = std::fs::read(&path).unwrap();
+    let open = |max| {
+        Parser::with_options(ParseOptions::new().with_max_object_count(max))
+            .open_section(&data, "New Section 1.one")
+    };
+    let max = (1..1000).find(|max| open(*max).is_ok()).unwrap();
+    let section = open(max).unwrap();
+    let err = section.page(&section.pages()[0]).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::TooManyObjects { .. }));
+
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let options = ParseOptions::new()
+        .with_recycle_bin(true)
+        .with_section_groups(false);
+    let mut parser = Parser::with_options(options);
+    let notebook = parser.parse_notebook(&path).unwrap();
+
+    let groups: Vec<_> = notebook
+        .entries()
+        .iter()
+        .filter_map(|entry| match entry {
+            SectionEntry::Section(_) => None,
+            SectionEntry::SectionGroup(group) => Some(group),
+        })
+        .collect();
+    assert!(groups.iter().any(|group| group.is_recycle_bin()));
+    assert!(groups
+        .iter()
+        .filter(|group| !group.is_recycle_bin())
+        .all(|group| group.entries().is_empty()));
+}
+
+#[test]
+fn test_lenient_warnings() {
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let options = ParseOptions::new()
+        .with_strict(false)
+        .with_max_object_count(10);
+    let mut parser = Parser::with_options(options);
+    let notebook = parser.parse_notebook(&path).unwrap();
+
+    // The section's pages exceed the limit and are left out
+    let section = match &notebook.entries()[0] {
+        SectionEntry::Section(section) => section,
+        SectionEntry::SectionGroup(_) => panic!("expected a section"),
+    };
+    assert_eq!(section.display_name(), "New Section 1");
+    assert!(section.page_series()[0].pages().is_empty());
+
+    let warnings = parser.take_warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].location(), "New Section 1.one");
+    assert!(matches!(
+        warnings[0].error().kind(),
+        ErrorKind::TooManyObjects { max: 10, .. }
+    ));
+    assert!(parser.warnings().is_empty());
+}
+
+#[test]
+fn test_link_index() {
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let mut parser = Parser::new();
+    let notebook = parser.parse_notebook(&path).unwrap();
+    let index = notebook.link_index();
+
+    let section = match &notebook.entries()[0] {
+        SectionEntry::Section(section) => section,
+        SectionEntry::SectionGroup(_) => panic!("expected a section"),
+    };
+    let page = &section.page_series()[0].pages()[0];
+
+    let url = format!(
+        "onenote:https://example.com/Notebook/New%20Section%201.one#Test%20Page&section-id={}&page-id={}&end",
+        section.id(),
+        page.id()
+    );
+    let (linked_section, linked_page) = index.resolve(&url).unwrap();
+    assert_eq!(linked_section.id(), section.id());
+    assert_eq!(linked_page.id(), page.id());
+
+    let url = format!("onenote:#New%20Section%201&section-id={}&end", section.id());
+    assert!(index.resolve(&url).is_none());
+    assert_eq!(index.resolve_section(&url).unwrap().id(), section.id());
+
+    assert!(index.resolve("https://example.com").is_none());
+}
+
+#[test]
+fn test_rich_text_links() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+    let page = &section.page_series()[0].pages()[0];
+
+    let mut texts = vec![];
+    for outline in page
+        .contents()
+        .iter()
+        .filter_map(|content| content.outline())
+    {
+        collect_rich_text(outline.items(), &mut texts);
+    }
+
+    let text = texts
+        .into_iter()
+        .find(|text| !text.links().is_empty())
+        .unwrap();
+    assert!(!text.text().contains("HYPERLINK"));
+
+    let link = &text.links()[0];
+    let range = link.display_range();
+    let display_text: String = text
+        .text()
+        .chars()
+        .skip(range.start as usize)
+        .take(range.len())
+        .collect();
+
+    assert_eq!(link.url(), "https://example.com");
+    assert_eq!(display_text, "magna");
+}
+
+fn collect_rich_text<'a>(items: &'a [OutlineItem], texts: &mut Vec<&'a RichText>) {
+    for item in items {
+        match item {
+            OutlineItem::Group(group) => collect_rich_text(group.outlines(), texts),
+            OutlineItem::Element(element) => {
+                texts.extend(element.contents().iter().filter_map(|c| c.rich_text()));
+                collect_rich_text(element.children(), texts);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_render_section_markdown() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+
+    let markdown = MarkdownRenderer::new().render_section(&section);
+    let files: Vec<_> = markdown.files().iter().map(|file| file.path()).collect();
+
+    assert_eq!(files, ["attachments/testimages_screenshot.jpg"]);
+    assert_snapshot!(markdown.text());
+}
+
+#[test]
+fn test_render_section_html() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+
+    assert_snapshot!(HtmlRenderer::new().render_section(&section));
+}
+
+#[test]
+fn test_open_section() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+    let data = std::fs::read(&path).unwrap();
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+    let lazy = parser.open_section(&data, "New Section 1.one").unwrap();
+
+    assert_eq!(lazy.display_name(), section.display_name());
+
+    let pages: Vec<_> = section
+        .page_series()
+        .iter()
+        .flat_map(|series| series.pages())
+        .collect();
+    assert_eq!(lazy.pages().len(), pages.len());
+
+    for (header, page) in lazy.pages().iter().zip(pages) {
+        let lazy_page = lazy.page(header).unwrap();
+
+        assert_eq!(header.level(), page.level());
+        assert_eq!(lazy_page.title_text(), page.title_text());
+        assert_eq!(format!("{:?}", lazy_page), format!("{:?}", page));
+    }
+
+    assert_debug_snapshot!(lazy.pages());
+}
+
+#[test]
+fn test_page_revision() {
+    let data = std::fs::read("tests/samples/New Section 1.one").unwrap();
+
+    let mut parser = Parser::new();
+    let section = parser.open_section(&data, "New Section 1.one").unwrap();
+
+    let header = &section.pages()[0];
+    let page = section.page(header).unwrap();
+    assert!(page.revisions().len() > 1);
+
+    let current = section
+        .page_revision(header, page.revisions()[0].id())
+        .unwrap()
+        .unwrap();
+    assert_eq!(format!("{:?}", current), format!("{:?}", page));
+
+    let previous = page.revisions()[1].id();
+    if let Some(previous) = section.page_revision(header, previous).unwrap() {
+        assert_eq!(previous.revisions().len(), page.revisions().len() - 1);
+    }
+}
+
+#[test]
+fn test_write_section() {
+    let paths = [
+        "tests/samples/New Section 1.one",
+        "tests/samples/New Section Group/New Section 1.one",
+        "tests/samples/New Section Group/New Section 2.one",
+        "tests/samples/OneNote_RecycleBin/OneNote_DeletedPages.one",
+    ];
+
+    let mut parser = Parser::new();
+
+    for path in paths {
+        let data = fs::read(path).unwrap();
+        let section = parser.open_section(&data, path).unwrap();
+
+        let written = section.to_bytes().unwrap();
+        assert!(written == data, "{} differs after writing", path);
+
+        let reopened = parser.open_section(&written, path).unwrap();
+        assert_eq!(reopened.pages().len(), section.pages().len());
+    }
+}
+
+#[test]
+fn test_build_section() {
+    let title = ParagraphStyling::new().with_bold(true).with_font_size(28);
+    let text = RichTextBuilder::new("Status: ")
+        .with_run("done", title)
+        .with_alignment(ParagraphAlignment::Center);
+    let table = TableBuilder::new().with_row(
+        TableRowBuilder::new()
+            .with_cell(TableCellBuilder::new().with_text(RichTextBuilder::new("Owner")))
+            .with_cell(TableCellBuilder::new().with_text(RichTextBuilder::new("Task"))),
+    );
+    let outline = OutlineBuilder::new().with_element(
+        OutlineElementBuilder::new()
+            .with_text(text)
+            .with_child(OutlineElementBuilder::new().with_table(table)),
+    );
+
+    let page = PageBuilder::new("Weekly meeting")
+        .with_author("Alex")
+        .with_outline(outline)
+        .with_image(ImageBuilder::new(vec![1, 2, 3]).with_extension(".png"))
+        .with_embedded_file(EmbeddedFileBuilder::new("notes.txt", b"notes".to_vec()));
+    let subpage = PageBuilder::new("Action items").with_level(2);
+
+    let builder = SectionBuilder::new("Meetings")
+        .with_page_series(PageSeriesBuilder::new().with_page(page).with_page(subpage));
+    let data = builder.to_bytes().unwrap();
+
+    let section = Parser::new()
+        .parse_section_from_bytes(&data, &builder.file_name())
+        .unwrap();
+    assert_eq!(section.display_name(), "Meetings");
+
+    let lazy = Parser::new()
+        .open_section(&data, &builder.file_name())
+        .unwrap();
+    assert_eq!(lazy.pages().len(), 2);
+    assert!(lazy.to_bytes().unwrap() == data);
+
+    let pages = section.page_series()[0].pages();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].title_text(), Some("Weekly meeting"));
+    assert_eq!(pages[0].author(), Some("Alex"));
+    assert_eq!(pages[1].title_text(), Some("Action items"));
+    assert_eq!(pages[1].level(), 2);
+
+    let contents = pages[0].contents();
+    assert_eq!(contents.len(), 3);
+
+    let element = match &contents[0].outline().unwrap().items()[0] {
+        OutlineItem::Element(element) => element,
+        OutlineItem::Group(_) => panic!("expected an outline element"),
+    };
+    let text = element.contents()[0].rich_text().unwrap();
+    assert_eq!(text.text(), "Status: done");
+    assert_eq!(text.text_run_indices(), [8]);
+    assert!(text.text_run_formatting()[1].bold());
+    assert_eq!(text.text_run_formatting()[1].font_size(), Some(28));
+    assert_eq!(text.paragraph_alignment(), ParagraphAlignment::Center);
+
+    let table = match &element.children()[0] {
+        OutlineItem::Element(child) => child.contents()[0].table().unwrap(),
+        OutlineItem::Group(_) => panic!("expected an outline element"),
+    };
+    assert_eq!((table.rows(), table.cols()), (1, 2));
+
+    let image = contents[1].image().unwrap();
+    assert_eq!(image.data(), Some(&[1, 2, 3][..]));
+    assert_eq!(image.extension(), Some(".png"));
+
+    let file = contents[2].embedded_file().unwrap();
+    assert_eq!(file.filename(), "notes.txt");
+    assert_eq!(file.data(), b"notes");
+}
+
+#[test]
+fn test_raw_store() {
+    let data = fs::read("tests/samples/New Section 1.one").unwrap();
+
+    let mut parser = Parser::new();
+    let section = parser
+        .parse_section_from_bytes(&data, "New Section 1.one")
+        .unwrap();
+    let store = parser.open_raw(&data, "New Section 1.one").unwrap();
+
+    let data_root = store.data_root();
+    let root = data_root.object(data_root.content_root().unwrap()).unwrap();
+    assert_eq!(root.type_name().as_deref(), Some("SectionNode"));
+    assert_eq!(root.jc_id(), 0x00060007);
+
+    // Follow the section's page series to the page object spaces
+    let page_series = match root.property(0x24001C20).unwrap().value() {
+        RawValue::ObjectIds(ids) => ids.clone(),
+        value => panic!("unexpected value {:?}", value),
+    };
+    assert_eq!(page_series.len(), section.page_series().len());
+
+    let series = data_root.object(page_series[0]).unwrap();
+    let page_spaces = match series.property(0x1D63).unwrap().value() {
+        RawValue::ObjectSpaceIds(ids) => ids.clone(),
+        value => panic!("unexpected value {:?}", value),
+    };
+    assert_eq!(page_spaces.len(), section.page_series()[0].pages().len());
+
+    let page_space = store.object_space(page_spaces[0]).unwrap();
+    let metadata = page_space
+        .object(page_space.metadata_root().unwrap())
+        .unwrap();
+    let title = metadata.property(0x1C001CF3).unwrap();
+    assert_eq!(title.name().as_deref(), Some("CachedTitleString"));
+    assert!(matches!(title.value(), RawValue::Bytes(_)));
+
+    let spaces = store.object_spaces();
+    assert!(page_spaces
+        .iter()
+        .all(|id| spaces.iter().any(|space| space.id() == *id)));
+    assert!(spaces
+        .iter()
+        .flat_map(|space| space.objects())
+        .any(|object| object.type_name().is_none()));
+}
+
+#[test]
+fn test_unknown_content_types() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+
+    // All contents of the sample section are supported
+    assert!(section.unknown_content_types().is_empty());
+
+    let contents = section
+        .page_series()
+        .iter()
+        .flat_map(|series| series.pages())
+        .flat_map(|page| page.contents());
+    assert!(contents.clone().all(|content| content.unknown().is_none()));
+    assert!(contents.filter_map(|content| content.outline()).count() > 0);
+}
+
+#[test]
+fn test_parse_section_from_reader() {
+    let path = PathBuf::from("tests/samples/New Section 1.one");
+    let data = fs::read(&path).unwrap();
+
+    let mut parser = Parser::new();
+    let section = parser.parse_section(&path).unwrap();
+    let from_reader = parser
+        .parse_section_from_reader(Cursor::new(data), "New Section 1.one")
+        .unwrap();
+
+    assert_eq!(format!("{:?}", from_reader), format!("{:?}", section));
+}
+
+/// A resolver reading the section files of a sample notebook into memory.
+struct SampleResolver {
+    dir: PathBuf,
+}
+
+impl NotebookResolver for SampleResolver {
+    fn resolve(&mut self, path: &[String]) -> Result<Option<ResolvedEntry>> {
+        let mut file = self.dir.clone();
+        file.extend(path);
+
+        if !file.is_file() {
+            return Ok(None);
+        }
+
+        Ok(Some(ResolvedEntry::Section(fs::read(file)?)))
+    }
+}
+
+#[test]
+fn test_parse_notebook_from_bytes() {
+    for dir in ["tests/samples", "tests/samples/New Section Group"] {
+        let dir = PathBuf::from(dir);
+        let path = dir.join("Open Notebook.onetoc2");
+        let data = fs::read(&path).unwrap();
+
+        let mut parser = Parser::new();
+        let notebook = parser.parse_notebook(&path).unwrap();
+        let from_bytes = parser
+            .parse_notebook_from_bytes(&data, &path.to_string_lossy(), &mut SampleResolver { dir })
+            .unwrap();
+
+        assert!(!notebook.entries().is_empty());
+        assert_eq!(format!("{:?}", from_bytes), format!("{:?}", notebook));
+    }
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn test_parse_notebook_from_zip() {
+    use onenote_parser::notebook::ZipSource;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    let files = [
+        "Open Notebook.onetoc2",
+        "New Section 1.one",
+        "New Section Group/Open Notebook.onetoc2",
+        "New Section Group/New Section 1.one",
+        "New Section Group/New Section 2.one",
+    ];
+
+    let mut archive = ZipWriter::new(Cursor::new(vec![]));
+    for file in files {
+        archive
+            .start_file(format!("samples/{}", file), FileOptions::default())
+            .unwrap();
+        archive
+            .write_all(&fs::read(Path::new("tests/samples").join(file)).unwrap())
+            .unwrap();
+    }
+    let archive = archive.finish().unwrap();
+
+    let mut parser = Parser::new();
+    let mut source = ZipSource::new(archive).unwrap();
+
+    for dir in ["", "New Section Group"] {
+        let path = Path::new("tests/samples")
+            .join(dir)
+            .join("Open Notebook.onetoc2");
+        let notebook = parser.parse_notebook(&path).unwrap();
+
+        let path = Path::new("samples").join(dir).join("Open Notebook.onetoc2");
+        let from_zip = parser
+            .parse_notebook_from_source(&mut source, &path)
+            .unwrap();
+
+        assert_eq!(format!("{:?}", from_zip), format!("{:?}", notebook));
+    }
+}
+
+#[cfg(feature = "search")]
+#[test]
+fn test_search_index() {
+    use onenote_parser::search::{ParagraphKind, SearchIndex};
+
+    let path = PathBuf::from("tests/samples/Open Notebook.onetoc2");
+
+    let mut parser = Parser::new();
+    let notebook = parser.parse_notebook(&path).unwrap();
+
+    let mut index = SearchIndex::new();
+    index.add_notebook(&notebook);
+
+    let page = &index.pages()[0];
+    assert_eq!(page.section_path(), ["New Section 1"]);
+    assert_eq!(page.title(), Some("Test Page"));
+
+    let hits = index.query("Dolore Magna");
+    assert!(!hits.is_empty());
+
+    let paragraph = &index.pages()[hits[0].page()].paragraphs()[hits[0].paragraph()];
+    let words: Vec<String> = hits[0]
+        .matches()
+        .iter()
+        .map(|range| {
+            paragraph
+                .text()
+                .chars()
+                .skip(range.start)
+                .take(range.len())
+                .collect()
+        })
+        .collect();
+    assert_eq!(words, ["dolore", "magna"]);
+
+    let hits = index.query("testimages archive");
+    assert_eq!(hits.len(), 1);
+
+    let paragraph = &index.pages()[hits[0].page()].paragraphs()[hits[0].paragraph()];
+    assert_eq!(paragraph.kind(), ParagraphKind::ImageAltText);
+
+    let hits = index.query("c");
+    assert_eq!(hits.len(), 1);
+
+    let paragraph = &index.pages()[hits[0].page()].paragraphs()[hits[0].paragraph()];
+    assert_eq!(paragraph.kind(), ParagraphKind::TableCell);
+
+    assert!(index.query("nonexistent").is_empty());
+}